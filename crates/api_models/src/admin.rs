@@ -31,8 +31,8 @@ pub struct CreateMerchantAccount {
     /// Webhook related details
     pub webhook_details: Option<WebhookDetails>,
 
-    /// The routing algorithm to be used for routing payments to desired connectors
-    #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "stripe"}))]
+    /// The routing algorithm to be used for routing payments to desired connectors. It can route all payments to a single connector, to the first enabled connector of a priority list, or split them across connectors by volume
+    #[schema(value_type = Option<Object>,example = json!({"type": "volume_split", "data": [{"connector": "stripe", "split": 70}, {"connector": "adyen", "split": 30}]}))]
     pub routing_algorithm: Option<serde_json::Value>,
 
     /// A boolean value to indicate if the merchant is a sub-merchant under a master or a parent merchant. By default, its value is false.
@@ -175,7 +175,122 @@ pub struct MerchantDetails {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
+    /// Route all payments to the given connector
     Single(api_enums::RoutableConnectors),
+    /// Route payments to the first connector in the list which is enabled for the merchant
+    Priority(Vec<api_enums::RoutableConnectors>),
+    /// Split payments across the connectors in proportion to their share of the volume
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+    /// Route payments based on rules evaluated against the attributes of the payment
+    RuleBased(RoutingRules),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    /// The connector to route the payments to
+    pub connector: api_enums::RoutableConnectors,
+    /// The percentage of the payments to be routed to the connector
+    pub split: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    /// The rules to be evaluated in order, the first rule whose conditions are all satisfied by the payment is applied
+    pub rules: Vec<RoutingRule>,
+    /// The connectors to route to when none of the rules are applicable, in the order of priority
+    pub default_connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// The name of the rule
+    pub name: String,
+    /// The conditions which must all be satisfied by the payment for the rule to be applied
+    pub conditions: Vec<RoutingCondition>,
+    /// The connectors to route to when the rule is applied, in the order of priority
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "field", rename_all = "snake_case", deny_unknown_fields)]
+pub enum RoutingCondition {
+    /// Satisfied if the currency of the payment is one of the given currencies
+    Currency { values: Vec<api_enums::Currency> },
+    /// Satisfied if the amount of the payment lies within the given (inclusive) bounds
+    Amount { min: Option<i64>, max: Option<i64> },
+    /// Satisfied if the card used for the payment belongs to one of the given card networks
+    CardNetwork { values: Vec<api_enums::CardNetwork> },
+    /// Satisfied if the card number used for the payment starts with one of the given BINs
+    CardBin { values: Vec<String> },
+    /// Satisfied if the country of the billing address is one of the given two-letter ISO country codes
+    BillingCountry { values: Vec<String> },
+    /// Satisfied if the payment method of the payment is one of the given payment methods
+    PaymentMethod {
+        values: Vec<api_enums::PaymentMethodType>,
+    },
+    /// Satisfied if the metadata of the payment contains the given key, with the given value if one is provided
+    Metadata {
+        key: String,
+        value: Option<serde_json::Value>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RoutingDryRunResponse {
+    /// The connector the payment would be routed to
+    #[schema(value_type = Option<Connector>, example = "stripe")]
+    pub connector: Option<api_enums::RoutableConnectors>,
+    /// The connectors the payment could be routed to, in the order of priority, considering only the connectors enabled for the merchant
+    #[schema(value_type = Vec<Connector>)]
+    pub eligible_connectors: Vec<api_enums::RoutableConnectors>,
+    /// The name of the routing rule which was applied, if the routing algorithm is rule based
+    #[schema(example = "high_value_eur_payments")]
+    pub matched_rule: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ConnectorHealthResponse {
+    /// The name of the connector
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The health of the connector considered when routing payments, which is the overridden health if the health is overridden
+    #[schema(value_type = ConnectorHealthStatus, example = "healthy")]
+    pub status: api_enums::ConnectorHealthStatus,
+    /// Whether the circuit breaker of the connector is open, after the connector failed too many calls or responded too slowly
+    #[schema(example = false)]
+    pub circuit_open: bool,
+    /// The health of the connector set by an administrator, overriding the health tracked by the circuit breaker
+    #[schema(value_type = Option<ConnectorHealthStatus>)]
+    pub health_override: Option<api_enums::ConnectorHealthStatus>,
+    /// The number of seconds of the rolling window over which the calls to the connector are tracked
+    #[schema(example = 60)]
+    pub window_secs: u64,
+    /// The number of calls made to the connector within the window
+    #[schema(example = 120)]
+    pub calls: u64,
+    /// The number of calls to the connector within the window which failed with a server error or a timeout
+    #[schema(example = 3)]
+    pub failed_calls: u64,
+    /// The ratio of calls to the connector within the window which succeeded
+    #[schema(example = 0.975)]
+    pub success_rate: Option<f64>,
+    /// The average number of milliseconds taken by the calls to the connector within the window
+    #[schema(example = 450)]
+    pub average_latency_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorHealthOverrideRequest {
+    /// The health of the connector to be considered when routing payments, regardless of the circuit breaker
+    #[schema(value_type = ConnectorHealthStatus, example = "unhealthy")]
+    pub status: api_enums::ConnectorHealthStatus,
+    /// The number of seconds after which the override is removed, defaults to the override TTL in the settings
+    #[schema(example = 3600)]
+    pub ttl_secs: Option<i64>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -197,6 +312,10 @@ pub struct WebhookDetails {
     #[schema(value_type = Option<String>, example = "www.ekart.com/webhooks")]
     pub webhook_url: Option<Secret<String>>,
 
+    /// The secret used to sign the webhooks sent to the merchant, at least 32 characters long. The payment response hash key of the merchant is used if not provided
+    #[schema(value_type = Option<String>, min_length = 32, max_length = 255, example = "whsec_xfN3bKx8L2gZpTq7Yc4RmW9vJe6HsA1d")]
+    pub webhook_secret: Option<Secret<String>>,
+
     /// If this property is true, a webhook message is posted whenever a new payment is created
     #[schema(example = true)]
     pub payment_created_enabled: Option<bool>,
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::enums::ApiKeyScope;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources that the API Key can access. The API Key can access all resources if no
+    /// scopes are provided.
+    #[schema(example = json!(["payments:read", "reporting:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +67,11 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources that the API Key can access, or `null` if the API Key can access all
+    /// resources.
+    #[schema(example = json!(["payments:read", "reporting:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +114,11 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources that the API Key can access, or `null` if the API Key can access all
+    /// resources.
+    #[schema(example = json!(["payments:read", "reporting:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +146,11 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// The resources that the API Key can access. An empty list of scopes grants the API Key
+    /// access to all resources.
+    #[schema(example = json!(["payments:read", "reporting:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
 }
 
 /// The response body for revoking an API Key.
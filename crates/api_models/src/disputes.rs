@@ -1 +1,108 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums::{DisputeStage, DisputeStatus};
+
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier for payment_intent
+    pub payment_id: String,
+    /// The identifier for payment_attempt
+    pub attempt_id: String,
+    /// The dispute amount
+    pub amount: String,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// Stage of the dispute
+    #[schema(value_type = DisputeStage)]
+    pub dispute_stage: DisputeStage,
+    /// Status of the dispute
+    #[schema(value_type = DisputeStatus)]
+    pub dispute_status: DisputeStatus,
+    /// connector to which dispute is associated with
+    pub connector: String,
+    /// Status of the dispute sent by connector
+    pub connector_status: String,
+    /// Dispute id sent by connector
+    pub connector_dispute_id: String,
+    /// Reason of dispute sent by connector
+    pub connector_reason: Option<String>,
+    /// Reason code of dispute sent by connector
+    pub connector_reason_code: Option<String>,
+    /// Evidence deadline of dispute sent by connector
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// Dispute created time sent by connector
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    /// Dispute updated time sent by connector
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+    /// Time at which dispute is received
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// status of the dispute
+    #[schema(value_type = Option<DisputeStatus>)]
+    pub dispute_status: Option<DisputeStatus>,
+    /// stage of the dispute
+    #[schema(value_type = Option<DisputeStage>)]
+    pub dispute_stage: Option<DisputeStage>,
+    /// reason for the dispute
+    pub reason: Option<String>,
+    /// connector linked to dispute
+    pub connector: Option<String>,
+    /// The time at which dispute is received
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub received_time: Option<PrimitiveDateTime>,
+    /// Time less than the dispute received time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "received_time.lt")]
+    pub received_time_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the dispute received time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "received_time.gt")]
+    pub received_time_gt: Option<PrimitiveDateTime>,
+    /// Time less than or equals to the dispute received time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "received_time.lte")]
+    pub received_time_lte: Option<PrimitiveDateTime>,
+    /// Time greater than or equals to the dispute received time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "received_time.gte")]
+    pub received_time_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitEvidenceRequest {
+    /// Dispute Id
+    pub dispute_id: String,
+    /// File Id of the cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// File Id of the communication between the merchant and the customer
+    pub customer_communication: Option<String>,
+    /// File Id of the customer signature, proving the customer authorized the payment
+    pub customer_signature: Option<String>,
+    /// File Id of the receipt or invoice of the payment
+    pub receipt: Option<String>,
+    /// File Id of the refund policy
+    pub refund_policy: Option<String>,
+    /// File Id of the documentation of the service provided
+    pub service_documentation: Option<String>,
+    /// File Id of the documentation of the shipment of the product
+    pub shipping_documentation: Option<String>,
+    /// File Id of any additional evidence
+    pub uncategorized_file: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
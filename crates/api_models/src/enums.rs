@@ -11,6 +11,8 @@ use utoipa::ToSchema;
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    ToSchema,
+    frunk::LabelledGeneric,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
@@ -262,6 +264,20 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    PaymentProcessing,
+    PaymentCancelled,
+    PaymentCaptured,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    MandateRevoked,
 }
 
 #[derive(
@@ -493,6 +509,52 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The period over which the number of charges of a mandate is limited
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MandateFrequencyPeriod {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorHealthStatus {
+    /// Payments are routed to the connector
+    Healthy,
+    /// Payments are routed to the fallback connectors instead of the connector, when possible
+    Unhealthy,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -522,6 +584,7 @@ pub enum Connector {
     Fiserv,
     Globalpay,
     Klarna,
+    MockThreeDs,
     Payu,
     Rapyd,
     Shift4,
@@ -534,6 +597,12 @@ impl Connector {
     pub fn supports_access_token(&self) -> bool {
         matches!(self, Self::Globalpay | Self::Payu)
     }
+
+    /// Whether the connector can authorize a payment with the result of a 3DS authentication
+    /// performed by the router
+    pub fn supports_external_three_ds(&self) -> bool {
+        matches!(self, Self::Adyen)
+    }
 }
 
 #[derive(
@@ -541,6 +610,7 @@ impl Connector {
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
     serde::Serialize,
     serde::Deserialize,
@@ -568,6 +638,136 @@ pub enum RoutableConnectors {
     Worldpay,
 }
 
+/// The card network of a card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardNetwork {
+    Visa,
+    Mastercard,
+    AmericanExpress,
+    Discover,
+    DinersClub,
+    Jcb,
+}
+
+/// The status of the payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    Success,
+    Failed,
+    Cancelled,
+    Pending,
+    Ineligible,
+    #[default]
+    RequiresFulfillment,
+    Reversed,
+}
+
+/// The type of the payout destination
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Card,
+    Bank,
+}
+
+/// The status of the subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is charged at every due date
+    #[default]
+    Active,
+    /// The last charge of the subscription failed and is being retried
+    PastDue,
+    /// The subscription is not charged until it is resumed
+    Paused,
+    /// The subscription is no longer charged
+    Cancelled,
+}
+
+/// The unit of the interval at which the subscription is charged
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
 /// Wallets which support obtaining session object
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -608,3 +808,225 @@ impl From<AttemptStatus> for IntentStatus {
         }
     }
 }
+
+/// The stage of the dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStage {
+    PreDispute,
+    #[default]
+    Dispute,
+    PreArbitration,
+}
+
+/// The status of the dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    #[default]
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    // dispute has been successfully challenged by the merchant
+    DisputeWon,
+    // dispute has been unsuccessfully challenged
+    DisputeLost,
+}
+
+/// The purpose of the file being uploaded
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FilePurpose {
+    /// Evidence to be submitted to the connector for challenging a dispute
+    #[default]
+    DisputeEvidence,
+}
+
+/// The records exported for reconciliation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportEntity {
+    /// Payments, without connector transaction IDs, which are held by the attempts of the payment
+    /// and are reconciled through the payment attempt export joined on `payment_id`
+    PaymentIntent,
+    /// Payment attempts along with their connector transaction IDs. Processor fees are not
+    /// exported, as the fees charged by connectors are not recorded.
+    PaymentAttempt,
+    Refund,
+}
+
+/// The format of the file to which the records are exported
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportFormat {
+    /// Comma separated values, with a header row
+    #[default]
+    Csv,
+    /// One JSON object per line
+    Jsonl,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportStatus {
+    /// The records are being written to the file
+    Pending,
+    /// The file is available for download
+    Completed,
+}
+
+/// The resources that an API key can access. A write scope also grants read access to the
+/// resource.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum ApiKeyScope {
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+    #[serde(rename = "refunds:read")]
+    #[strum(serialize = "refunds:read")]
+    RefundsRead,
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+    #[serde(rename = "customers:read")]
+    #[strum(serialize = "customers:read")]
+    CustomersRead,
+    #[serde(rename = "customers:write")]
+    #[strum(serialize = "customers:write")]
+    CustomersWrite,
+    #[serde(rename = "payment_methods:read")]
+    #[strum(serialize = "payment_methods:read")]
+    PaymentMethodsRead,
+    #[serde(rename = "payment_methods:write")]
+    #[strum(serialize = "payment_methods:write")]
+    PaymentMethodsWrite,
+    #[serde(rename = "mandates:read")]
+    #[strum(serialize = "mandates:read")]
+    MandatesRead,
+    #[serde(rename = "mandates:write")]
+    #[strum(serialize = "mandates:write")]
+    MandatesWrite,
+    #[serde(rename = "disputes:read")]
+    #[strum(serialize = "disputes:read")]
+    DisputesRead,
+    #[serde(rename = "disputes:write")]
+    #[strum(serialize = "disputes:write")]
+    DisputesWrite,
+    #[serde(rename = "payouts:read")]
+    #[strum(serialize = "payouts:read")]
+    PayoutsRead,
+    #[serde(rename = "payouts:write")]
+    #[strum(serialize = "payouts:write")]
+    PayoutsWrite,
+    #[serde(rename = "subscriptions:read")]
+    #[strum(serialize = "subscriptions:read")]
+    SubscriptionsRead,
+    #[serde(rename = "subscriptions:write")]
+    #[strum(serialize = "subscriptions:write")]
+    SubscriptionsWrite,
+    #[serde(rename = "files:read")]
+    #[strum(serialize = "files:read")]
+    FilesRead,
+    #[serde(rename = "files:write")]
+    #[strum(serialize = "files:write")]
+    FilesWrite,
+    /// Listing payments, refunds and disputes, and exporting them
+    #[serde(rename = "reporting:read")]
+    #[strum(serialize = "reporting:read")]
+    ReportingRead,
+}
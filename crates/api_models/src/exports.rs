@@ -0,0 +1,44 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExportRequest {
+    /// The records to be exported
+    #[schema(value_type = ExportEntity, example = "payment_attempt")]
+    pub entity: enums::ExportEntity,
+    /// The format of the exported file
+    #[schema(value_type = Option<ExportFormat>, example = "csv")]
+    #[serde(default)]
+    pub format: enums::ExportFormat,
+    /// The records created at or after this time are exported
+    #[schema(example = "2023-03-01T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// The records created before this time are exported
+    #[schema(example = "2023-03-02T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ExportResponse {
+    /// The identifier for the export, which is the identifier of the exported file
+    #[schema(example = "file_qAG1xC9EWpA2g1vb9ALc")]
+    pub export_id: String,
+    /// The name of the exported file
+    #[schema(example = "payment_attempt_2023-03-01_2023-03-02.csv")]
+    pub file_name: Option<String>,
+    /// The status of the export
+    #[schema(value_type = ExportStatus, example = "completed")]
+    pub status: enums::ExportStatus,
+    /// The link from which the exported file can be downloaded, once the export is completed
+    #[schema(example = "https://sandbox.hyperswitch.io/files/file_qAG1xC9EWpA2g1vb9ALc")]
+    pub download_url: Option<String>,
+    /// Time at which the export was requested
+    #[schema(example = "2023-03-02T01:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
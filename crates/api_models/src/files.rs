@@ -1 +1,24 @@
+use utoipa::ToSchema;
 
+use crate::enums;
+
+/// The fields of the `multipart/form-data` request used to create a file
+#[derive(Debug, ToSchema)]
+pub struct CreateFileRequest {
+    /// The file to be uploaded
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    /// The name of the file
+    pub file_name: Option<String>,
+    /// The purpose for which the file is being uploaded
+    #[schema(value_type = FilePurpose)]
+    pub purpose: enums::FilePurpose,
+    /// The identifier of the dispute for which the file is submitted as evidence
+    pub dispute_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CreateFileResponse {
+    /// ID of the file created
+    pub file_id: String,
+}
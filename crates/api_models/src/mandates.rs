@@ -18,7 +18,7 @@ pub struct MandateRevokedResponse {
     pub status: api_enums::MandateStatus,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize, ToSchema)]
 pub struct MandateResponse {
     /// The identifier for mandate
     pub mandate_id: String,
@@ -36,7 +36,7 @@ pub struct MandateResponse {
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize, ToSchema)]
 pub struct MandateCardDetails {
     /// The last 4 digits of card
     pub last4_digits: Option<String>,
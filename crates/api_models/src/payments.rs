@@ -251,6 +251,29 @@ pub struct MandateAmountData {
     /// The currency for the transaction
     #[schema(value_type = Currency, example = "USD")]
     pub currency: api_enums::Currency,
+    /// The time from which the mandate can be charged
+    #[schema(example = "2022-09-10T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_date: Option<PrimitiveDateTime>,
+    /// The time after which the mandate can no longer be charged
+    #[schema(example = "2023-09-10T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_date: Option<PrimitiveDateTime>,
+    /// The maximum number of times the mandate can be charged in a period
+    pub frequency: Option<MandateFrequency>,
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Copy, Debug, Default, ToSchema, serde::Serialize, serde::Deserialize,
+)]
+pub struct MandateFrequency {
+    /// The maximum number of charges in each period
+    #[schema(example = 1)]
+    pub max_charges: i32,
+    /// The period over which the charges are counted, starting at the beginning of each calendar
+    /// day, week (Monday), month or year
+    #[schema(value_type = MandateFrequencyPeriod, example = "month")]
+    pub period: api_enums::MandateFrequencyPeriod,
 }
 
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -531,7 +554,7 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsCaptureRequest {
     /// The unique identifier for the payment
     pub payment_id: Option<String>,
@@ -539,6 +562,8 @@ pub struct PaymentsCaptureRequest {
     pub merchant_id: Option<String>,
     /// The Amount to be captured/ debited from the user's payment method.
     pub amount_to_capture: Option<i64>,
+    /// Whether this is the last capture of the payment, releasing the uncaptured amount. Payments with the `manual_multiple` capture method can be captured partially until the final capture is made
+    pub final_capture: Option<bool>,
     /// Decider to refund the uncaptured amount
     pub refund_uncaptured_amount: Option<bool>,
     /// Provides information about a card payment that customers see on their statements.
@@ -563,6 +588,7 @@ pub enum NextActionType {
     DisplayQrCode,
     InvokeSdkClient,
     TriggerApi,
+    ThreeDsInvoke,
 }
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct NextAction {
@@ -572,6 +598,27 @@ pub struct NextAction {
     /// Contains the url for redirection flow
     #[schema(example = "https://router.juspay.io/redirect/fakushdfjlksdfasklhdfj")]
     pub redirect_to_url: Option<String>,
+    /// Contains the data for the 3DS method and challenge to be performed by the SDK, for payments authenticated with 3DS by the router
+    pub three_ds_data: Option<ThreeDsData>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct ThreeDsData {
+    /// The identifier of the 3DS transaction with the 3DS server
+    pub three_ds_server_transaction_id: String,
+    /// The version of the 3DS protocol used for the authentication
+    #[schema(example = "2.2.0")]
+    pub message_version: String,
+    /// The URL of the 3DS method to be loaded in a hidden iframe, before authenticating the customer
+    pub three_ds_method_url: Option<String>,
+    /// The data to be posted to the 3DS method URL as `threeDSMethodData`
+    pub three_ds_method_data: Option<String>,
+    /// The URL of the access control server to which the challenge request is to be posted
+    pub acs_url: Option<String>,
+    /// The challenge request to be posted to the access control server as `creq`
+    pub challenge_request: Option<String>,
+    /// The URL to which the authentication request is to be sent, once the 3DS method is completed or the challenge is answered
+    pub authentication_url: String,
 }
 
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
@@ -622,6 +669,8 @@ pub struct PaymentsResponse {
     /// List of refund that happened on this intent
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
+    /// List of attempts made for this payment, including the ones retried on a fallback connector
+    pub attempts: Option<Vec<PaymentAttemptResponse>>,
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -692,6 +741,32 @@ pub struct PaymentsResponse {
     pub error_message: Option<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentAttemptResponse {
+    /// Unique identifier for the attempt
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+    /// The status of the attempt
+    #[schema(value_type = AttemptStatus, example = "charged")]
+    pub status: api_enums::AttemptStatus,
+    /// The amount of the attempt
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The connector the attempt was made with
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// The error code received from the connector, if the attempt failed
+    #[schema(example = "card_declined")]
+    pub error_code: Option<String>,
+    /// The error message received from the connector, if the attempt failed
+    #[schema(example = "Your card was declined")]
+    pub error_message: Option<String>,
+    /// Time when the attempt was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentListConstraints {
@@ -723,12 +798,34 @@ pub struct PaymentListConstraints {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     #[serde(rename = "created.gte")]
     pub created_gte: Option<PrimitiveDateTime>,
+    /// The status of the payment
+    #[schema(value_type = Option<IntentStatus>, example = "succeeded")]
+    pub status: Option<api_enums::IntentStatus>,
+    /// The connector through which the payment was attempted
+    #[schema(value_type = Option<Connector>, example = "stripe")]
+    pub connector: Option<api_enums::Connector>,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The payment method with which the payment was attempted
+    #[schema(value_type = Option<PaymentMethodType>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethodType>,
+    /// The minimum amount of the payment, inclusive
+    #[serde(rename = "amount.gte")]
+    pub amount_gte: Option<i64>,
+    /// The maximum amount of the payment, inclusive
+    #[serde(rename = "amount.lte")]
+    pub amount_lte: Option<i64>,
+    /// A key which must be present in the metadata of the payment
+    pub metadata_key: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PaymentListResponse {
     /// The number of payments included in the list
     pub size: usize,
+    /// The total number of payments matching the filters, irrespective of the pagination
+    pub total_count: i64,
     // The list of payments response objects
     pub data: Vec<PaymentsResponse>,
 }
@@ -921,6 +1018,34 @@ impl From<PaymentsCancelRequest> for PaymentsResponse {
     }
 }
 
+impl From<PaymentsIncrementalAuthorizationRequest> for PaymentsResponse {
+    fn from(item: PaymentsIncrementalAuthorizationRequest) -> Self {
+        Self {
+            payment_id: Some(item.payment_id),
+            amount: item.amount,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PaymentsPreAuthenticateRequest> for PaymentsResponse {
+    fn from(item: PaymentsPreAuthenticateRequest) -> Self {
+        Self {
+            payment_id: Some(item.payment_id),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PaymentsAuthenticateRequest> for PaymentsResponse {
+    fn from(item: PaymentsAuthenticateRequest) -> Self {
+        Self {
+            payment_id: Some(item.payment_id),
+            ..Default::default()
+        }
+    }
+}
+
 impl From<PaymentsCaptureRequest> for PaymentsResponse {
     // After removing the request from the payments_to_payments_response this will no longer be needed
     fn from(item: PaymentsCaptureRequest) -> Self {
@@ -1172,6 +1297,43 @@ pub struct PaymentsCancelRequest {
     pub cancellation_reason: Option<String>,
 }
 
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsIncrementalAuthorizationRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The new total amount to be authorized for the payment, which has to be greater than the amount authorized so far
+    #[schema(example = 8000)]
+    pub amount: i64,
+}
+
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsPreAuthenticateRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The client secret of the payment, required when the request is made with the publishable key
+    pub client_secret: Option<String>,
+    /// The card to be authenticated, which is used to authorize the payment once authenticated
+    pub payment_method_data: PaymentMethod,
+}
+
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsAuthenticateRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The client secret of the payment, required when the request is made with the publishable key
+    pub client_secret: Option<String>,
+    /// The information of the browser of the customer, used by the issuer to assess the risk of the payment
+    pub browser_info: Option<serde_json::Value>,
+    /// Whether the 3DS method given in the next action was completed
+    #[serde(default)]
+    pub three_ds_method_completed: bool,
+    /// The challenge response `cres` received from the access control server, once the challenge is answered
+    pub challenge_response: Option<String>,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsStartRequest {
     /// Unique identifier for the payment. This ensures impotency for multiple payments
@@ -1 +1,219 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. If not provided, it is auto generated by the router
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub payout_id: Option<String>,
+
+    /// The payout amount. Amount for the payout in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc
+    #[schema(minimum = 100, example = 6540)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code in which the payout is to be made
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The identifier for the customer (the seller) receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The connector through which the payout is to be processed. If not provided, the merchant's routing algorithm is used
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The type of the payout destination
+    #[schema(value_type = PayoutType, example = "card")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The details of the payout destination. Required when the payout is to be fulfilled
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// Whether the payout is to be fulfilled with the connector immediately
+    #[schema(default = false, example = true)]
+    pub confirm: Option<bool>,
+
+    /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
+    #[schema(max_length = 255, example = "Payout for order #1234")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutUpdateRequest {
+    /// The payout amount. Amount for the payout in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc
+    #[schema(minimum = 100, example = 6540)]
+    pub amount: Option<i64>,
+
+    /// The three-letter ISO currency code in which the payout is to be made
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The identifier for the customer (the seller) receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The type of the payout destination
+    #[schema(value_type = Option<PayoutType>, example = "card")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// The details of the payout destination. Required when the payout is to be fulfilled
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// Whether the payout is to be fulfilled with the connector immediately
+    #[schema(default = false, example = true)]
+    pub confirm: Option<bool>,
+
+    /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
+    #[schema(max_length = 255, example = "Payout for order #1234")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(PayoutCard),
+    Bank(PayoutBank),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutCard {
+    /// The card number
+    #[schema(value_type = String, example = "4242424242424242")]
+    pub card_number: Secret<String, pii::CardNumber>,
+    /// The card's expiry month
+    #[schema(value_type = String, example = "24")]
+    pub card_exp_month: Secret<String>,
+    /// The card's expiry year
+    #[schema(value_type = String, example = "24")]
+    pub card_exp_year: Secret<String>,
+    /// The card holder's name
+    #[schema(value_type = String, example = "John Test")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBank {
+    /// International Bank Account Number (IBAN) of the beneficiary
+    #[schema(value_type = String, example = "NL46TEST0136169112")]
+    pub iban: Secret<String>,
+    /// Bank Identifier Code (BIC) of the beneficiary's bank
+    #[schema(value_type = Option<String>, example = "ABNANL2A")]
+    pub bic: Option<Secret<String>>,
+    /// Name of the beneficiary's bank
+    #[schema(example = "Deutsche Bank")]
+    pub bank_name: Option<String>,
+    /// Two-letter ISO country code of the beneficiary's bank
+    #[schema(example = "NL")]
+    pub bank_country_code: Option<String>,
+    /// Name of the account holder
+    #[schema(value_type = String, example = "John Test")]
+    pub account_holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// Unique identifier for the payout
+    pub payout_id: String,
+    /// The identifier for the Merchant Account
+    pub merchant_id: String,
+    /// The identifier for the customer receiving the payout
+    pub customer_id: Option<String>,
+    /// The payout amount in lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The connector through which the payout was processed
+    pub connector: Option<String>,
+    /// The identifier for the payout at the connector
+    pub connector_payout_id: Option<String>,
+    /// The type of the payout destination
+    #[schema(value_type = PayoutType)]
+    pub payout_type: api_enums::PayoutType,
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus)]
+    pub status: api_enums::PayoutStatus,
+    /// An arbitrary string attached to the object
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The error message, if the payout failed at the connector
+    pub error_message: Option<String>,
+    /// The error code, if the payout failed at the connector
+    pub error_code: Option<String>,
+    /// The masked card number or IBAN the payout was fulfilled to
+    #[schema(example = "************1111")]
+    pub payout_account: Option<String>,
+    /// The timestamp at which the payout was created
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutListRequest {
+    /// The identifier for the customer receiving the payouts
+    pub customer_id: Option<String>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list
+    pub size: usize,
+    /// The list of payouts
+    pub data: Vec<PayoutResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutAccountsListRequest {
+    /// The identifier for the customer receiving the payouts
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutAccount {
+    /// The type of the payout destination
+    #[schema(value_type = PayoutType)]
+    pub payout_type: api_enums::PayoutType,
+    /// The masked card number or IBAN of the payout destination
+    #[schema(example = "************1111")]
+    pub payout_account: String,
+    /// The identifier for the latest payout fulfilled to the account
+    pub last_payout_id: String,
+    /// The timestamp at which the latest payout to the account was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_used_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutAccountsListResponse {
+    /// The number of payout accounts included in the list
+    pub size: usize,
+    /// The list of payout accounts, the most recently used first
+    pub data: Vec<PayoutAccount>,
+}
@@ -0,0 +1,74 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the mandate against which the subscription is charged
+    #[schema(example = "man_5LMjXIgVb4Kjd2RLKhBJ")]
+    pub mandate_id: String,
+    /// The amount charged at every billing cycle, in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The unit of the interval at which the subscription is charged
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of intervals between two charges, defaults to 1
+    #[schema(example = 1)]
+    pub interval_count: Option<i32>,
+    /// Time at which the subscription is charged for the first time, defaults to the current time
+    #[schema(example = "2023-04-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_at: Option<PrimitiveDateTime>,
+    /// An arbitrary string attached to the object, which is also attached to the payments of the
+    /// subscription
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// Unique identifier for the subscription
+    #[schema(example = "sub_mbabizu24mvu3mela5nj")]
+    pub subscription_id: String,
+    /// The identifier for the Merchant Account
+    pub merchant_id: String,
+    /// The identifier for the customer being charged
+    pub customer_id: String,
+    /// The identifier for the mandate against which the subscription is charged
+    pub mandate_id: String,
+    /// The status of the subscription
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: api_enums::SubscriptionStatus,
+    /// The amount charged at every billing cycle, in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+    /// The unit of the interval at which the subscription is charged
+    #[schema(value_type = SubscriptionInterval)]
+    pub interval: api_enums::SubscriptionInterval,
+    /// The number of intervals between two charges
+    pub interval_count: i32,
+    /// Time at which the subscription is charged next, unless the subscription is paused or
+    /// cancelled
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_charge_at: Option<PrimitiveDateTime>,
+    /// The identifier for the payment made for the last charge of the subscription
+    pub last_payment_id: Option<String>,
+    /// An arbitrary string attached to the object
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The timestamp at which the subscription was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
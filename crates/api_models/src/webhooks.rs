@@ -1,27 +1,72 @@
-use common_utils::custom_serde;
+use common_utils::{
+    crypto::{self, SignMessage, VerifySignature},
+    custom_serde,
+    errors::{CryptoError, CustomResult},
+};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as api_enums, payments};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    PaymentIntentProcessing,
+    PaymentIntentCancelled,
+    PaymentIntentCaptured,
+    RefundSuccess,
+    RefundFailure,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    // dispute has been successfully challenged by the merchant
+    DisputeWon,
+    // dispute has been unsuccessfully challenged
+    DisputeLost,
+    MandateRevoked,
+    PayoutSuccess,
+    PayoutFailure,
+    PayoutCancelled,
+    // funds of a successful payout have been returned by the bank of the recipient
+    PayoutReversed,
 }
 
 pub enum WebhookFlow {
     Payment,
     Refund,
+    Dispute,
+    Mandate,
     Subscription,
+    Payout,
 }
 
 impl From<IncomingWebhookEvent> for WebhookFlow {
     fn from(evt: IncomingWebhookEvent) -> Self {
         match evt {
-            IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
-            IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::PaymentIntentFailure
+            | IncomingWebhookEvent::PaymentIntentSuccess
+            | IncomingWebhookEvent::PaymentIntentProcessing
+            | IncomingWebhookEvent::PaymentIntentCancelled
+            | IncomingWebhookEvent::PaymentIntentCaptured => Self::Payment,
+            IncomingWebhookEvent::RefundSuccess | IncomingWebhookEvent::RefundFailure => {
+                Self::Refund
+            }
+            IncomingWebhookEvent::DisputeOpened
+            | IncomingWebhookEvent::DisputeAccepted
+            | IncomingWebhookEvent::DisputeExpired
+            | IncomingWebhookEvent::DisputeCancelled
+            | IncomingWebhookEvent::DisputeChallenged
+            | IncomingWebhookEvent::DisputeWon
+            | IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::MandateRevoked => Self::Mandate,
+            IncomingWebhookEvent::PayoutSuccess
+            | IncomingWebhookEvent::PayoutFailure
+            | IncomingWebhookEvent::PayoutCancelled
+            | IncomingWebhookEvent::PayoutReversed => Self::Payout,
         }
     }
 }
@@ -47,4 +92,131 @@ pub struct OutgoingWebhook {
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
+    RefundDetails(refunds::RefundResponse),
+    DisputeDetails(Box<disputes::DisputeResponse>),
+    MandateDetails(mandates::MandateResponse),
+}
+
+/// Header carrying the hex encoded HMAC-SHA512 signature of an outgoing webhook
+pub const OUTGOING_WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature-512";
+
+/// Header carrying the UNIX timestamp (in seconds) at which an outgoing webhook was signed
+pub const OUTGOING_WEBHOOK_TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+
+/// The message signed for an outgoing webhook, made up of the signing timestamp and the raw
+/// request body separated by a `.`, so that a captured webhook cannot be replayed later
+fn get_outgoing_webhook_signature_message(timestamp: &str, body: &[u8]) -> Vec<u8> {
+    [timestamp.as_bytes(), b".", body].concat()
+}
+
+/// Sign the body of an outgoing webhook with the secret of the merchant, returning the hex encoded
+/// signature to be sent in the [`OUTGOING_WEBHOOK_SIGNATURE_HEADER`] header
+pub fn sign_outgoing_webhook(
+    secret: &[u8],
+    timestamp: &str,
+    body: &[u8],
+) -> CustomResult<String, CryptoError> {
+    let message = get_outgoing_webhook_signature_message(timestamp, body);
+    let signature = crypto::HmacSha512.sign_message(secret, &message)?;
+    Ok(hex::encode(signature))
+}
+
+/// Verify the signature of an outgoing webhook received by the merchant, given the values of the
+/// [`OUTGOING_WEBHOOK_SIGNATURE_HEADER`] and [`OUTGOING_WEBHOOK_TIMESTAMP_HEADER`] headers and the
+/// raw request body. Webhooks whose timestamp is more than `tolerance` away from the current time,
+/// in the past or in the future, are rejected.
+pub fn verify_outgoing_webhook_signature(
+    secret: &[u8],
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+    tolerance: time::Duration,
+) -> CustomResult<bool, CryptoError> {
+    let signed_at = match timestamp.parse::<i64>() {
+        Ok(signed_at) => signed_at,
+        Err(_) => return Ok(false),
+    };
+    let is_outside_tolerance = time::OffsetDateTime::now_utc()
+        .unix_timestamp()
+        .checked_sub(signed_at)
+        .map_or(true, |age| {
+            age.unsigned_abs() > tolerance.whole_seconds().unsigned_abs()
+        });
+    if is_outside_tolerance {
+        return Ok(false);
+    }
+
+    let signature = match hex::decode(signature) {
+        Ok(signature) => signature,
+        Err(_) => return Ok(false),
+    };
+    let message = get_outgoing_webhook_signature_message(timestamp, body);
+    crypto::HmacSha512.verify_signature(secret, &signature, &message)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const SECRET: &[u8] = b"webhook_secret_1234";
+    const BODY: &[u8] = br#"{"event_type":"payment_succeeded"}"#;
+
+    #[test]
+    fn test_outgoing_webhook_signature_verification() {
+        let timestamp = time::OffsetDateTime::now_utc().unix_timestamp().to_string();
+        let signature = sign_outgoing_webhook(SECRET, &timestamp, BODY).unwrap();
+        let tolerance = time::Duration::minutes(5);
+
+        assert!(
+            verify_outgoing_webhook_signature(SECRET, &signature, &timestamp, BODY, tolerance)
+                .unwrap()
+        );
+        assert!(!verify_outgoing_webhook_signature(
+            b"wrong_secret",
+            &signature,
+            &timestamp,
+            BODY,
+            tolerance
+        )
+        .unwrap());
+        assert!(!verify_outgoing_webhook_signature(
+            SECRET,
+            &signature,
+            &timestamp,
+            br#"{"event_type":"payment_failed"}"#,
+            tolerance
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_stale_outgoing_webhook_is_rejected() {
+        let timestamp = (time::OffsetDateTime::now_utc().unix_timestamp() - 600).to_string();
+        let signature = sign_outgoing_webhook(SECRET, &timestamp, BODY).unwrap();
+
+        assert!(!verify_outgoing_webhook_signature(
+            SECRET,
+            &signature,
+            &timestamp,
+            BODY,
+            time::Duration::minutes(5)
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_future_outgoing_webhook_is_rejected() {
+        let timestamp = (time::OffsetDateTime::now_utc().unix_timestamp() + 600).to_string();
+        let signature = sign_outgoing_webhook(SECRET, &timestamp, BODY).unwrap();
+
+        assert!(!verify_outgoing_webhook_signature(
+            SECRET,
+            &signature,
+            &timestamp,
+            BODY,
+            time::Duration::minutes(5)
+        )
+        .unwrap());
+    }
 }
@@ -2,7 +2,7 @@ pub mod types;
 
 use actix_web::{delete, get, post, web, HttpRequest, HttpResponse};
 use error_stack::report;
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
@@ -45,7 +45,7 @@ pub async fn customer_create(
         |state, merchant_account, req| {
             customers::create_customer(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersCreate),
     )
     .await
 }
@@ -77,7 +77,7 @@ pub async fn customer_retrieve(
         |state, merchant_account, req| {
             customers::retrieve_customer(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersRetrieve),
     )
     .await
 }
@@ -118,7 +118,7 @@ pub async fn customer_update(
         |state, merchant_account, req| {
             customers::update_customer(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersUpdate),
     )
     .await
 }
@@ -148,7 +148,7 @@ pub async fn customer_delete(
         &req,
         payload,
         customers::delete_customer,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersDelete),
     )
     .await
 }
@@ -176,7 +176,7 @@ pub async fn list_customer_payment_method_api(
         &req,
         customer_id.as_ref(),
         cards::list_customer_payment_method,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomerPaymentMethodsList),
     )
     .await
 }
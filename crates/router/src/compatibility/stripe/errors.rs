@@ -109,6 +109,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "duplicate payment method")]
     DuplicatePaymentMethod,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "duplicate config")]
+    DuplicateConfig,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "" , message = "deserialization failed: {error_message}")]
     SerdeQsError {
         error_message: String,
@@ -168,6 +171,32 @@ pub enum StripeErrorCode {
     PaymentIntentMandateInvalid { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
+    PayoutNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such dispute")]
+    DisputeNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The dispute could not be updated. {reason}")]
+    DisputeStatusValidationFailed { reason: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such subscription")]
+    SubscriptionNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The subscription could not be updated. {reason}")]
+    SubscriptionStatusValidationFailed { reason: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such file")]
+    FileNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "File is not available")]
+    FileNotAvailable,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "File validation failed. {reason}")]
+    FileValidationFailed { reason: String },
+    #[error(error_type = StripeErrorType::IdempotencyError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyReused,
+    #[error(error_type = StripeErrorType::IdempotencyError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this idempotency key")]
+    IdempotentRequestInProgress,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimitExceeded,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "IR_20", message = "The provided key does not have the required permissions for this endpoint")]
+    AccessForbidden,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -323,6 +352,7 @@ pub enum StripeErrorType {
     ApiError,
     CardError,
     InvalidRequestError,
+    IdempotencyError,
 }
 
 impl From<errors::ApiErrorResponse> for StripeErrorCode {
@@ -390,12 +420,23 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
+            errors::ApiErrorResponse::MandateAmountLimitExceeded => {
+                Self::PaymentIntentMandateInvalid {
+                    message: "The payment amount exceeds the amount remaining on the mandate"
+                        .to_string(),
+                }
+            }
+            errors::ApiErrorResponse::MandateNotInEffect { reason }
+            | errors::ApiErrorResponse::MandateChargeFrequencyExceeded { reason } => {
+                Self::PaymentIntentMandateInvalid { message: reason }
+            }
             errors::ApiErrorResponse::ReturnUrlUnavailable => Self::ReturnUrlUnavailable,
             errors::ApiErrorResponse::DuplicateMerchantAccount => Self::DuplicateMerchantAccount,
             errors::ApiErrorResponse::DuplicateMerchantConnectorAccount => {
                 Self::DuplicateMerchantConnectorAccount
             }
             errors::ApiErrorResponse::DuplicatePaymentMethod => Self::DuplicatePaymentMethod,
+            errors::ApiErrorResponse::DuplicateConfig => Self::DuplicateConfig, // not a stripe code
             errors::ApiErrorResponse::ClientSecretInvalid => Self::PaymentIntentInvalidParameter {
                 param: "client_secret".to_owned(),
             },
@@ -429,6 +470,29 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
+            errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::DisputeNotFound { .. } => Self::DisputeNotFound,
+            errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
+                Self::DisputeStatusValidationFailed { reason }
+            }
+            errors::ApiErrorResponse::SubscriptionNotFound => Self::SubscriptionNotFound,
+            errors::ApiErrorResponse::SubscriptionStatusValidationFailed { reason } => {
+                Self::SubscriptionStatusValidationFailed { reason }
+            }
+            errors::ApiErrorResponse::FileNotFound => Self::FileNotFound,
+            errors::ApiErrorResponse::FileNotAvailable => Self::FileNotAvailable,
+            errors::ApiErrorResponse::FileValidationFailed { reason } => {
+                Self::FileValidationFailed { reason }
+            }
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::IdempotentRequestInProgress => {
+                Self::IdempotentRequestInProgress
+            }
+            errors::ApiErrorResponse::RateLimitExceeded { .. } => Self::RateLimitExceeded,
+            errors::ApiErrorResponse::AccessForbidden => Self::AccessForbidden,
         }
     }
 }
@@ -461,6 +525,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
+            | Self::DuplicateConfig
             | Self::PaymentFailed
             | Self::VerificationFailed { .. }
             | Self::MaximumRefundCount
@@ -474,7 +539,19 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ResourceIdNotFound
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
-            | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST,
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. }
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::SubscriptionNotFound
+            | Self::SubscriptionStatusValidationFailed { .. }
+            | Self::FileNotFound
+            | Self::FileNotAvailable
+            | Self::FileValidationFailed { .. } => StatusCode::BAD_REQUEST,
+            Self::IdempotencyKeyReused | Self::IdempotentRequestInProgress => StatusCode::CONFLICT,
+            Self::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
+            Self::AccessForbidden => StatusCode::FORBIDDEN,
             Self::RefundFailed
             | Self::InternalServerError
             | Self::MandateActive
@@ -3,7 +3,7 @@ pub mod types;
 use actix_web::{get, post, web, HttpRequest, HttpResponse};
 use api_models::payments as payment_types;
 use error_stack::report;
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
@@ -57,7 +57,7 @@ pub async fn payment_intents_create(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsCreate),
     )
     .await
 }
@@ -77,10 +77,11 @@ pub async fn payment_intents_retrieve(
         param: None,
     };
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Flow::PaymentsRetrieve) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -136,10 +137,11 @@ pub async fn payment_intents_update(
 
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Flow::PaymentsUpdate) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -196,11 +198,14 @@ pub async fn payment_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Flow::PaymentsConfirm,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -275,7 +280,7 @@ pub async fn payment_intents_capture(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsCapture),
     )
     .await
 }
@@ -302,10 +307,11 @@ pub async fn payment_intents_cancel(
     let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
     payload.payment_id = payment_id;
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Flow::PaymentsCancel) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -363,7 +369,7 @@ pub async fn payment_intent_list(
         |state, merchant_account, req| {
             payments::list_payments(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsList),
     )
     .await
 }
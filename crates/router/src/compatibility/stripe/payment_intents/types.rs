@@ -335,6 +335,13 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            status: None,
+            connector: None,
+            currency: None,
+            payment_method: None,
+            amount_gte: None,
+            amount_lte: None,
+            metadata_key: None,
         })
     }
 }
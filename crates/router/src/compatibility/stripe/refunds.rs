@@ -1,7 +1,7 @@
 pub mod types;
 
 use actix_web::{get, post, web, HttpRequest, HttpResponse};
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
@@ -35,7 +35,7 @@ pub async fn refund_create(
         &req,
         create_refund_req,
         refunds::refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsCreate),
     )
     .await
 }
@@ -69,7 +69,7 @@ pub async fn refund_retrieve(
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsRetrieve),
     )
     .await
 }
@@ -102,7 +102,7 @@ pub async fn refund_update(
         |state, merchant_account, req| {
             refunds::refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsUpdate),
     )
     .await
 }
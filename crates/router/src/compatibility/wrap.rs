@@ -6,7 +6,10 @@ use router_env::{instrument, tracing};
 use serde::Serialize;
 
 use crate::{
-    core::errors::{self, RouterResult},
+    core::{
+        errors::{self, RouterResult},
+        idempotency,
+    },
     routes::app::AppStateInfo,
     services::{api, authentication as auth, logger},
 };
@@ -27,8 +30,41 @@ where
     E: From<errors::ApiErrorResponse> + Serialize + error_stack::Context + actix_web::ResponseError,
     T: std::fmt::Debug,
     A: AppStateInfo,
+    U: auth::AuthInfo,
+{
+    match idempotency::get_idempotency_key(request) {
+        // The response is stored in the format of Stripe, to be replayed as it was first served
+        Some(idempotency_key) => api::idempotent_server_wrap_util(
+            state,
+            request,
+            payload,
+            func,
+            api_authentication,
+            idempotency_key,
+            build_compatibility_response::<Q, S, E>,
+        )
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(api_response_error=?error);
+            let pg_error = E::from(error.current_context().clone());
+            api::log_and_return_error_response(report!(pg_error))
+        }),
+        None => build_compatibility_response::<Q, S, E>(
+            request,
+            api::server_wrap_util(state, request, payload, func, api_authentication).await,
+        ),
+    }
+}
+
+fn build_compatibility_response<Q, S, E>(
+    request: &HttpRequest,
+    resp: RouterResult<api::ApplicationResponse<Q>>,
+) -> HttpResponse
+where
+    Q: Serialize,
+    S: From<Q> + Serialize,
+    E: From<errors::ApiErrorResponse> + Serialize + error_stack::Context + actix_web::ResponseError,
 {
-    let resp = api::server_wrap_util(state, request, payload, func, api_authentication).await;
     match resp {
         Ok(api::ApplicationResponse::Json(router_resp)) => {
             let pg_resp = S::try_from(router_resp);
@@ -69,6 +105,9 @@ where
         Ok(api::ApplicationResponse::Form(form_data)) => api::build_redirection_form(&form_data)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
+            api::http_response_file_data(file_data, content_type)
+        }
         Err(error) => {
             logger::error!(api_response_error=?error);
             let pg_error = E::from(error.current_context().clone());
@@ -43,6 +43,111 @@ impl Default for super::settings::Locker {
     }
 }
 
+impl Default for super::settings::WebhooksSettings {
+    fn default() -> Self {
+        Self {
+            outgoing_enabled: false,
+            outgoing_max_retries: 5,
+            outgoing_retry_base_interval_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            // 24 hours
+            key_ttl_secs: 86400,
+            // The secrets must be configured, there being no safe default for them
+            fingerprint_secret: String::new(),
+            response_encryption_key: String::new(),
+        }
+    }
+}
+
+impl Default for super::settings::FileUploadSettings {
+    fn default() -> Self {
+        Self {
+            // 5 MB, the largest file size supported by the connectors
+            max_file_size: 5_000_000,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            merchant: super::settings::TokenBucket {
+                capacity: 200,
+                refill_rate: 100.0,
+            },
+            api_key: super::settings::TokenBucket {
+                capacity: 100,
+                refill_rate: 50.0,
+            },
+            routes: Default::default(),
+        }
+    }
+}
+
+impl Default for super::settings::AccessTokenSettings {
+    fn default() -> Self {
+        Self {
+            refresh_before_expiry_secs: 60,
+            lock_ttl_secs: 10,
+            lock_wait_retries: 20,
+            lock_wait_interval_ms: 100,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorHealthSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_secs: 60,
+            bucket_secs: 10,
+            min_calls: 20,
+            max_failure_rate: 0.5,
+            max_average_latency_ms: 10000,
+            cooldown_secs: 30,
+            override_ttl_secs: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ExportSettings {
+    fn default() -> Self {
+        Self {
+            batch_size: 1000,
+            max_range_days: 31,
+        }
+    }
+}
+
+impl Default for super::settings::ApiKeysSettings {
+    fn default() -> Self {
+        Self {
+            cache_ttl_secs: 300,
+            last_used_update_interval_secs: 60,
+            legacy_merchant_keys_enabled: true,
+        }
+    }
+}
+
+impl Default for super::settings::SubscriptionSettings {
+    fn default() -> Self {
+        Self {
+            dunning_max_retries: 3,
+            // 1 day
+            dunning_retry_base_interval_secs: 86400,
+            // 1 hour
+            pending_charge_check_interval_secs: 3600,
+        }
+    }
+}
+
 impl Default for super::settings::SupportedConnectors {
     fn default() -> Self {
         Self {
@@ -121,3 +226,11 @@ impl Default for super::settings::DrainerSettings {
         }
     }
 }
+
+impl Default for super::settings::FileStorageConfig {
+    fn default() -> Self {
+        Self::FileSystem {
+            path: "files".into(),
+        }
+    }
+}
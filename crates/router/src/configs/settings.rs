@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
 use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
@@ -51,6 +54,16 @@ pub struct Settings {
     pub drainer: DrainerSettings,
     pub jwekey: Jwekey,
     pub webhooks: WebhooksSettings,
+    pub file_storage: FileStorageConfig,
+    pub file_upload: FileUploadSettings,
+    pub auto_retries: AutoRetries,
+    pub idempotency: IdempotencySettings,
+    pub rate_limit: RateLimitSettings,
+    pub access_token: AccessTokenSettings,
+    pub connector_health: ConnectorHealthSettings,
+    pub export: ExportSettings,
+    pub api_keys: ApiKeysSettings,
+    pub subscriptions: SubscriptionSettings,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -135,7 +148,7 @@ pub struct SupportedConnectors {
 #[serde(default)]
 pub struct Connectors {
     pub aci: ConnectorParams,
-    pub adyen: ConnectorParams,
+    pub adyen: ConnectorParamsWithSecondaryBaseUrl,
     pub applepay: ConnectorParams,
     pub authorizedotnet: ConnectorParams,
     pub braintree: ConnectorParams,
@@ -144,10 +157,11 @@ pub struct Connectors {
     pub fiserv: ConnectorParams,
     pub globalpay: ConnectorParams,
     pub klarna: ConnectorParams,
+    pub mock_three_ds: ConnectorParams,
     pub payu: ConnectorParams,
     pub rapyd: ConnectorParams,
     pub shift4: ConnectorParams,
-    pub stripe: ConnectorParams,
+    pub stripe: ConnectorParamsWithSecondaryBaseUrl,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
 
@@ -161,6 +175,13 @@ pub struct ConnectorParams {
     pub base_url: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithSecondaryBaseUrl {
+    pub base_url: String,
+    pub secondary_base_url: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
@@ -198,10 +219,143 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
+    /// Maximum number of times the delivery of an outgoing webhook is retried
+    pub outgoing_max_retries: u32,
+    /// Interval before the first retry of an outgoing webhook, doubled after every retry
+    pub outgoing_retry_base_interval_secs: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Duration for which the response to a request is replayed for the same idempotency key
+    pub key_ttl_secs: i64,
+    /// Secret keying the fingerprints of the requests sent with an idempotency key
+    pub fingerprint_secret: String,
+    /// Hex encoded AES-256 key encrypting the responses stored for idempotency keys
+    pub response_encryption_key: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "file_storage_backend", rename_all = "snake_case")]
+pub enum FileStorageConfig {
+    /// Store the files in a directory on the local filesystem
+    FileSystem { path: String },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FileUploadSettings {
+    /// Maximum size in bytes of the files uploaded by merchants, the upload being rejected once
+    /// the file exceeds it
+    pub max_file_size: usize,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AutoRetries {
+    /// Error codes returned by each connector which are soft declines, upon which the payment is
+    /// retried on a fallback connector
+    pub retriable_error_codes: HashMap<String, HashSet<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    /// Whether the API requests of merchants are rate limited
+    pub enabled: bool,
+    /// Limit on all the API requests of a merchant
+    pub merchant: TokenBucket,
+    /// Limit on the API requests made with a single API key
+    pub api_key: TokenBucket,
+    /// Limits on the API requests of a merchant to a route, keyed by the HTTP method and the path
+    /// of the route, such as `POST /payments/{payment_id}/capture`
+    pub routes: HashMap<String, TokenBucket>,
+}
+
+/// Token bucket allowing bursts of requests up to its capacity, refilled at a constant rate
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct TokenBucket {
+    /// Maximum number of requests allowed in a burst
+    pub capacity: u32,
+    /// Number of requests replenished every second
+    pub refill_rate: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AccessTokenSettings {
+    /// Number of seconds before the expiry of an access token after which it is refreshed, so
+    /// that connectors are never called with a token about to expire
+    pub refresh_before_expiry_secs: i64,
+    /// Number of seconds for which a single request is allowed to refresh an access token, while
+    /// the concurrent requests wait for the refreshed token
+    pub lock_ttl_secs: i64,
+    /// Number of times a request checks for the access token being refreshed by another request,
+    /// before refreshing the access token itself
+    pub lock_wait_retries: u32,
+    /// Number of milliseconds between the checks for the access token being refreshed
+    pub lock_wait_interval_ms: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConnectorHealthSettings {
+    /// Whether the calls to connectors are tracked, to skip unhealthy connectors when routing
+    pub enabled: bool,
+    /// Number of seconds of the rolling window over which the calls to a connector are tracked
+    pub window_secs: u64,
+    /// Number of seconds of each of the buckets making up the rolling window
+    pub bucket_secs: u64,
+    /// Minimum number of calls within the window before the circuit breaker can open
+    pub min_calls: u64,
+    /// Ratio of failed calls within the window above which the circuit breaker opens
+    pub max_failure_rate: f64,
+    /// Average latency of the calls within the window above which the circuit breaker opens
+    pub max_average_latency_ms: u64,
+    /// Number of seconds for which the circuit breaker stays open, before the connector is tried
+    /// again
+    pub cooldown_secs: u64,
+    /// Number of seconds after which the health of a connector overridden by an administrator is
+    /// tracked by the circuit breaker again, unless another duration is requested
+    pub override_ttl_secs: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ExportSettings {
+    /// Number of records read from the database at a time while exporting records
+    pub batch_size: i64,
+    /// Maximum number of days of records which can be exported at once
+    pub max_range_days: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ApiKeysSettings {
+    /// Number of seconds for which a verified API key is cached
+    pub cache_ttl_secs: i64,
+    /// Minimum number of seconds between updates of the last used time of an API key
+    pub last_used_update_interval_secs: i64,
+    /// Whether merchants can authenticate with the API keys stored in plaintext in their merchant
+    /// accounts, which are migrated to hashed API keys as they are used
+    pub legacy_merchant_keys_enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionSettings {
+    /// Maximum number of times a failed charge of a subscription is retried, before the
+    /// subscription is cancelled
+    pub dunning_max_retries: u32,
+    /// Interval before the first retry of a failed charge, doubled after every retry
+    pub dunning_retry_base_interval_secs: i64,
+    /// Interval after which a charge which is still being processed is checked again
+    pub pending_charge_check_interval_secs: i64,
 }
 
 impl Settings {
@@ -278,6 +432,15 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.jwekey.validate()?;
+        self.file_storage.validate()?;
+        self.webhooks.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
+        self.access_token.validate()?;
+        self.connector_health.validate()?;
+        self.export.validate()?;
+        self.api_keys.validate()?;
+        self.subscriptions.validate()?;
 
         Ok(())
     }
@@ -142,6 +142,22 @@ impl super::settings::ConnectorParams {
     }
 }
 
+impl super::settings::ConnectorParamsWithSecondaryBaseUrl {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.secondary_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector secondary base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::SchedulerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -184,3 +200,191 @@ impl super::settings::DrainerSettings {
         })
     }
 }
+
+impl super::settings::FileStorageConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        match self {
+            Self::FileSystem { path } => when(path.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "file storage path must not be empty".into(),
+                ))
+            }),
+        }
+    }
+}
+
+impl super::settings::WebhooksSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.outgoing_retry_base_interval_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "outgoing webhook retry interval must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.key_ttl_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency key TTL must be positive".into(),
+            ))
+        })?;
+
+        when(self.fingerprint_secret.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency fingerprint secret must not be empty".into(),
+            ))
+        })?;
+
+        let response_encryption_key = hex::decode(&self.response_encryption_key).map_err(|_| {
+            ApplicationError::InvalidConfigurationValueError(
+                "idempotency response encryption key must be hex encoded".into(),
+            )
+        })?;
+        when(response_encryption_key.len() != 32, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency response encryption key must be 32 bytes long".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.merchant.validate()?;
+        self.api_key.validate()?;
+        self.routes
+            .values()
+            .try_for_each(super::settings::TokenBucket::validate)
+    }
+}
+
+impl super::settings::TokenBucket {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.capacity == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit capacity must be positive".into(),
+            ))
+        })?;
+
+        when(self.refill_rate <= 0.0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit refill rate must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::AccessTokenSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.refresh_before_expiry_secs < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "access token refresh before expiry must not be negative".into(),
+            ))
+        })?;
+
+        when(self.lock_ttl_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "access token lock TTL must be positive".into(),
+            ))
+        })?;
+
+        when(self.lock_wait_interval_ms == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "access token lock wait interval must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorHealthSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.bucket_secs == 0 || self.window_secs < self.bucket_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector health window must be at least one positive bucket long".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.max_failure_rate <= 0.0 || self.max_failure_rate > 1.0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector health maximum failure rate must lie in (0, 1]".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.cooldown_secs == 0 || self.override_ttl_secs <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector health cooldown and override TTL must be positive".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ExportSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size <= 0 || self.max_range_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "export batch size and maximum range must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ApiKeysSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.cache_ttl_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key cache TTL must be positive".into(),
+            ))
+        })?;
+
+        when(self.last_used_update_interval_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key last used update interval must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SubscriptionSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.dunning_retry_base_interval_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "subscription dunning retry interval must be positive".into(),
+            ))
+        })?;
+
+        when(self.pending_charge_check_interval_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "subscription pending charge check interval must be positive".into(),
+            ))
+        })
+    }
+}
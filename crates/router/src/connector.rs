@@ -8,6 +8,7 @@ pub mod cybersource;
 pub mod fiserv;
 pub mod globalpay;
 pub mod klarna;
+pub mod mock_three_ds;
 pub mod payu;
 pub mod rapyd;
 pub mod shift4;
@@ -19,6 +20,6 @@ pub mod worldpay;
 pub use self::{
     aci::Aci, adyen::Adyen, applepay::Applepay, authorizedotnet::Authorizedotnet,
     braintree::Braintree, checkout::Checkout, cybersource::Cybersource, fiserv::Fiserv,
-    globalpay::Globalpay, klarna::Klarna, payu::Payu, rapyd::Rapyd, shift4::Shift4, stripe::Stripe,
-    worldline::Worldline, worldpay::Worldpay,
+    globalpay::Globalpay, klarna::Klarna, mock_three_ds::MockThreeDs, payu::Payu, rapyd::Rapyd,
+    shift4::Shift4, stripe::Stripe, worldline::Worldline, worldpay::Worldpay,
 };
@@ -50,6 +50,42 @@ impl api::PaymentAuthorize for Aci {}
 impl api::PaymentSync for Aci {}
 impl api::PaymentVoid for Aci {}
 impl api::PaymentCapture for Aci {}
+impl api::PaymentIncrementalAuthorization for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Aci {}
 impl api::ConnectorAccessToken for Aci {}
 
@@ -526,6 +562,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Aci {}
+impl api::PayoutFulfill for Aci {}
+impl api::PayoutCancel for Aci {}
+impl api::PayoutReverse for Aci {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::ConnectorMandateRevoke for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Aci
+{
+}
+
+impl api::ConnectorCustomer for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Aci
+{
+}
+
+impl api::Dispute for Aci {}
+impl api::AcceptDispute for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Aci
+{
+}
+
+impl api::SubmitEvidence for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Aci
+{
+}
+
+impl api::FileUpload for Aci {}
+impl api::UploadFile for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Aci
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Aci {
     fn get_webhook_object_reference_id(
@@ -75,6 +75,137 @@ impl
     // Issue: #173
 }
 
+impl api::PaymentIncrementalAuthorization for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+        Ok(format!(
+            "{}{}/{}/amountUpdates",
+            self.base_url(connectors),
+            "v68/payments",
+            id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenAmountUpdateRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenAmountUpdateRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: adyen::AdyenAmountUpdateResponse = res
+            .response
+            .parse_struct("AdyenAmountUpdateResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl api::PaymentPreAuthenticate for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Adyen {}
 
 impl
@@ -289,8 +420,12 @@ impl
             .response
             .parse_struct("AdyenPaymentResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        let is_manual_capture =
-            data.request.capture_method == Some(storage_enums::CaptureMethod::Manual);
+        let is_manual_capture = matches!(
+            data.request.capture_method,
+            Some(
+                storage_enums::CaptureMethod::Manual | storage_enums::CaptureMethod::ManualMultiple
+            )
+        );
         types::RouterData::try_from((
             types::ResponseRouterData {
                 response,
@@ -402,8 +537,13 @@ impl
             .response
             .parse_struct("AdyenPaymentResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        let is_manual_capture =
-            data.request.capture_method == Some(storage_models::enums::CaptureMethod::Manual);
+        let is_manual_capture = matches!(
+            data.request.capture_method,
+            Some(
+                storage_models::enums::CaptureMethod::Manual
+                    | storage_models::enums::CaptureMethod::ManualMultiple
+            )
+        );
         types::RouterData::try_from((
             types::ResponseRouterData {
                 response,
@@ -637,6 +777,410 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Adyen {}
+impl api::PayoutFulfill for Adyen {}
+impl api::PayoutCancel for Adyen {}
+impl api::PayoutReverse for Adyen {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let endpoint = match req.request.payout_type {
+            storage_enums::PayoutType::Card => "payout",
+            storage_enums::PayoutType::Bank => "storeDetailAndSubmitThirdParty",
+        };
+        Ok(format!(
+            "{}{}{}",
+            connectors.adyen.secondary_base_url, "pal/servlet/Payout/v68/", endpoint
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutFulfillRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenPayoutFulfillRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+                .headers(types::PayoutFulfillType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutFulfillType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            connectors.adyen.secondary_base_url, "pal/servlet/Payout/v68/declineThirdParty"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutCancelRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCancelRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCancelType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCancel>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCancel>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoReverse>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // Paid out payouts are reversed through the modifications of the Payment API, like
+        // the payments they are made of
+        Ok(format!(
+            "{}{}",
+            connectors.adyen.secondary_base_url, "pal/servlet/Payment/v68/cancelOrRefund"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutReverseRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenPayoutReverseRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutReverseType::get_url(self, req, connectors)?)
+                .headers(types::PayoutReverseType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutReverseType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoReverse>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoReverse>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl api::ConnectorMandateRevoke for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.common_get_content_type().to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            connectors.adyen.secondary_base_url, "pal/servlet/Recurring/v68/disable"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::MandateRevokeRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenDisableRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenDisableRequest>::encode_to_string_of_json(&connector_req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::MandateRevokeType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::MandateRevokeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::MandateRevokeRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenDisableResponse = res
+            .response
+            .parse_struct("AdyenDisableResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl api::ConnectorCustomer for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Adyen
+{
+}
+
 fn get_webhook_object_from_body(
     body: &[u8],
 ) -> CustomResult<adyen::AdyenNotificationRequestItemWH, errors::ParsingError> {
@@ -652,6 +1196,41 @@ fn get_webhook_object_from_body(
     Ok(item_object.notification_request_item)
 }
 
+impl api::Dispute for Adyen {}
+impl api::AcceptDispute for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Adyen
+{
+}
+
+impl api::SubmitEvidence for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Adyen
+{
+}
+
+impl api::FileUpload for Adyen {}
+impl api::UploadFile for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Adyen
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Adyen {
     fn get_webhook_source_verification_algorithm(
@@ -726,6 +1305,21 @@ impl api::IncomingWebhook for Adyen {
         let notif = get_webhook_object_from_body(body)
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
 
+        // Dispute notifications carry the disputed payment in `originalReference`
+        if adyen::get_dispute_event_type(notif.event_code.as_str()).is_some() {
+            return notif
+                .original_reference
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+                .into_report();
+        }
+        // Returned payouts are notified as a modification of the payout, in `originalReference`
+        if notif.event_code == "PAIDOUT_REVERSED" {
+            return notif
+                .original_reference
+                .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+                .into_report();
+        }
+
         Ok(notif.psp_reference)
     }
 
@@ -738,7 +1332,13 @@ impl api::IncomingWebhook for Adyen {
 
         Ok(match notif.event_code.as_str() {
             "AUTHORISATION" => api::IncomingWebhookEvent::PaymentIntentSuccess,
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+            // The `pspReference` of refund notifications is the reference of the refund itself
+            "REFUND" if notif.success == "true" => api::IncomingWebhookEvent::RefundSuccess,
+            "REFUND" | "REFUND_FAILED" => api::IncomingWebhookEvent::RefundFailure,
+            event_code => adyen::get_dispute_event_type(event_code)
+                .or_else(|| adyen::get_payout_event_type(event_code, &notif.success))
+                .ok_or(errors::ConnectorError::WebhookEventTypeNotFound)
+                .into_report()?,
         })
     }
 
@@ -766,6 +1366,16 @@ impl api::IncomingWebhook for Adyen {
             "[accepted]".to_string(),
         ))
     }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(api::DisputePayload::from(notif))
+    }
 }
 
 impl services::ConnectorRedirectResponse for Adyen {
@@ -776,3 +1386,92 @@ impl services::ConnectorRedirectResponse for Adyen {
         Ok(payments::CallConnectorAction::Trigger)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    fn get_dispute_webhook_body(event_code: &str, dispute_status: Option<&str>) -> Vec<u8> {
+        serde_json::json!({
+            "notificationItems": [{
+                "NotificationRequestItem": {
+                    "additionalData": {
+                        "hmacSignature": "signature",
+                        "chargebackReasonCode": "10.4",
+                        "disputeStatus": dispute_status,
+                        "defensePeriodEndsAt": "2023-04-07T10:40:00Z"
+                    },
+                    "amount": { "value": 1000, "currency": "EUR" },
+                    "originalReference": "original_reference",
+                    "pspReference": "psp_reference",
+                    "eventCode": event_code,
+                    "merchantAccountCode": "merchant_account",
+                    "merchantReference": "merchant_reference",
+                    "success": "true",
+                    "reason": "Other Fraud-Card Absent Environment",
+                    "eventDate": "2023-03-28T10:40:00Z"
+                }
+            }]
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_get_dispute_details() {
+        let body = get_dispute_webhook_body("CHARGEBACK", Some("Undefended"));
+        let dispute_details = Adyen.get_dispute_details(&body).unwrap();
+
+        assert_eq!(dispute_details.amount, "1000");
+        assert_eq!(dispute_details.currency, "EUR");
+        assert_eq!(
+            dispute_details.dispute_stage,
+            storage_enums::DisputeStage::Dispute
+        );
+        assert_eq!(dispute_details.connector_status, "Undefended");
+        assert_eq!(dispute_details.connector_dispute_id, "psp_reference");
+        assert_eq!(
+            dispute_details.connector_reason_code.as_deref(),
+            Some("10.4")
+        );
+        assert_eq!(
+            dispute_details.challenge_required_by,
+            Some(time::macros::datetime!(2023-04-07 10:40:00))
+        );
+        assert_eq!(
+            dispute_details.created_at,
+            Some(time::macros::datetime!(2023-03-28 10:40:00))
+        );
+    }
+
+    #[test]
+    fn test_get_dispute_details_stage() {
+        // The event code is the connector status when the dispute status is not sent
+        let body = get_dispute_webhook_body("NOTIFICATION_OF_CHARGEBACK", None);
+        let dispute_details = Adyen.get_dispute_details(&body).unwrap();
+        assert_eq!(
+            dispute_details.dispute_stage,
+            storage_enums::DisputeStage::PreDispute
+        );
+        assert_eq!(
+            dispute_details.connector_status,
+            "NOTIFICATION_OF_CHARGEBACK"
+        );
+
+        let body = get_dispute_webhook_body("PREARBITRATION_LOST", None);
+        let dispute_details = Adyen.get_dispute_details(&body).unwrap();
+        assert_eq!(
+            dispute_details.dispute_stage,
+            storage_enums::DisputeStage::PreArbitration
+        );
+    }
+
+    #[test]
+    fn test_get_dispute_details_without_notification_items() {
+        assert!(Adyen
+            .get_dispute_details(br#"{"notificationItems": []}"#)
+            .is_err());
+    }
+}
@@ -90,6 +90,10 @@ pub struct AdyenPaymentRequest {
     shopper_interaction: AdyenShopperInteraction,
     #[serde(skip_serializing_if = "Option::is_none")]
     recurring_processing_model: Option<AdyenRecurringModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shopper_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store_payment_method: Option<bool>,
     additional_data: Option<AdditionalData>,
     shopper_name: Option<ShopperName>,
     shopper_email: Option<Secret<String, Email>>,
@@ -98,6 +102,23 @@ pub struct AdyenPaymentRequest {
     delivery_address: Option<Address>,
     country_code: Option<String>,
     line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpi_data: Option<AdyenMpiData>,
+}
+
+/// Outcome of the 3DS authentication of the shopper performed by the router, for Adyen to
+/// authorize the payment without authenticating the shopper again
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdyenMpiData {
+    cavv: Secret<String>,
+    eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+    #[serde(rename = "threeDSVersion")]
+    three_ds_version: String,
+    directory_response: String,
+    authentication_response: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -180,6 +201,13 @@ pub struct AdyenResponse {
     merchant_reference: String,
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
+    additional_data: Option<AdyenResponseAdditionalData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdyenResponseAdditionalData {
+    #[serde(rename = "recurring.recurringDetailReference")]
+    recurring_detail_reference: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -369,6 +397,15 @@ fn get_recurring_processing_model(
     }
 }
 
+// Adyen stores the payment method against the shopper reference, so that it can be used for
+// recurring payments and disabled when the mandate is revoked
+fn get_shopper_reference(item: &types::PaymentsAuthorizeRouterData) -> Option<String> {
+    match item.request.setup_future_usage {
+        Some(storage_enums::FutureUsage::OffSession) => item.request.customer_id.clone(),
+        _ => None,
+    }
+}
+
 fn get_browser_info(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenBrowserInfo> {
     if matches!(item.auth_type, storage_enums::AuthenticationType::ThreeDs) {
         item.request
@@ -389,9 +426,36 @@ fn get_browser_info(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenBr
     }
 }
 
+fn get_mpi_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<Option<AdyenMpiData>, error_stack::Report<errors::ConnectorError>> {
+    item.request
+        .three_ds_authentication
+        .as_ref()
+        .map(|authentication| {
+            Ok(AdyenMpiData {
+                cavv: authentication.authentication_value.clone().ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "three_ds_authentication.authentication_value",
+                    },
+                )?,
+                eci: authentication.eci.clone(),
+                ds_trans_id: authentication.ds_transaction_id.clone(),
+                three_ds_version: authentication.message_version.clone(),
+                // Only successful authentications are forwarded to the connector
+                directory_response: "Y".to_string(),
+                authentication_response: "Y".to_string(),
+            })
+        })
+        .transpose()
+}
+
 fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<AdditionalData> {
     match item.request.capture_method {
-        Some(storage_models::enums::CaptureMethod::Manual) => Some(AdditionalData {
+        Some(
+            storage_models::enums::CaptureMethod::Manual
+            | storage_models::enums::CaptureMethod::ManualMultiple,
+        ) => Some(AdditionalData {
             authorisation_type: AuthType::PreAuth,
             manual_capture: true,
         }),
@@ -547,10 +611,13 @@ fn get_card_specific_payment_data(
     let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
     let shopper_interaction = AdyenShopperInteraction::from(item);
     let recurring_processing_model = get_recurring_processing_model(item);
+    let shopper_reference = get_shopper_reference(item);
+    let store_payment_method = shopper_reference.is_some().then_some(true);
     let browser_info = get_browser_info(item);
     let additional_data = get_additional_data(item);
     let return_url = item.get_return_url()?;
     let payment_method = get_payment_method_data(item)?;
+    let mpi_data = get_mpi_data(item)?;
     Ok(AdyenPaymentRequest {
         amount,
         merchant_account: auth_type.merchant_account,
@@ -559,6 +626,8 @@ fn get_card_specific_payment_data(
         return_url,
         shopper_interaction,
         recurring_processing_model,
+        shopper_reference,
+        store_payment_method,
         browser_info,
         additional_data,
         telephone_number: None,
@@ -568,6 +637,7 @@ fn get_card_specific_payment_data(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        mpi_data,
     })
 }
 
@@ -581,6 +651,8 @@ fn get_wallet_specific_payment_data(
     let payment_method = get_payment_method_data(item)?;
     let shopper_interaction = AdyenShopperInteraction::from(item);
     let recurring_processing_model = get_recurring_processing_model(item);
+    let shopper_reference = get_shopper_reference(item);
+    let store_payment_method = shopper_reference.is_some().then_some(true);
     let return_url = item.get_return_url()?;
     Ok(AdyenPaymentRequest {
         amount,
@@ -590,6 +662,8 @@ fn get_wallet_specific_payment_data(
         return_url,
         shopper_interaction,
         recurring_processing_model,
+        shopper_reference,
+        store_payment_method,
         browser_info,
         additional_data,
         telephone_number: None,
@@ -599,6 +673,7 @@ fn get_wallet_specific_payment_data(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        mpi_data: None,
     })
 }
 
@@ -612,6 +687,8 @@ fn get_paylater_specific_payment_data(
     let payment_method = get_payment_method_data(item)?;
     let shopper_interaction = AdyenShopperInteraction::from(item);
     let recurring_processing_model = get_recurring_processing_model(item);
+    let shopper_reference = get_shopper_reference(item);
+    let store_payment_method = shopper_reference.is_some().then_some(true);
     let return_url = item.get_return_url()?;
     let shopper_name = get_shopper_name(item);
     let shopper_email = item.request.email.clone();
@@ -628,6 +705,8 @@ fn get_paylater_specific_payment_data(
         return_url,
         shopper_interaction,
         recurring_processing_model,
+        shopper_reference,
+        store_payment_method,
         browser_info,
         additional_data,
         telephone_number,
@@ -637,6 +716,7 @@ fn get_paylater_specific_payment_data(
         delivery_address,
         country_code,
         line_items,
+        mpi_data: None,
     })
 }
 
@@ -719,11 +799,14 @@ pub fn get_adyen_response(
         None
     };
 
+    let mandate_reference = response
+        .additional_data
+        .and_then(|data| data.recurring_detail_reference);
     let payments_response_data = types::PaymentsResponseData::TransactionResponse {
         resource_id: types::ResponseId::ConnectorTransactionId(response.psp_reference),
         redirection_data: None,
         redirect: false,
-        mandate_reference: None,
+        mandate_reference,
         connector_metadata: None,
     };
     Ok((status, error, payments_response_data))
@@ -835,7 +918,8 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for AdyenCaptureRequest {
         let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
         Ok(Self {
             merchant_account: auth_type.merchant_account,
-            reference: item.payment_id.to_string(),
+            // Each of the partial captures of the payment is referenced separately
+            reference: item.request.capture_id.clone(),
             amount: Amount {
                 currency: item.request.currency.to_string(),
                 value: item
@@ -866,16 +950,23 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
         item: types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>,
     ) -> Result<Self, Self::Error> {
         let (status, amount_captured) = match item.response.status.as_str() {
-            "received" => (
+            "received" if item.data.request.final_capture => (
                 storage_enums::AttemptStatus::Charged,
                 Some(item.response.amount.value),
             ),
+            "received" => (
+                storage_enums::AttemptStatus::PartialCharged,
+                Some(item.response.amount.value),
+            ),
             _ => (storage_enums::AttemptStatus::Pending, None),
         };
         Ok(Self {
             status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(item.response.psp_reference),
+                // Further captures are made against the payment, not against this capture
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.payment_psp_reference,
+                ),
                 redirect: false,
                 redirection_data: None,
                 mandate_reference: None,
@@ -887,6 +978,72 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     }
 }
 
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAmountUpdateRequest {
+    merchant_account: String,
+    amount: Amount,
+    reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData> for AdyenAmountUpdateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            reference: item.payment_id.clone(),
+            // Adyen expects the new total amount of the authorization, not the increment
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.total_amount,
+            },
+        })
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAmountUpdateResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
+    amount: Amount,
+}
+
+impl TryFrom<types::PaymentsIncrementalAuthorizationResponseRouterData<AdyenAmountUpdateResponse>>
+    for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<AdyenAmountUpdateResponse>,
+    ) -> Result<Self, Self::Error> {
+        // The amount update is processed asynchronously, the outcome being notified through the
+        // AUTHORISATION_ADJUSTMENT webhook
+        let status = match item.response.status.as_str() {
+            "received" => storage_enums::AttemptStatus::Authorized,
+            _ => storage_enums::AttemptStatus::Pending,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.payment_psp_reference,
+                ),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 /*
 // This is a repeated code block from Stripe inegration. Can we avoid the repetition in every integration
 #[derive(Debug, Serialize, Deserialize)]
@@ -961,6 +1118,263 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
     }
 }
 
+// Payouts Request and Response
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum AdyenPayoutFulfillRequest {
+    Card(AdyenPayoutCardRequest),
+    Bank(AdyenPayoutBankRequest),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCardRequest {
+    amount: Amount,
+    merchant_account: String,
+    reference: String,
+    card: AdyenPayoutCard,
+    shopper_reference: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCard {
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBankRequest {
+    amount: Amount,
+    merchant_account: String,
+    reference: String,
+    bank: AdyenPayoutBank,
+    recurring: AdyenPayoutRecurring,
+    shopper_reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBank {
+    iban: Secret<String>,
+    owner_name: Secret<String>,
+    bic: Option<Secret<String>>,
+    bank_name: Option<String>,
+    country_code: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPayoutRecurring {
+    contract: AdyenPayoutContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenPayoutContract {
+    Payout,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCancelRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutReverseRequest {
+    merchant_account: String,
+    original_reference: String,
+    reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    result_code: Option<String>,
+    response: Option<String>,
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutFulfillRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let amount = Amount {
+            currency: item.request.currency.to_string(),
+            value: item.request.amount,
+        };
+        let payout_method_data = item.request.payout_method_data.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            },
+        )?;
+        match payout_method_data {
+            api::PayoutMethodData::Card(card) => Ok(Self::Card(AdyenPayoutCardRequest {
+                amount,
+                merchant_account: auth_type.merchant_account,
+                reference: item.request.payout_id.clone(),
+                card: AdyenPayoutCard {
+                    number: card.card_number,
+                    expiry_month: card.card_exp_month,
+                    expiry_year: card.card_exp_year,
+                    holder_name: card.card_holder_name,
+                },
+                shopper_reference: item.request.customer_id.clone(),
+            })),
+            api::PayoutMethodData::Bank(bank) => {
+                Ok(Self::Bank(AdyenPayoutBankRequest {
+                    amount,
+                    merchant_account: auth_type.merchant_account,
+                    reference: item.request.payout_id.clone(),
+                    bank: AdyenPayoutBank {
+                        iban: bank.iban,
+                        owner_name: bank.account_holder_name,
+                        bic: bank.bic,
+                        bank_name: bank.bank_name,
+                        country_code: bank.bank_country_code,
+                    },
+                    recurring: AdyenPayoutRecurring {
+                        contract: AdyenPayoutContract::Payout,
+                    },
+                    // Adyen requires a shopper reference to store the bank details against
+                    shopper_reference: item.request.customer_id.clone().unwrap_or_else(|| {
+                        format!("{}_{}", item.merchant_id, item.request.payout_id)
+                    }),
+                }))
+            }
+        }
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCancelRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let original_reference = item.request.connector_payout_id.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            },
+        )?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference,
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutReverseRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let original_reference = item.request.connector_payout_id.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            },
+        )?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference,
+            reference: item.request.payout_id.clone(),
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        // Instant card payouts return a `resultCode`, while the third party payout endpoints
+        // acknowledge the request through either `resultCode` or `response`
+        let status = match item
+            .response
+            .result_code
+            .as_deref()
+            .or(item.response.response.as_deref())
+        {
+            Some("Authorised") => storage_enums::PayoutStatus::Success,
+            Some("Refused") | Some("Error") => storage_enums::PayoutStatus::Failed,
+            Some("[payout-decline-received]") => storage_enums::PayoutStatus::Cancelled,
+            Some("[cancelOrRefund-received]") => storage_enums::PayoutStatus::Reversed,
+            _ => storage_enums::PayoutStatus::Pending,
+        };
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.psp_reference,
+                status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Mandate Revoke Request and Response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDisableRequest {
+    merchant_account: String,
+    shopper_reference: String,
+    recurring_detail_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum AdyenDisableStatus {
+    #[serde(rename = "[detail-successfully-disabled]")]
+    DetailSuccessfullyDisabled,
+    #[serde(rename = "[all-details-successfully-disabled]")]
+    AllDetailsSuccessfullyDisabled,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdyenDisableResponse {
+    response: AdyenDisableStatus,
+}
+
+impl TryFrom<&types::MandateRevokeRouterData> for AdyenDisableRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::MandateRevokeRouterData) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            shopper_reference: item.request.customer_id.clone(),
+            recurring_detail_reference: item.request.connector_mandate_id.clone(),
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, AdyenDisableResponse, T, types::MandateRevokeResponseData>>
+    for types::RouterData<F, T, types::MandateRevokeResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AdyenDisableResponse,
+            T,
+            types::MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let mandate_status = match item.response.response {
+            AdyenDisableStatus::DetailSuccessfullyDisabled
+            | AdyenDisableStatus::AllDetailsSuccessfullyDisabled => {
+                storage_enums::MandateStatus::Revoked
+            }
+        };
+        Ok(Self {
+            response: Ok(types::MandateRevokeResponseData { mandate_status }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorResponse {
@@ -995,6 +1409,10 @@ pub struct ErrorResponse {
 #[serde(rename_all = "camelCase")]
 pub struct AdyenAdditionalDataWH {
     pub hmac_signature: String,
+    pub chargeback_reason_code: Option<String>,
+    pub dispute_status: Option<String>,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub defense_period_ends_at: Option<time::OffsetDateTime>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1014,6 +1432,9 @@ pub struct AdyenNotificationRequestItemWH {
     pub merchant_account_code: String,
     pub merchant_reference: String,
     pub success: String,
+    pub reason: Option<String>,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub event_date: Option<time::OffsetDateTime>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1046,3 +1467,206 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
         }
     }
 }
+
+fn to_utc_primitive_date_time(date_time: time::OffsetDateTime) -> time::PrimitiveDateTime {
+    let date_time = date_time.to_offset(time::UtcOffset::UTC);
+    time::PrimitiveDateTime::new(date_time.date(), date_time.time())
+}
+
+pub fn get_dispute_event_type(event_code: &str) -> Option<api::IncomingWebhookEvent> {
+    match event_code {
+        "NOTIFICATION_OF_CHARGEBACK" | "REQUEST_FOR_INFORMATION" | "CHARGEBACK" => {
+            Some(api::IncomingWebhookEvent::DisputeOpened)
+        }
+        "CHARGEBACK_REVERSED" | "PREARBITRATION_WON" => Some(api::IncomingWebhookEvent::DisputeWon),
+        "SECOND_CHARGEBACK" | "PREARBITRATION_LOST" => Some(api::IncomingWebhookEvent::DisputeLost),
+        _ => None,
+    }
+}
+
+pub fn get_payout_event_type(event_code: &str, success: &str) -> Option<api::IncomingWebhookEvent> {
+    match event_code {
+        "PAYOUT_THIRDPARTY" if success == "true" => Some(api::IncomingWebhookEvent::PayoutSuccess),
+        "PAYOUT_THIRDPARTY" => Some(api::IncomingWebhookEvent::PayoutFailure),
+        "PAYOUT_DECLINE" | "PAYOUT_EXPIRE" => Some(api::IncomingWebhookEvent::PayoutCancelled),
+        "PAIDOUT_REVERSED" => Some(api::IncomingWebhookEvent::PayoutReversed),
+        _ => None,
+    }
+}
+
+fn get_dispute_stage(event_code: &str) -> storage_enums::DisputeStage {
+    match event_code {
+        "NOTIFICATION_OF_CHARGEBACK" | "REQUEST_FOR_INFORMATION" => {
+            storage_enums::DisputeStage::PreDispute
+        }
+        "SECOND_CHARGEBACK" | "PREARBITRATION_WON" | "PREARBITRATION_LOST" => {
+            storage_enums::DisputeStage::PreArbitration
+        }
+        _ => storage_enums::DisputeStage::Dispute,
+    }
+}
+
+impl From<AdyenNotificationRequestItemWH> for api::DisputePayload {
+    fn from(notif: AdyenNotificationRequestItemWH) -> Self {
+        Self {
+            amount: notif.amount.value.to_string(),
+            currency: notif.amount.currency,
+            dispute_stage: get_dispute_stage(notif.event_code.as_str()),
+            connector_status: notif
+                .additional_data
+                .dispute_status
+                .unwrap_or(notif.event_code),
+            connector_dispute_id: notif.psp_reference,
+            connector_reason: notif.reason,
+            connector_reason_code: notif.additional_data.chargeback_reason_code,
+            challenge_required_by: notif
+                .additional_data
+                .defense_period_ends_at
+                .map(to_utc_primitive_date_time),
+            created_at: notif.event_date.map(to_utc_primitive_date_time),
+            updated_at: notif.event_date.map(to_utc_primitive_date_time),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_incremental_authorization_router_data(
+        connector_auth_type: types::ConnectorAuthType,
+    ) -> types::PaymentsIncrementalAuthorizationRouterData {
+        types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_id".to_string(),
+            connector: "adyen".to_string(),
+            payment_id: "payment_id".to_string(),
+            attempt_id: Some("attempt_id".to_string()),
+            status: storage_enums::AttemptStatus::Authorized,
+            payment_method: storage_enums::PaymentMethodType::Card,
+            connector_auth_type,
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: types::PaymentAddress::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: types::PaymentsIncrementalAuthorizationData {
+                total_amount: 1500,
+                additional_amount: 500,
+                currency: storage_enums::Currency::USD,
+                connector_transaction_id: "connector_transaction_id".to_string(),
+            },
+            response: Err(types::ErrorResponse::default()),
+            payment_method_id: None,
+        }
+    }
+
+    #[test]
+    fn test_amount_update_request() {
+        let router_data =
+            get_incremental_authorization_router_data(types::ConnectorAuthType::BodyKey {
+                api_key: "api_key".to_string(),
+                key1: "merchant_account".to_string(),
+            });
+        let request = AdyenAmountUpdateRequest::try_from(&router_data).unwrap();
+
+        // Adyen is sent the new total amount of the authorization
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "merchantAccount": "merchant_account",
+                "amount": { "currency": "USD", "value": 1500 },
+                "reference": "payment_id",
+            })
+        );
+    }
+
+    #[test]
+    fn test_amount_update_request_without_merchant_account() {
+        let router_data =
+            get_incremental_authorization_router_data(types::ConnectorAuthType::HeaderKey {
+                api_key: "api_key".to_string(),
+            });
+        assert!(AdyenAmountUpdateRequest::try_from(&router_data).is_err());
+    }
+
+    fn get_amount_update_response(status: &str) -> AdyenAmountUpdateResponse {
+        serde_json::from_value(serde_json::json!({
+            "merchantAccount": "merchant_account",
+            "paymentPspReference": "payment_psp_reference",
+            "pspReference": "psp_reference",
+            "reference": "payment_id",
+            "status": status,
+            "amount": { "currency": "USD", "value": 1500 },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_amount_update_response() {
+        let router_data =
+            get_incremental_authorization_router_data(types::ConnectorAuthType::NoKey);
+        let router_data = types::PaymentsIncrementalAuthorizationRouterData::try_from(
+            types::ResponseRouterData {
+                response: get_amount_update_response("received"),
+                data: router_data,
+                http_code: 200,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(router_data.status, storage_enums::AttemptStatus::Authorized);
+        assert!(matches!(
+            router_data.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(ref id),
+                ..
+            }) if id == "payment_psp_reference"
+        ));
+    }
+
+    #[test]
+    fn test_amount_update_response_not_received() {
+        let router_data =
+            get_incremental_authorization_router_data(types::ConnectorAuthType::NoKey);
+        let router_data = types::PaymentsIncrementalAuthorizationRouterData::try_from(
+            types::ResponseRouterData {
+                response: get_amount_update_response("refused"),
+                data: router_data,
+                http_code: 200,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(router_data.status, storage_enums::AttemptStatus::Pending);
+    }
+
+    #[test]
+    fn test_payout_event_type() {
+        assert_eq!(
+            get_payout_event_type("PAYOUT_THIRDPARTY", "true"),
+            Some(api::IncomingWebhookEvent::PayoutSuccess)
+        );
+        assert_eq!(
+            get_payout_event_type("PAYOUT_THIRDPARTY", "false"),
+            Some(api::IncomingWebhookEvent::PayoutFailure)
+        );
+        assert_eq!(
+            get_payout_event_type("PAYOUT_DECLINE", "true"),
+            Some(api::IncomingWebhookEvent::PayoutCancelled)
+        );
+        assert_eq!(
+            get_payout_event_type("PAYOUT_EXPIRE", "true"),
+            Some(api::IncomingWebhookEvent::PayoutCancelled)
+        );
+        assert_eq!(
+            get_payout_event_type("PAIDOUT_REVERSED", "true"),
+            Some(api::IncomingWebhookEvent::PayoutReversed)
+        );
+        assert_eq!(get_payout_event_type("AUTHORISATION", "true"), None);
+    }
+}
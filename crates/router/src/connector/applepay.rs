@@ -36,6 +36,42 @@ impl api::PaymentSync for Applepay {}
 impl api::PaymentVoid for Applepay {}
 impl api::PaymentCapture for Applepay {}
 impl api::PreVerify for Applepay {}
+impl api::PaymentIncrementalAuthorization for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Applepay {}
 impl api::ConnectorAccessToken for Applepay {}
 
@@ -238,6 +274,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 impl services::ConnectorRedirectResponse for Applepay {}
 
+impl api::Payouts for Applepay {}
+impl api::PayoutFulfill for Applepay {}
+impl api::PayoutCancel for Applepay {}
+impl api::PayoutReverse for Applepay {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::ConnectorMandateRevoke for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Applepay
+{
+}
+
+impl api::ConnectorCustomer for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Applepay
+{
+}
+
+impl api::Dispute for Applepay {}
+impl api::AcceptDispute for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Applepay
+{
+}
+
+impl api::SubmitEvidence for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Applepay
+{
+}
+
+impl api::FileUpload for Applepay {}
+impl api::UploadFile for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Applepay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Applepay {
     fn get_webhook_object_reference_id(
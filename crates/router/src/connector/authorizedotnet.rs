@@ -41,6 +41,42 @@ impl api::PaymentAuthorize for Authorizedotnet {}
 impl api::PaymentSync for Authorizedotnet {}
 impl api::PaymentVoid for Authorizedotnet {}
 impl api::PaymentCapture for Authorizedotnet {}
+impl api::PaymentIncrementalAuthorization for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Authorizedotnet {}
 impl api::ConnectorAccessToken for Authorizedotnet {}
 
@@ -568,6 +604,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Authorizedotnet {}
+impl api::PayoutFulfill for Authorizedotnet {}
+impl api::PayoutCancel for Authorizedotnet {}
+impl api::PayoutReverse for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::ConnectorMandateRevoke for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Authorizedotnet
+{
+}
+
+impl api::ConnectorCustomer for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Authorizedotnet
+{
+}
+
+impl api::Dispute for Authorizedotnet {}
+impl api::AcceptDispute for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Authorizedotnet
+{
+}
+
+impl api::SubmitEvidence for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Authorizedotnet
+{
+}
+
+impl api::FileUpload for Authorizedotnet {}
+impl api::UploadFile for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Authorizedotnet
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Authorizedotnet {
     fn get_webhook_object_reference_id(
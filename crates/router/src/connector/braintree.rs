@@ -50,6 +50,42 @@ impl api::PaymentSync for Braintree {}
 impl api::PaymentVoid for Braintree {}
 impl api::PaymentCapture for Braintree {}
 
+impl api::PaymentIncrementalAuthorization for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Braintree {}
 impl api::ConnectorAccessToken for Braintree {}
 
@@ -683,6 +719,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Braintree {}
+impl api::PayoutFulfill for Braintree {}
+impl api::PayoutCancel for Braintree {}
+impl api::PayoutReverse for Braintree {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl api::ConnectorMandateRevoke for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Braintree
+{
+}
+
+impl api::ConnectorCustomer for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Braintree
+{
+}
+
+impl api::Dispute for Braintree {}
+impl api::AcceptDispute for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Braintree
+{
+}
+
+impl api::SubmitEvidence for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Braintree
+{
+}
+
+impl api::FileUpload for Braintree {}
+impl api::UploadFile for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Braintree
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Braintree {
     fn get_webhook_object_reference_id(
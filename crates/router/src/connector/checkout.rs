@@ -15,12 +15,14 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
+    db::StorageInterface,
     headers, logger, services,
     types::{
         self,
         api::{self, ConnectorCommon},
+        storage::enums as storage_enums,
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -59,6 +61,42 @@ impl api::PaymentAuthorize for Checkout {}
 impl api::PaymentSync for Checkout {}
 impl api::PaymentVoid for Checkout {}
 impl api::PaymentCapture for Checkout {}
+impl api::PaymentIncrementalAuthorization for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Checkout {}
 impl api::ConnectorAccessToken for Checkout {}
 
@@ -717,27 +755,386 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Checkout {}
+impl api::PayoutFulfill for Checkout {}
+impl api::PayoutCancel for Checkout {}
+impl api::PayoutReverse for Checkout {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+}
+
+impl api::ConnectorMandateRevoke for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Checkout
+{
+}
+
+impl api::ConnectorCustomer for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Checkout
+{
+}
+
+impl api::Dispute for Checkout {}
+impl api::AcceptDispute for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::AcceptDisputeType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}disputes/{}/accept",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::AcceptDisputeType::get_url(self, req, connectors)?)
+            .headers(types::AcceptDisputeType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::AcceptDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::AcceptDisputeRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+        // Checkout acknowledges an accepted dispute with an empty `204 No Content` response
+        Ok(types::AcceptDisputeRouterData {
+            response: Ok(types::AcceptDisputeResponse {
+                dispute_status: storage_enums::DisputeStatus::DisputeAccepted,
+                connector_status: None,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::SubmitEvidence for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Checkout
+{
+}
+
+impl api::FileUpload for Checkout {
+    fn validate_file_upload(
+        &self,
+        purpose: api::FilePurpose,
+        file_size: i32,
+        file_type: mime::Mime,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        match purpose {
+            api::FilePurpose::DisputeEvidence => {
+                let supported_file_types = ["image/jpeg", "image/png", "application/pdf"];
+                // 4 Megabytes (MB)
+                if file_size > 4_000_000 {
+                    Err(errors::ConnectorError::FileValidationFailed {
+                        reason: "file_size exceeded the max file size of 4MB".to_owned(),
+                    })?
+                }
+                if !supported_file_types.contains(&file_type.to_string().as_str()) {
+                    Err(errors::ConnectorError::FileValidationFailed {
+                        reason: "file_type does not match JPEG, JPG, PNG, or PDF format".to_owned(),
+                    })?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl api::UploadFile for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The multipart boundary is part of the content type, so it is set by the client
+        let mut header = vec![(headers::X_ROUTER.to_string(), "test".to_string())];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "multipart/form-data"
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "files"))
+    }
+
+    fn get_request_form_data(
+        &self,
+        req: &types::UploadFileRouterData,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        let checkout_req = checkout::construct_file_upload_request(req.request.clone())?;
+        Ok(Some(checkout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::UploadFileType::get_url(self, req, connectors)?)
+            .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+            .form_data(types::UploadFileType::get_request_form_data(self, req)?)
+            .content_type(services::ContentType::FormData)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+        let response: checkout::FileUploadResponse = res
+            .response
+            .parse_struct("Checkout FileUploadResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Checkout {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
         &self,
+        headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = headers
+            .get("cko-signature")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?
+            .to_str()
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(body.to_vec())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
+        let secret = db
+            .get_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+        Ok(secret)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let event: checkout::CheckoutWebhookEventType = body
+            .parse_struct("CheckoutWebhookEventType")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        // Refunds are referenced by the action id of the refund, which is the connector refund id
+        if event.event_type.is_refund_event() {
+            let details: checkout::CheckoutRefundWebhookBody = body
+                .parse_struct("CheckoutRefundWebhookBody")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+            return Ok(details.data.action_id);
+        }
+
+        let details: checkout::CheckoutDisputeWebhookBody = body
+            .parse_struct("CheckoutDisputeWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(details.data.payment_id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: checkout::CheckoutWebhookEventType = body
+            .parse_struct("CheckoutWebhookEventType")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Option::<api::IncomingWebhookEvent>::from(details.event_type)
+            .ok_or(errors::ConnectorError::WebhookEventTypeNotFound)
+            .into_report()
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let details: serde_json::Value = body
+            .parse_struct("CheckoutWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(details)
+    }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let details: checkout::CheckoutDisputeWebhookBody = body
+            .parse_struct("CheckoutDisputeWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(api::DisputePayload::from(details))
     }
 }
 
@@ -758,3 +1155,103 @@ impl services::ConnectorRedirectResponse for Checkout {
             .unwrap_or(payments::CallConnectorAction::Trigger))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    #[test]
+    fn test_get_dispute_details() {
+        let body = br#"{
+            "type": "dispute_evidence_required",
+            "data": {
+                "id": "dsp_1",
+                "payment_id": "pay_1",
+                "amount": 1000,
+                "currency": "USD",
+                "reason_code": "10.4",
+                "category": "fraudulent",
+                "evidence_required_by": "2023-04-07T12:40:00+02:00"
+            },
+            "created_on": "2023-03-28T10:40:00Z"
+        }"#;
+        let dispute_details = Checkout.get_dispute_details(body).unwrap();
+
+        assert_eq!(dispute_details.amount, "1000");
+        assert_eq!(dispute_details.currency, "USD");
+        assert_eq!(
+            dispute_details.dispute_stage,
+            storage_enums::DisputeStage::Dispute
+        );
+        assert_eq!(
+            dispute_details.connector_status,
+            "dispute_evidence_required"
+        );
+        assert_eq!(dispute_details.connector_dispute_id, "dsp_1");
+        assert_eq!(
+            dispute_details.connector_reason.as_deref(),
+            Some("fraudulent")
+        );
+        assert_eq!(
+            dispute_details.connector_reason_code.as_deref(),
+            Some("10.4")
+        );
+        // Dates are converted to UTC
+        assert_eq!(
+            dispute_details.challenge_required_by,
+            Some(time::macros::datetime!(2023-04-07 10:40:00))
+        );
+        assert_eq!(
+            dispute_details.created_at,
+            Some(time::macros::datetime!(2023-03-28 10:40:00))
+        );
+    }
+
+    #[test]
+    fn test_get_dispute_details_invalid_body() {
+        assert!(Checkout
+            .get_dispute_details(br#"{"type": "dispute_received"}"#)
+            .is_err());
+    }
+
+    fn get_refund_webhook_body(event_type: &str) -> Vec<u8> {
+        serde_json::json!({
+            "type": event_type,
+            "data": {
+                "id": "pay_1",
+                "action_id": "act_1"
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_refund_webhook_event_type() {
+        assert_eq!(
+            Checkout
+                .get_webhook_event_type(&get_refund_webhook_body("payment_refunded"))
+                .unwrap(),
+            api::IncomingWebhookEvent::RefundSuccess
+        );
+        assert_eq!(
+            Checkout
+                .get_webhook_event_type(&get_refund_webhook_body("payment_refund_declined"))
+                .unwrap(),
+            api::IncomingWebhookEvent::RefundFailure
+        );
+    }
+
+    #[test]
+    fn test_refund_webhook_object_reference_id() {
+        // Refunds are referenced by the action id of the refund
+        assert_eq!(
+            Checkout
+                .get_webhook_object_reference_id(&get_refund_webhook_body("payment_refunded"))
+                .unwrap(),
+            "act_1"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
-    core::errors,
+    core::errors::{self, CustomResult},
     pii, services,
     types::{
         self, api,
@@ -371,9 +371,14 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
+        let capture_type = if item.request.final_capture {
+            CaptureType::Final
+        } else {
+            CaptureType::NonFinal
+        };
         Ok(Self {
             amount: item.request.amount_to_capture,
-            capture_type: Some(CaptureType::Final),
+            capture_type: Some(capture_type),
             processing_channel_id,
         })
     }
@@ -392,10 +397,12 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
         item: types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>,
     ) -> Result<Self, Self::Error> {
         let (status, amount_captured) = if item.http_code == 202 {
-            (
-                enums::AttemptStatus::Charged,
-                item.data.request.amount_to_capture,
-            )
+            let status = if item.data.request.final_capture {
+                enums::AttemptStatus::Charged
+            } else {
+                enums::AttemptStatus::PartialCharged
+            };
+            (status, item.data.request.amount_to_capture)
         } else {
             (enums::AttemptStatus::Pending, None)
         };
@@ -592,3 +599,153 @@ impl From<CheckoutRedirectResponseStatus> for enums::AttemptStatus {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookEventType {
+    #[serde(rename = "type")]
+    pub event_type: CheckoutWebhookEvent,
+}
+
+#[derive(Debug, Clone, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckoutWebhookEvent {
+    DisputeReceived,
+    DisputeEvidenceRequired,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCanceled,
+    DisputeWon,
+    DisputeLost,
+    PaymentRefunded,
+    PaymentRefundDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+impl CheckoutWebhookEvent {
+    pub fn is_refund_event(&self) -> bool {
+        matches!(self, Self::PaymentRefunded | Self::PaymentRefundDeclined)
+    }
+}
+
+impl From<CheckoutWebhookEvent> for Option<api::IncomingWebhookEvent> {
+    fn from(event: CheckoutWebhookEvent) -> Self {
+        match event {
+            CheckoutWebhookEvent::DisputeReceived
+            | CheckoutWebhookEvent::DisputeEvidenceRequired => {
+                Some(api::IncomingWebhookEvent::DisputeOpened)
+            }
+            CheckoutWebhookEvent::DisputeExpired => Some(api::IncomingWebhookEvent::DisputeExpired),
+            CheckoutWebhookEvent::DisputeAccepted => {
+                Some(api::IncomingWebhookEvent::DisputeAccepted)
+            }
+            CheckoutWebhookEvent::DisputeCanceled => {
+                Some(api::IncomingWebhookEvent::DisputeCancelled)
+            }
+            CheckoutWebhookEvent::DisputeWon => Some(api::IncomingWebhookEvent::DisputeWon),
+            CheckoutWebhookEvent::DisputeLost => Some(api::IncomingWebhookEvent::DisputeLost),
+            CheckoutWebhookEvent::PaymentRefunded => Some(api::IncomingWebhookEvent::RefundSuccess),
+            CheckoutWebhookEvent::PaymentRefundDeclined => {
+                Some(api::IncomingWebhookEvent::RefundFailure)
+            }
+            CheckoutWebhookEvent::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutDisputeWebhookData {
+    pub id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub reason_code: Option<String>,
+    pub category: Option<String>,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub evidence_required_by: Option<time::OffsetDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutRefundWebhookData {
+    pub id: String,
+    pub action_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutRefundWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: CheckoutWebhookEvent,
+    pub data: CheckoutRefundWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutDisputeWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: CheckoutWebhookEvent,
+    pub data: CheckoutDisputeWebhookData,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub created_on: Option<time::OffsetDateTime>,
+}
+
+fn to_utc_primitive_date_time(date_time: time::OffsetDateTime) -> time::PrimitiveDateTime {
+    let date_time = date_time.to_offset(time::UtcOffset::UTC);
+    time::PrimitiveDateTime::new(date_time.date(), date_time.time())
+}
+
+impl From<CheckoutDisputeWebhookBody> for api::DisputePayload {
+    fn from(item: CheckoutDisputeWebhookBody) -> Self {
+        Self {
+            amount: item.data.amount.to_string(),
+            currency: item.data.currency,
+            dispute_stage: enums::DisputeStage::Dispute,
+            connector_status: item.event_type.to_string(),
+            connector_dispute_id: item.data.id,
+            connector_reason: item.data.category,
+            connector_reason_code: item.data.reason_code,
+            challenge_required_by: item
+                .data
+                .evidence_required_by
+                .map(to_utc_primitive_date_time),
+            created_at: item.created_on.map(to_utc_primitive_date_time),
+            updated_at: item.created_on.map(to_utc_primitive_date_time),
+        }
+    }
+}
+
+pub fn construct_file_upload_request(
+    file_upload_router_data: types::UploadFileRequestData,
+) -> CustomResult<reqwest::multipart::Form, errors::ConnectorError> {
+    let mut multipart = reqwest::multipart::Form::new();
+    multipart = multipart.text("purpose", "dispute_evidence");
+    let file_data = reqwest::multipart::Part::bytes(file_upload_router_data.file)
+        .file_name(file_upload_router_data.file_key)
+        .mime_str(file_upload_router_data.file_type.as_ref())
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failure in constructing file data")?;
+    multipart = multipart.part("file", file_data);
+    Ok(multipart)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, FileUploadResponse, T, types::UploadFileResponse>>
+    for types::RouterData<F, T, types::UploadFileResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, FileUploadResponse, T, types::UploadFileResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::UploadFileResponse {
+                provider_file_id: item.response.file_id,
+            }),
+            ..item.data
+        })
+    }
+}
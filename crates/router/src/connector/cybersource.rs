@@ -27,6 +27,14 @@ use crate::{
 pub struct Cybersource;
 
 impl Cybersource {
+    /// Requests with a body are signed along with the digest of the body
+    fn has_payload(http_method: services::Method) -> bool {
+        matches!(
+            http_method,
+            services::Method::Post | services::Method::Put | services::Method::Patch
+        )
+    }
+
     pub fn generate_digest(&self, payload: &[u8]) -> String {
         let payload_digest = digest::digest(&digest::SHA256, payload);
         consts::BASE64_ENGINE.encode(payload_digest)
@@ -46,13 +54,14 @@ impl Cybersource {
             merchant_account,
             api_secret,
         } = auth;
-        let is_post_method = matches!(http_method, services::Method::Post);
-        let digest_str = if is_post_method { "digest " } else { "" };
+        let has_payload = Self::has_payload(http_method);
+        let digest_str = if has_payload { "digest " } else { "" };
         let headers = format!("host date (request-target) {digest_str}v-c-merchant-id");
-        let request_target = if is_post_method {
-            format!("(request-target): post {resource}\ndigest: SHA-256={payload}\n")
+        let method = http_method.to_string().to_lowercase();
+        let request_target = if has_payload {
+            format!("(request-target): {method} {resource}\ndigest: SHA-256={payload}\n")
         } else {
-            format!("(request-target): get {resource}\n")
+            format!("(request-target): {method} {resource}\n")
         };
         let signature_string = format!(
             "host: {host}\ndate: {date}\n{request_target}v-c-merchant-id: {merchant_account}"
@@ -164,7 +173,7 @@ where
             ("Host".to_string(), host.to_string()),
             ("Signature".to_string(), signature),
         ];
-        if matches!(http_method, services::Method::Post | services::Method::Put) {
+        if Self::has_payload(http_method) {
             headers.push(("Digest".to_string(), format!("SHA-256={sha256}")));
         }
         Ok(headers)
@@ -176,6 +185,7 @@ impl api::PaymentAuthorize for Cybersource {}
 impl api::PaymentSync for Cybersource {}
 impl api::PaymentVoid for Cybersource {}
 impl api::PaymentCapture for Cybersource {}
+impl api::PaymentIncrementalAuthorization for Cybersource {}
 impl api::PreVerify for Cybersource {}
 impl api::ConnectorAccessToken for Cybersource {}
 
@@ -190,8 +200,130 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
     // Not Implemented (R)
 }
 
+impl api::PaymentPreAuthenticate for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Cybersource {}
 
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Cybersource
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_http_method(&self) -> services::Method {
+        services::Method::Patch
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let connector_payment_id = req.request.connector_transaction_id.clone();
+        Ok(format!(
+            "{}pts/v2/payments/{}",
+            self.base_url(connectors),
+            connector_payment_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req_obj = cybersource::IncrementalAuthorizationRequest::try_from(req)?;
+        let req =
+            utils::Encode::<cybersource::IncrementalAuthorizationRequest>::encode_to_string_of_json(
+                &req_obj,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Patch)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: cybersource::CybersourcePaymentsResponse = res
+            .response
+            .parse_struct("Cybersource PaymentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(cybersource_incremental_authorization_response=?response);
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            false,
+        ))
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
     for Cybersource
 {
@@ -692,6 +824,83 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Cybersource {}
+impl api::PayoutFulfill for Cybersource {}
+impl api::PayoutCancel for Cybersource {}
+impl api::PayoutReverse for Cybersource {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::ConnectorMandateRevoke for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Cybersource
+{
+}
+
+impl api::ConnectorCustomer for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Cybersource
+{
+}
+
+impl api::Dispute for Cybersource {}
+impl api::AcceptDispute for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Cybersource
+{
+}
+
+impl api::SubmitEvidence for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Cybersource
+{
+}
+
+impl api::FileUpload for Cybersource {}
+impl api::UploadFile for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Cybersource
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Cybersource {
     fn get_webhook_object_reference_id(
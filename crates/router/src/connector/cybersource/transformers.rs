@@ -200,6 +200,71 @@ impl TryFrom<&types::RefundExecuteRouterData> for CybersourcePaymentsRequest {
     }
 }
 
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IncrementalAuthorizationRequest {
+    processing_information: IncrementalAuthorizationProcessingInformation,
+    order_information: IncrementalAuthorizationOrderInformation,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IncrementalAuthorizationProcessingInformation {
+    authorization_options: AuthorizationOptions,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizationOptions {
+    initiator: Initiator,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Initiator {
+    stored_credential_used: bool,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IncrementalAuthorizationOrderInformation {
+    amount_details: AdditionalAmount,
+}
+
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalAmount {
+    additional_amount: String,
+    currency: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        value: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            // The card details stored with the original authorization are used for the increment
+            processing_information: IncrementalAuthorizationProcessingInformation {
+                authorization_options: AuthorizationOptions {
+                    initiator: Initiator {
+                        stored_credential_used: true,
+                    },
+                },
+            },
+            // Cybersource expects the increment of the authorization, not the new total amount
+            order_information: IncrementalAuthorizationOrderInformation {
+                amount_details: AdditionalAmount {
+                    additional_amount: value.request.additional_amount.to_string(),
+                    currency: value.request.currency.to_string().to_uppercase(),
+                },
+            },
+        })
+    }
+}
+
 pub struct CybersourceAuthType {
     pub(super) api_key: String,
     pub(super) merchant_account: String,
@@ -460,3 +525,110 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CybersourceTransaction
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_incremental_authorization_router_data(
+        connector_auth_type: types::ConnectorAuthType,
+    ) -> types::PaymentsIncrementalAuthorizationRouterData {
+        types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_id".to_string(),
+            connector: "cybersource".to_string(),
+            payment_id: "payment_id".to_string(),
+            attempt_id: Some("attempt_id".to_string()),
+            status: enums::AttemptStatus::Authorized,
+            payment_method: enums::PaymentMethodType::Card,
+            connector_auth_type,
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: types::PaymentAddress::default(),
+            auth_type: enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: types::PaymentsIncrementalAuthorizationData {
+                total_amount: 1500,
+                additional_amount: 500,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "connector_transaction_id".to_string(),
+            },
+            response: Err(types::ErrorResponse::default()),
+            payment_method_id: None,
+        }
+    }
+
+    #[test]
+    fn test_incremental_authorization_request() {
+        let router_data =
+            get_incremental_authorization_router_data(types::ConnectorAuthType::NoKey);
+        let request = IncrementalAuthorizationRequest::try_from(&router_data).unwrap();
+
+        // Cybersource is sent the increment of the authorization
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "processingInformation": {
+                    "authorizationOptions": {
+                        "initiator": { "storedCredentialUsed": true }
+                    }
+                },
+                "orderInformation": {
+                    "amountDetails": { "additionalAmount": "500", "currency": "USD" }
+                },
+            })
+        );
+    }
+
+    fn get_incremental_authorization_response(
+        response: serde_json::Value,
+    ) -> types::PaymentsIncrementalAuthorizationRouterData {
+        let router_data =
+            get_incremental_authorization_router_data(types::ConnectorAuthType::NoKey);
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response: serde_json::from_value::<CybersourcePaymentsResponse>(response).unwrap(),
+                data: router_data,
+                http_code: 201,
+            },
+            false,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_incremental_authorization_response() {
+        let router_data = get_incremental_authorization_response(serde_json::json!({
+            "id": "connector_transaction_id",
+            "status": "AUTHORIZED",
+        }));
+
+        assert_eq!(router_data.status, enums::AttemptStatus::Authorized);
+        assert!(matches!(
+            router_data.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(ref id),
+                ..
+            }) if id == "connector_transaction_id"
+        ));
+    }
+
+    #[test]
+    fn test_incremental_authorization_response_declined() {
+        let router_data = get_incremental_authorization_response(serde_json::json!({
+            "id": "connector_transaction_id",
+            "status": "DECLINED",
+            "errorInformation": {
+                "reason": "EXCEEDS_CREDIT_LIMIT",
+                "message": "Decline - Exceeds the credit limit",
+            },
+        }));
+
+        assert_eq!(router_data.status, enums::AttemptStatus::Failure);
+        assert!(router_data.response.is_err());
+    }
+}
@@ -245,6 +245,42 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Fiserv {}
 
 #[allow(dead_code)]
@@ -412,6 +448,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Fiserv {}
+impl api::PayoutFulfill for Fiserv {}
+impl api::PayoutCancel for Fiserv {}
+impl api::PayoutReverse for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::ConnectorMandateRevoke for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Fiserv
+{
+}
+
+impl api::ConnectorCustomer for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Fiserv
+{
+}
+
+impl api::Dispute for Fiserv {}
+impl api::AcceptDispute for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Fiserv
+{
+}
+
+impl api::SubmitEvidence for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Fiserv
+{
+}
+
+impl api::FileUpload for Fiserv {}
+impl api::UploadFile for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Fiserv
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Fiserv {
     fn get_webhook_object_reference_id(
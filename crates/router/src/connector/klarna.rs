@@ -50,6 +50,42 @@ impl api::PaymentAuthorize for Klarna {}
 impl api::PaymentSync for Klarna {}
 impl api::PaymentVoid for Klarna {}
 impl api::PaymentCapture for Klarna {}
+impl api::PaymentIncrementalAuthorization for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Klarna {}
 impl api::ConnectorAccessToken for Klarna {}
 
@@ -340,6 +376,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Klarna {}
+impl api::PayoutFulfill for Klarna {}
+impl api::PayoutCancel for Klarna {}
+impl api::PayoutReverse for Klarna {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+}
+
+impl api::ConnectorMandateRevoke for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Klarna
+{
+}
+
+impl api::ConnectorCustomer for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Klarna
+{
+}
+
+impl api::Dispute for Klarna {}
+impl api::AcceptDispute for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Klarna
+{
+}
+
+impl api::SubmitEvidence for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Klarna
+{
+}
+
+impl api::FileUpload for Klarna {}
+impl api::UploadFile for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Klarna
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Klarna {
     fn get_webhook_object_reference_id(
@@ -0,0 +1,465 @@
+// The requests and responses are shared with the mock 3DS server of the router
+pub mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::{IntoReport, ResultExt};
+use transformers as mock_three_ds;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse,
+    },
+    utils::{self, BytesExt},
+};
+
+/// 3DS server authenticating the customers of payments on behalf of the router, which is not
+/// authorizing payments
+#[derive(Debug, Clone)]
+pub struct MockThreeDs;
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for MockThreeDs
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for MockThreeDs {
+    fn id(&self) -> &'static str {
+        "mock_three_ds"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.mock_three_ds.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth: mock_three_ds::MockThreeDsAuthType = auth_type
+            .try_into()
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![(headers::X_API_KEY.to_string(), auth.api_key)])
+    }
+
+    fn build_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: mock_three_ds::MockThreeDsErrorResponse = res
+            .response
+            .parse_struct("MockThreeDsErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.error_description,
+            reason: None,
+        })
+    }
+}
+
+impl api::Payment for MockThreeDs {}
+impl api::ConnectorAccessToken for MockThreeDs {}
+
+impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PreVerify for MockThreeDs {}
+
+impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::PaymentsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentVoid for MockThreeDs {}
+
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentSync for MockThreeDs {}
+
+impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentCapture for MockThreeDs {}
+
+impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::PaymentsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentIncrementalAuthorization for MockThreeDs {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for MockThreeDs {}
+
+impl
+    ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for MockThreeDs
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsPreAuthenticateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsPreAuthenticateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/preauth", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsPreAuthenticateRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let mock_three_ds_req =
+            utils::Encode::<mock_three_ds::MockThreeDsPreAuthenticationRequest>::convert_and_encode(
+                req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(mock_three_ds_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsPreAuthenticateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsPreAuthenticateType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsPreAuthenticateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsPreAuthenticateType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsPreAuthenticateRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsPreAuthenticateRouterData, errors::ConnectorError> {
+        let response: mock_three_ds::MockThreeDsPreAuthenticationResponse = res
+            .response
+            .parse_struct("MockThreeDsPreAuthenticationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(mock_three_ds_pre_authentication_response=?response);
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::PaymentAuthenticate for MockThreeDs {}
+
+impl
+    ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for MockThreeDs
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthenticateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    // The result of the challenge is fetched once the customer has answered it
+    fn get_url(
+        &self,
+        req: &types::PaymentsAuthenticateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        match req.request.challenge_response {
+            Some(_) => Ok(format!("{}/results", self.base_url(connectors))),
+            None => Ok(format!("{}/auth", self.base_url(connectors))),
+        }
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthenticateRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let mock_three_ds_req = match req.request.challenge_response {
+            Some(_) => {
+                utils::Encode::<mock_three_ds::MockThreeDsResultsRequest>::convert_and_encode(req)
+            }
+            None => utils::Encode::<mock_three_ds::MockThreeDsAuthenticationRequest>::convert_and_encode(
+                req,
+            ),
+        }
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(mock_three_ds_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthenticateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthenticateType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsAuthenticateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsAuthenticateType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthenticateRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsAuthenticateRouterData, errors::ConnectorError> {
+        let response: mock_three_ds::MockThreeDsAuthenticationResponse = res
+            .response
+            .parse_struct("MockThreeDsAuthenticationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(mock_three_ds_authentication_response=?response);
+        types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        }
+        .try_into()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::PaymentSession for MockThreeDs {}
+
+impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthorize for MockThreeDs {}
+
+impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::Refund for MockThreeDs {}
+impl api::RefundExecute for MockThreeDs {}
+impl api::RefundSync for MockThreeDs {}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for MockThreeDs {}
+impl api::PayoutFulfill for MockThreeDs {}
+impl api::PayoutCancel for MockThreeDs {}
+impl api::PayoutReverse for MockThreeDs {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::ConnectorMandateRevoke for MockThreeDs {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for MockThreeDs
+{
+}
+
+impl api::ConnectorCustomer for MockThreeDs {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for MockThreeDs
+{
+}
+
+impl api::Dispute for MockThreeDs {}
+impl api::AcceptDispute for MockThreeDs {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::SubmitEvidence for MockThreeDs {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for MockThreeDs {}
+impl api::UploadFile for MockThreeDs {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for MockThreeDs
+{
+    // Not Implemented (R)
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for MockThreeDs {
+    fn get_webhook_object_reference_id(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+}
+
+impl services::ConnectorRedirectResponse for MockThreeDs {}
@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors,
+    pii::PeekInterface,
+    types::{self, api},
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockThreeDsPreAuthenticationRequest {
+    pub card_number: String,
+    pub amount: i64,
+    pub currency: String,
+}
+
+impl TryFrom<&types::PaymentsPreAuthenticateRouterData> for MockThreeDsPreAuthenticationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsPreAuthenticateRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref ccard) => Ok(Self {
+                card_number: ccard.card_number.peek().clone(),
+                amount: item.request.amount,
+                currency: item.request.currency.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Current Payment Method".to_string(),
+            ))?,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockThreeDsPreAuthenticationResponse {
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+    pub message_version: String,
+    #[serde(rename = "threeDSMethodURL")]
+    pub three_ds_method_url: Option<String>,
+    #[serde(rename = "threeDSMethodData")]
+    pub three_ds_method_data: Option<String>,
+}
+
+/// Whether the 3DS method was completed, before authenticating the customer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MockThreeDsCompletionIndicator {
+    #[serde(rename = "Y")]
+    Completed,
+    #[serde(rename = "N")]
+    NotCompleted,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockThreeDsAuthenticationRequest {
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+    pub message_version: String,
+    pub card_number: String,
+    pub amount: i64,
+    pub currency: String,
+    #[serde(rename = "threeDSCompInd")]
+    pub three_ds_comp_ind: MockThreeDsCompletionIndicator,
+    pub browser_info: Option<types::BrowserInformation>,
+}
+
+impl TryFrom<&types::PaymentsAuthenticateRouterData> for MockThreeDsAuthenticationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthenticateRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref ccard) => Ok(Self {
+                three_ds_server_trans_id: item.request.three_ds_server_transaction_id.clone(),
+                message_version: item.request.message_version.clone(),
+                card_number: ccard.card_number.peek().clone(),
+                amount: item.request.amount,
+                currency: item.request.currency.to_string(),
+                three_ds_comp_ind: if item.request.three_ds_method_completed {
+                    MockThreeDsCompletionIndicator::Completed
+                } else {
+                    MockThreeDsCompletionIndicator::NotCompleted
+                },
+                browser_info: item.request.browser_info.clone(),
+            }),
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Current Payment Method".to_string(),
+            ))?,
+        }
+    }
+}
+
+/// Request fetching the result of the challenge answered by the customer
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockThreeDsResultsRequest {
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+    pub cres: String,
+}
+
+impl TryFrom<&types::PaymentsAuthenticateRouterData> for MockThreeDsResultsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthenticateRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            three_ds_server_trans_id: item.request.three_ds_server_transaction_id.clone(),
+            cres: item.request.challenge_response.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "challenge_response",
+                },
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MockThreeDsTransStatus {
+    #[serde(rename = "Y")]
+    Authenticated,
+    #[serde(rename = "N")]
+    NotAuthenticated,
+    #[serde(rename = "C")]
+    ChallengeRequired,
+}
+
+impl From<MockThreeDsTransStatus> for types::ThreeDsAuthenticationStatus {
+    fn from(item: MockThreeDsTransStatus) -> Self {
+        match item {
+            MockThreeDsTransStatus::Authenticated => Self::Succeeded,
+            MockThreeDsTransStatus::NotAuthenticated => Self::Failed,
+            MockThreeDsTransStatus::ChallengeRequired => Self::ChallengeRequired,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockThreeDsAuthenticationResponse {
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+    pub message_version: String,
+    pub trans_status: MockThreeDsTransStatus,
+    #[serde(rename = "acsURL")]
+    pub acs_url: Option<String>,
+    pub creq: Option<String>,
+    pub authentication_value: Option<String>,
+    pub eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    pub ds_trans_id: Option<String>,
+}
+
+// Auth Struct
+pub struct MockThreeDsAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for MockThreeDsAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = item {
+            Ok(Self {
+                api_key: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType)?
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            MockThreeDsPreAuthenticationResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            MockThreeDsPreAuthenticationResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let authentication = types::ThreeDsAuthentication {
+            connector: item.data.connector.clone(),
+            three_ds_server_transaction_id: item.response.three_ds_server_trans_id,
+            message_version: item.response.message_version,
+            status: types::ThreeDsAuthenticationStatus::Pending,
+            three_ds_method_url: item.response.three_ds_method_url,
+            three_ds_method_data: item.response.three_ds_method_data,
+            acs_url: None,
+            challenge_request: None,
+            authentication_value: None,
+            eci: None,
+            ds_transaction_id: None,
+        };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::ThreeDsAuthenticationResponse {
+                authentication,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            MockThreeDsAuthenticationResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            MockThreeDsAuthenticationResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let authentication = types::ThreeDsAuthentication {
+            connector: item.data.connector.clone(),
+            three_ds_server_transaction_id: item.response.three_ds_server_trans_id,
+            message_version: item.response.message_version,
+            status: item.response.trans_status.into(),
+            three_ds_method_url: None,
+            three_ds_method_data: None,
+            acs_url: item.response.acs_url,
+            challenge_request: item.response.creq,
+            authentication_value: item.response.authentication_value.map(Into::into),
+            eci: item.response.eci,
+            ds_transaction_id: item.response.ds_trans_id,
+        };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::ThreeDsAuthenticationResponse {
+                authentication,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockThreeDsErrorResponse {
+    pub error_code: String,
+    pub error_description: String,
+}
@@ -429,6 +429,42 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Payu {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -680,6 +716,83 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Payu {}
+impl api::PayoutFulfill for Payu {}
+impl api::PayoutCancel for Payu {}
+impl api::PayoutReverse for Payu {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Payu
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Payu
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Payu
+{
+}
+
+impl api::ConnectorMandateRevoke for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Payu
+{
+}
+
+impl api::ConnectorCustomer for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Payu
+{
+}
+
+impl api::Dispute for Payu {}
+impl api::AcceptDispute for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Payu
+{
+}
+
+impl api::SubmitEvidence for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Payu
+{
+}
+
+impl api::FileUpload for Payu {}
+impl api::UploadFile for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Payu
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Payu {
     fn get_webhook_object_reference_id(
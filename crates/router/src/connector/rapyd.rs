@@ -507,6 +507,42 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Rapyd {}
 
 impl
@@ -662,6 +698,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Rapyd {}
+impl api::PayoutFulfill for Rapyd {}
+impl api::PayoutCancel for Rapyd {}
+impl api::PayoutReverse for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::ConnectorMandateRevoke for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Rapyd
+{
+}
+
+impl api::ConnectorCustomer for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Rapyd
+{
+}
+
+impl api::Dispute for Rapyd {}
+impl api::AcceptDispute for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Rapyd
+{
+}
+
+impl api::SubmitEvidence for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Rapyd
+{
+}
+
+impl api::FileUpload for Rapyd {}
+impl api::UploadFile for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Rapyd
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Rapyd {
     fn get_webhook_source_verification_algorithm(
@@ -263,6 +263,42 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Shift4 {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -495,6 +531,83 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Shift4 {}
+impl api::PayoutFulfill for Shift4 {}
+impl api::PayoutCancel for Shift4 {}
+impl api::PayoutReverse for Shift4 {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl api::ConnectorMandateRevoke for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Shift4
+{
+}
+
+impl api::ConnectorCustomer for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Shift4
+{
+}
+
+impl api::Dispute for Shift4 {}
+impl api::AcceptDispute for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Shift4
+{
+}
+
+impl api::SubmitEvidence for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Shift4
+{
+}
+
+impl api::FileUpload for Shift4 {}
+impl api::UploadFile for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Shift4
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Shift4 {
     fn get_webhook_object_reference_id(
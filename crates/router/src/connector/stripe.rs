@@ -60,6 +60,42 @@ impl api::PaymentAuthorize for Stripe {}
 impl api::PaymentSync for Stripe {}
 impl api::PaymentVoid for Stripe {}
 impl api::PaymentCapture for Stripe {}
+impl api::PaymentIncrementalAuthorization for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Stripe {}
 impl api::ConnectorAccessToken for Stripe {}
 
@@ -168,12 +204,17 @@ impl
             .response
             .parse_struct("PaymentIntentResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
+        let router_data: types::PaymentsCaptureRouterData =
+            types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            })
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(types::RouterData {
+            status: stripe::get_capture_status(router_data.status, data.request.final_capture),
+            ..router_data
         })
-        .change_context(errors::ConnectorError::ResponseHandlingFailed)
     }
 
     fn get_error_response(
@@ -876,121 +917,994 @@ fn get_signature_elements_from_header(
     Ok(security_header_kvs)
 }
 
-#[async_trait::async_trait]
-impl api::IncomingWebhook for Stripe {
-    fn get_webhook_source_verification_algorithm(
+impl api::Payouts for Stripe {}
+impl api::PayoutFulfill for Stripe {}
+impl api::PayoutCancel for Stripe {}
+impl api::PayoutReverse for Stripe {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+}
+
+impl api::ConnectorMandateRevoke for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Stripe
+{
+    fn get_headers(
         &self,
-        _headers: &actix_web::http::header::HeaderMap,
-        _body: &[u8],
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::HmacSha256))
+        req: &types::MandateRevokeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::MandateRevokeType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
     }
 
-    fn get_webhook_source_verification_signature(
-        &self,
-        headers: &actix_web::http::header::HeaderMap,
-        _body: &[u8],
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
 
-        let signature = security_header_kvs
-            .remove("v1")
-            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
-            .into_report()?;
+    fn get_url(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        match stripe::StripeMandateReference::from(req.request.connector_mandate_id.clone()) {
+            stripe::StripeMandateReference::PaymentMethod { payment_method, .. } => Ok(format!(
+                "{}v1/payment_methods/{}/detach",
+                self.base_url(connectors),
+                payment_method
+            )),
+            stripe::StripeMandateReference::Legacy(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    "Revoking mandates made before payment methods were attached to customers"
+                        .to_string(),
+                )
+                .into())
+            }
+        }
+    }
 
-        hex::decode(signature)
-            .into_report()
-            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    fn build_request(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        // Legacy mandates are not backed by a payment method attached to a customer, there is
+        // nothing to detach at Stripe and they are revoked by the router alone
+        if let stripe::StripeMandateReference::Legacy(_) =
+            stripe::StripeMandateReference::from(req.request.connector_mandate_id.clone())
+        {
+            return Ok(None);
+        }
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
     }
 
-    fn get_webhook_source_verification_message(
+    #[instrument(skip_all)]
+    fn handle_response(
         &self,
-        headers: &actix_web::http::header::HeaderMap,
-        body: &[u8],
-        _merchant_id: &str,
-        _secret: &[u8],
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
+        data: &types::MandateRevokeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::MandateRevokeRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
 
-        let timestamp = security_header_kvs
-            .remove("t")
-            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
-            .into_report()?;
+        let response: stripe::StripePaymentMethodDetachResponse = res
+            .response
+            .parse_struct("Stripe PaymentMethodDetachResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
 
-        Ok(format!(
-            "{}.{}",
-            String::from_utf8_lossy(&timestamp),
-            String::from_utf8_lossy(body)
-        )
-        .into_bytes())
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
     }
+}
 
-    async fn get_webhook_source_verification_merchant_secret(
+impl api::ConnectorCustomer for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Stripe
+{
+    fn get_headers(
         &self,
-        db: &dyn StorageInterface,
-        merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
-        let secret = db
-            .get_key(&key)
-            .await
-            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+        req: &types::ConnectorCustomerRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::ConnectorCustomerType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
 
-        Ok(secret)
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
     }
 
-    fn get_webhook_object_reference_id(
+    fn get_url(
         &self,
-        body: &[u8],
+        _req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        let details: stripe::StripeWebhookObjectId = body
-            .parse_struct("StripeWebhookObjectId")
-            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
-
-        Ok(details.data.object.id)
+        Ok(format!("{}{}", self.base_url(connectors), "v1/customers"))
     }
 
-    fn get_webhook_event_type(
+    fn get_request_body(
         &self,
-        body: &[u8],
-    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        let details: stripe::StripeWebhookObjectEventType = body
-            .parse_struct("StripeWebhookObjectEventType")
-            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        req: &types::ConnectorCustomerRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req =
+            utils::Encode::<stripe::CreateCustomerRequest>::convert_and_url_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
 
-        Ok(match details.event_type.as_str() {
-            "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
-            "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
-        })
+    fn build_request(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorCustomerType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::ConnectorCustomerType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::ConnectorCustomerType::get_request_body(self, req)?)
+                .build(),
+        ))
     }
 
-    fn get_webhook_resource_object(
+    #[instrument(skip_all)]
+    fn handle_response(
         &self,
-        body: &[u8],
-    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        let details: stripe::StripeWebhookObjectResource = body
-            .parse_struct("StripeWebhookObjectResource")
-            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        data: &types::ConnectorCustomerRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::ConnectorCustomerRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
 
-        Ok(details.data.object)
+        let response: stripe::StripeCustomerResponse = res
+            .response
+            .parse_struct("StripeCustomerResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
     }
-}
 
-impl services::ConnectorRedirectResponse for Stripe {
-    fn get_flow_type(
+    fn get_error_response(
         &self,
-        query_params: &str,
-    ) -> CustomResult<crate::core::payments::CallConnectorAction, errors::ConnectorError> {
-        let query =
-            serde_urlencoded::from_str::<transformers::StripeRedirectResponse>(query_params)
-                .into_report()
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-
-        Ok(query
-            .redirect_status
-            .map_or(payments::CallConnectorAction::Trigger, |status| {
-                payments::CallConnectorAction::StatusUpdate(status.into())
-            }))
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::Dispute for Stripe {}
+impl api::AcceptDispute for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::AcceptDisputeType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}/close",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::AcceptDisputeType::get_url(self, req, connectors)?)
+                .headers(types::AcceptDisputeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::AcceptDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::AcceptDisputeRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::SubmitEvidence for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::SubmitEvidenceType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req = utils::Encode::<stripe::Evidence>::convert_and_url_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::SubmitEvidenceType::get_url(self, req, connectors)?)
+                .headers(types::SubmitEvidenceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::SubmitEvidenceType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::SubmitEvidenceRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::SubmitEvidenceRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::FileUpload for Stripe {
+    fn validate_file_upload(
+        &self,
+        purpose: api::FilePurpose,
+        file_size: i32,
+        file_type: mime::Mime,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        match purpose {
+            api::FilePurpose::DisputeEvidence => {
+                let supported_file_types = ["image/jpeg", "image/png", "application/pdf"];
+                // 5 Megabytes (MB)
+                if file_size > 5_000_000 {
+                    Err(errors::ConnectorError::FileValidationFailed {
+                        reason: "file_size exceeded the max file size of 5MB".to_owned(),
+                    })?
+                }
+                if !supported_file_types.contains(&file_type.to_string().as_str()) {
+                    Err(errors::ConnectorError::FileValidationFailed {
+                        reason: "file_type does not match JPEG, JPG, PNG, or PDF format".to_owned(),
+                    })?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl api::UploadFile for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The multipart boundary is part of the content type, so it is set by the client
+        let mut header = vec![(headers::X_ROUTER.to_string(), "test".to_string())];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "multipart/form-data"
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            connectors.stripe.secondary_base_url, "v1/files"
+        ))
+    }
+
+    fn get_request_form_data(
+        &self,
+        req: &types::UploadFileRouterData,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        let stripe_req = stripe::construct_file_upload_request(req.request.clone())?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::UploadFileType::get_url(self, req, connectors)?)
+                .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+                .form_data(types::UploadFileType::get_request_form_data(self, req)?)
+                .content_type(services::ContentType::FormData)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeFileUploadResponse = res
+            .response
+            .parse_struct("Stripe FileUploadResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Stripe {
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
+
+        let signature = security_header_kvs
+            .remove("v1")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
+
+        let timestamp = security_header_kvs
+            .remove("t")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        Ok(format!(
+            "{}.{}",
+            String::from_utf8_lossy(&timestamp),
+            String::from_utf8_lossy(body)
+        )
+        .into_bytes())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
+        let secret = db
+            .get_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+        Ok(secret)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let details: stripe::StripeWebhookObjectId = body
+            .parse_struct("StripeWebhookObjectId")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        let object = details.data.object;
+        Ok(match object.payment_intent {
+            // Refund objects are looked up by the refund id, not by the refunded payment
+            _ if object.object == "refund" => object.id,
+            // Dispute objects reference the disputed payment through `payment_intent`
+            Some(payment_intent) => payment_intent,
+            None => object.id,
+        })
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let details: stripe::StripeWebhookObjectEventType = body
+            .parse_struct("StripeWebhookObjectEventType")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(match details.event_type.as_str() {
+            "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
+            "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            "charge.dispute.created" => api::IncomingWebhookEvent::DisputeOpened,
+            "charge.dispute.closed" => {
+                let dispute: stripe::StripeWebhookObjectDispute = body
+                    .parse_struct("StripeWebhookObjectDispute")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+                match dispute.data.object.status {
+                    stripe::StripeDisputeStatus::Won => api::IncomingWebhookEvent::DisputeWon,
+                    stripe::StripeDisputeStatus::WarningClosed => {
+                        api::IncomingWebhookEvent::DisputeCancelled
+                    }
+                    _ => api::IncomingWebhookEvent::DisputeLost,
+                }
+            }
+            "charge.refund.updated" => {
+                let refund: stripe::StripeWebhookObjectRefund = body
+                    .parse_struct("StripeWebhookObjectRefund")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+                match refund.data.object.status {
+                    stripe::RefundStatus::Succeeded => api::IncomingWebhookEvent::RefundSuccess,
+                    stripe::RefundStatus::Failed | stripe::RefundStatus::Canceled => {
+                        api::IncomingWebhookEvent::RefundFailure
+                    }
+                    // Refunds which are yet to reach a terminal state are not acted upon
+                    stripe::RefundStatus::Pending | stripe::RefundStatus::RequiresAction => {
+                        Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?
+                    }
+                }
+            }
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+        })
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let details: stripe::StripeWebhookObjectResource = body
+            .parse_struct("StripeWebhookObjectResource")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(details.data.object)
+    }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let details: stripe::StripeWebhookObjectDispute = body
+            .parse_struct("StripeWebhookObjectDispute")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        api::DisputePayload::try_from(details.data.object)
+    }
+}
+
+impl services::ConnectorRedirectResponse for Stripe {
+    fn get_flow_type(
+        &self,
+        query_params: &str,
+    ) -> CustomResult<crate::core::payments::CallConnectorAction, errors::ConnectorError> {
+        let query =
+            serde_urlencoded::from_str::<transformers::StripeRedirectResponse>(query_params)
+                .into_report()
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(query
+            .redirect_status
+            .map_or(payments::CallConnectorAction::Trigger, |status| {
+                payments::CallConnectorAction::StatusUpdate(status.into())
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::{api::IncomingWebhook, storage::enums as storage_enums};
+
+    #[test]
+    fn test_get_dispute_details() {
+        let body = br#"{
+            "data": {
+                "object": {
+                    "id": "dp_1",
+                    "amount": 1000,
+                    "currency": "usd",
+                    "reason": "fraudulent",
+                    "status": "warning_needs_response",
+                    "created": 1680000000,
+                    "evidence_details": { "due_by": 1680864000 }
+                }
+            }
+        }"#;
+        let dispute_details = Stripe.get_dispute_details(body).unwrap();
+
+        assert_eq!(dispute_details.amount, "1000");
+        assert_eq!(dispute_details.currency, "USD");
+        assert_eq!(
+            dispute_details.dispute_stage,
+            storage_enums::DisputeStage::PreDispute
+        );
+        assert_eq!(dispute_details.connector_status, "warning_needs_response");
+        assert_eq!(dispute_details.connector_dispute_id, "dp_1");
+        assert_eq!(
+            dispute_details.connector_reason.as_deref(),
+            Some("fraudulent")
+        );
+        assert_eq!(
+            dispute_details.challenge_required_by,
+            Some(time::macros::datetime!(2023-04-07 10:40:00))
+        );
+        assert_eq!(
+            dispute_details.created_at,
+            Some(time::macros::datetime!(2023-03-28 10:40:00))
+        );
+    }
+
+    #[test]
+    fn test_dispute_status() {
+        assert_eq!(
+            storage_enums::DisputeStatus::from(stripe::StripeDisputeStatus::UnderReview),
+            storage_enums::DisputeStatus::DisputeChallenged
+        );
+        assert_eq!(
+            storage_enums::DisputeStatus::from(stripe::StripeDisputeStatus::ChargeRefunded),
+            storage_enums::DisputeStatus::DisputeLost
+        );
+        assert_eq!(
+            storage_enums::DisputeStage::from(stripe::StripeDisputeStatus::Won),
+            storage_enums::DisputeStage::Dispute
+        );
+    }
+
+    #[test]
+    fn test_get_dispute_details_invalid_body() {
+        assert!(Stripe.get_dispute_details(br#"{"data": {}}"#).is_err());
+    }
+
+    fn get_refund_webhook_body(status: &str) -> Vec<u8> {
+        serde_json::json!({
+            "type": "charge.refund.updated",
+            "data": {
+                "object": {
+                    "id": "re_1",
+                    "object": "refund",
+                    "payment_intent": "pi_1",
+                    "status": status
+                }
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_refund_webhook_event_type() {
+        assert_eq!(
+            Stripe
+                .get_webhook_event_type(&get_refund_webhook_body("succeeded"))
+                .unwrap(),
+            api::IncomingWebhookEvent::RefundSuccess
+        );
+        for status in ["failed", "canceled"] {
+            assert_eq!(
+                Stripe
+                    .get_webhook_event_type(&get_refund_webhook_body(status))
+                    .unwrap(),
+                api::IncomingWebhookEvent::RefundFailure
+            );
+        }
+        // Refunds which are yet to reach a terminal state are not acted upon
+        for status in ["pending", "requires_action"] {
+            assert!(Stripe
+                .get_webhook_event_type(&get_refund_webhook_body(status))
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_refund_webhook_object_reference_id() {
+        // Refunds are referenced by the refund id rather than the refunded payment
+        assert_eq!(
+            Stripe
+                .get_webhook_object_reference_id(&get_refund_webhook_body("succeeded"))
+                .unwrap(),
+            "re_1"
+        );
+    }
+
+    fn get_mandate_revoke_router_data(
+        connector_mandate_id: &str,
+    ) -> types::MandateRevokeRouterData {
+        types::RouterData {
+            flow: std::marker::PhantomData,
+            merchant_id: "merchant_id".to_string(),
+            connector: "stripe".to_string(),
+            payment_id: "payment_id".to_string(),
+            attempt_id: None,
+            status: storage_enums::AttemptStatus::default(),
+            payment_method: storage_enums::PaymentMethodType::Card,
+            connector_auth_type: types::ConnectorAuthType::HeaderKey {
+                api_key: "api_key".to_string(),
+            },
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: types::PaymentAddress::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: types::MandateRevokeRequestData {
+                mandate_id: "man_1".to_string(),
+                connector_mandate_id: connector_mandate_id.to_string(),
+                customer_id: "cus_1".to_string(),
+            },
+            response: Err(types::ErrorResponse::default()),
+            payment_method_id: None,
+        }
+    }
+
+    fn get_connectors() -> settings::Connectors {
+        let mut connectors = settings::Connectors::default();
+        connectors.stripe.base_url = "https://api.stripe.com/".to_string();
+        connectors
+    }
+
+    #[test]
+    fn test_mandate_revoke_request() {
+        let router_data = get_mandate_revoke_router_data("cus_stripe:pm_stripe");
+        let request =
+            types::MandateRevokeType::build_request(&Stripe, &router_data, &get_connectors())
+                .unwrap();
+
+        // The payment method of the mandate is detached from the customer
+        assert_eq!(
+            request.map(|request| request.url),
+            Some("https://api.stripe.com/v1/payment_methods/pm_stripe/detach".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mandate_revoke_legacy_request() {
+        // Legacy mandates have nothing to detach and are revoked by the router alone
+        let router_data = get_mandate_revoke_router_data("mandate_stripe");
+        let request =
+            types::MandateRevokeType::build_request(&Stripe, &router_data, &get_connectors())
+                .unwrap();
+
+        assert!(request.is_none());
+    }
+
+    fn get_mandate_revoke_response(customer: Option<&str>) -> types::MandateRevokeRouterData {
+        let response: stripe::StripePaymentMethodDetachResponse =
+            serde_json::from_value(serde_json::json!({
+                "id": "pm_stripe",
+                "customer": customer,
+            }))
+            .unwrap();
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: get_mandate_revoke_router_data("cus_stripe:pm_stripe"),
+            http_code: 200,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_mandate_revoke_response() {
+        assert!(matches!(
+            get_mandate_revoke_response(None).response,
+            Ok(types::MandateRevokeResponseData {
+                mandate_status: storage_enums::MandateStatus::Revoked
+            })
+        ));
+        // A payment method still attached to the customer can be charged again
+        assert!(matches!(
+            get_mandate_revoke_response(Some("cus_stripe")).response,
+            Ok(types::MandateRevokeResponseData {
+                mandate_status: storage_enums::MandateStatus::Active
+            })
+        ));
+    }
+
+    #[test]
+    fn test_mandate_revoke_error_response() {
+        let error_response = types::MandateRevokeType::get_error_response(
+            &Stripe,
+            types::Response {
+                response: br#"{
+                    "error": {
+                        "code": "resource_missing",
+                        "message": "No such PaymentMethod: 'pm_stripe'",
+                        "param": "payment_method",
+                        "type": "invalid_request_error"
+                    }
+                }"#
+                .to_vec()
+                .into(),
+                status_code: 404,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(error_response.code, "resource_missing");
+        assert_eq!(error_response.status_code, 404);
     }
 }
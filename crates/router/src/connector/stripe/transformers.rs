@@ -10,7 +10,7 @@ use url::Url;
 use uuid::Uuid;
 
 use crate::{
-    core::errors,
+    core::errors::{self, CustomResult},
     pii::{self, ExposeOptionInterface, Secret},
     services,
     types::{self, api, storage::enums},
@@ -81,6 +81,13 @@ pub struct PaymentIntentRequest {
     pub return_url: String,
     pub confirm: bool,
     pub off_session: Option<bool>,
+    /// Customer which the payment method of the payment is attached to
+    pub customer: Option<String>,
+    pub setup_future_usage: Option<enums::FutureUsage>,
+    /// The payment method saved with the mandate, when the payment is made with a mandate
+    pub payment_method: Option<String>,
+    /// Mandate reference of Stripe, for mandates made before payment methods were attached to
+    /// customers
     pub mandate: Option<String>,
     pub description: Option<String>,
     #[serde(flatten)]
@@ -88,6 +95,14 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(rename = "payment_method_options[card][request_multicapture]")]
+    pub request_multicapture: Option<StripeRequestMulticapture>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestMulticapture {
+    IfAvailable,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -101,10 +116,48 @@ pub struct SetupIntentRequest {
     pub confirm: bool,
     pub usage: Option<enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub customer: Option<String>,
     #[serde(flatten)]
     pub payment_data: StripePaymentMethodData,
 }
 
+/// Reference of a mandate made with Stripe, which is stored as the connector mandate id
+#[derive(Debug, Eq, PartialEq)]
+pub enum StripeMandateReference {
+    /// Payment method of the mandate, attached to a customer to be charged off session
+    PaymentMethod {
+        customer: String,
+        payment_method: String,
+    },
+    /// Any other reference, which is a mandate reference of Stripe for mandates made before
+    /// payment methods were attached to customers
+    Legacy(String),
+}
+
+impl StripeMandateReference {
+    fn get_connector_mandate_id(
+        customer: Option<&String>,
+        payment_method: Option<&String>,
+    ) -> Option<String> {
+        // A payment method which is not attached to a customer cannot be charged off session
+        customer
+            .zip(payment_method)
+            .map(|(customer, payment_method)| format!("{customer}:{payment_method}"))
+    }
+}
+
+impl From<String> for StripeMandateReference {
+    fn from(connector_mandate_id: String) -> Self {
+        match connector_mandate_id.split_once(':') {
+            Some((customer, payment_method)) => Self::PaymentMethod {
+                customer: customer.to_string(),
+                payment_method: payment_method.to_string(),
+            },
+            None => Self::Legacy(connector_mandate_id),
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct StripeCardData {
     #[serde(rename = "payment_method_types[]")]
@@ -201,22 +254,39 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                                                             // let api::PaymentMethod::Card(a) = item.payment_method_data;
                                                             // let api::PaymentMethod::Card(a) = item.payment_method_data;
 
-        let (payment_data, mandate) = {
+        let (payment_data, customer, payment_method, mandate) = {
             match item
                 .request
                 .mandate_id
                 .clone()
                 .and_then(|mandate_ids| mandate_ids.connector_mandate_id)
+                .map(StripeMandateReference::from)
             {
                 None => {
                     let payment_method: StripePaymentMethodData =
                         (item.request.payment_method_data.clone(), item.auth_type).try_into()?;
-                    (Some(payment_method), None)
+                    (
+                        Some(payment_method),
+                        item.request.connector_customer.clone(),
+                        None,
+                        None,
+                    )
                 }
-                Some(mandate_id) => (None, Some(mandate_id)),
+                Some(StripeMandateReference::PaymentMethod {
+                    customer,
+                    payment_method,
+                }) => (None, Some(customer), Some(payment_method), None),
+                Some(StripeMandateReference::Legacy(mandate)) => (None, None, None, Some(mandate)),
             }
         };
 
+        // The payment method is saved for off session payments when a mandate is set up with it
+        let setup_future_usage = item
+            .request
+            .connector_customer
+            .as_ref()
+            .map(|_| enums::FutureUsage::OffSession);
+
         let shipping_address = match item.address.shipping.clone() {
             Some(mut shipping) => StripeShippingAddress {
                 city: shipping.address.as_mut().and_then(|a| a.city.take()),
@@ -252,10 +322,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             &item.request.payment_method_data,
         )?;
 
-        let off_session = item
-            .request
-            .off_session
-            .and_then(|value| mandate.as_ref().map(|_| value));
+        let off_session = item.request.off_session.filter(|_| payment_data.is_none());
 
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
@@ -273,8 +340,14 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             description: item.description.clone(),
             shipping: shipping_address,
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
+            request_multicapture: (item.request.capture_method
+                == Some(enums::CaptureMethod::ManualMultiple))
+            .then_some(StripeRequestMulticapture::IfAvailable),
             payment_data,
             off_session,
+            customer,
+            setup_future_usage,
+            payment_method,
             mandate,
         })
     }
@@ -298,6 +371,7 @@ impl TryFrom<&types::VerifyRouterData> for SetupIntentRequest {
             payment_data,
             off_session: item.request.off_session,
             usage: item.request.setup_future_usage,
+            customer: item.request.connector_customer.clone(),
         })
     }
 }
@@ -359,7 +433,7 @@ pub struct PaymentIntentResponse {
     pub statement_descriptor_suffix: Option<String>,
     pub metadata: StripeMetadata,
     pub next_action: Option<StripeNextActionResponse>,
-    pub payment_method_options: Option<StripePaymentMethodOptions>,
+    pub payment_method: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
@@ -373,7 +447,7 @@ pub struct SetupIntentResponse {
     pub statement_descriptor_suffix: Option<String>,
     pub metadata: StripeMetadata,
     pub next_action: Option<StripeNextActionResponse>,
-    pub payment_method_options: Option<StripePaymentMethodOptions>,
+    pub payment_method: Option<String>,
 }
 
 impl<F, T>
@@ -401,17 +475,12 @@ impl<F, T>
             },
         );
 
-        let mandate_reference =
-            item.response
-                .payment_method_options
-                .and_then(|payment_method_options| match payment_method_options {
-                    StripePaymentMethodOptions::Card {
-                        mandate_options, ..
-                    } => mandate_options.map(|mandate_options| mandate_options.reference),
-                    StripePaymentMethodOptions::Klarna {} => None,
-                    StripePaymentMethodOptions::Affirm {} => None,
-                    StripePaymentMethodOptions::AfterpayClearpay {} => None,
-                });
+        // Stripe mandates are made with the payment method of the payment attached to the
+        // customer, and are revoked by detaching the payment method
+        let mandate_reference = StripeMandateReference::get_connector_mandate_id(
+            item.response.customer.as_ref(),
+            item.response.payment_method.as_ref(),
+        );
 
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
@@ -457,17 +526,12 @@ impl<F, T>
             },
         );
 
-        let mandate_reference =
-            item.response
-                .payment_method_options
-                .and_then(|payment_method_options| match payment_method_options {
-                    StripePaymentMethodOptions::Card {
-                        mandate_options, ..
-                    } => mandate_options.map(|mandate_option| mandate_option.reference),
-                    StripePaymentMethodOptions::Klarna {} => None,
-                    StripePaymentMethodOptions::Affirm {} => None,
-                    StripePaymentMethodOptions::AfterpayClearpay {} => None,
-                });
+        // Stripe mandates are made with the payment method of the payment attached to the
+        // customer, and are revoked by detaching the payment method
+        let mandate_reference = StripeMandateReference::get_connector_mandate_id(
+            item.response.customer.as_ref(),
+            item.response.payment_method.as_ref(),
+        );
 
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
@@ -557,13 +621,14 @@ pub enum RefundStatus {
     #[default]
     Pending,
     RequiresAction,
+    Canceled,
 }
 
 impl From<RefundStatus> for enums::RefundStatus {
     fn from(item: RefundStatus) -> Self {
         match item {
             self::RefundStatus::Succeeded => Self::Success,
-            self::RefundStatus::Failed => Self::Failure,
+            self::RefundStatus::Failed | self::RefundStatus::Canceled => Self::Failure,
             self::RefundStatus::Pending => Self::Pending,
             self::RefundStatus::RequiresAction => Self::ManualReview,
         }
@@ -691,28 +756,13 @@ pub enum CancellationReason {
     Abandoned,
 }
 
-#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
-#[non_exhaustive]
-#[serde(rename_all = "snake_case")]
-pub enum StripePaymentMethodOptions {
-    Card {
-        mandate_options: Option<StripeMandateOptions>,
-    },
-    Klarna {},
-    Affirm {},
-    AfterpayClearpay {},
-}
-// #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
-// pub struct Card
-#[derive(serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
-pub struct StripeMandateOptions {
-    reference: String, // Extendable, But only important field to be captured
-}
 /// Represents the capture request body for stripe connector.
 #[derive(Debug, Serialize, Clone, Copy)]
 pub struct CaptureRequest {
     /// If amount_to_capture is None stripe captures the amount in the payment intent.
     amount_to_capture: Option<i64>,
+    /// If final_capture is None stripe releases the uncaptured amount after the capture.
+    final_capture: Option<bool>,
 }
 
 impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
@@ -720,10 +770,22 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             amount_to_capture: item.request.amount_to_capture,
+            final_capture: (!item.request.final_capture).then_some(false),
         })
     }
 }
 
+/// A payment intent captured partially stays in `requires_capture` until its final capture
+pub fn get_capture_status(
+    status: enums::AttemptStatus,
+    final_capture: bool,
+) -> enums::AttemptStatus {
+    match status {
+        enums::AttemptStatus::Authorized if !final_capture => enums::AttemptStatus::PartialCharged,
+        status => status,
+    }
+}
+
 // #[cfg(test)]
 // mod test_stripe_transformers {
 //     use super::*;
@@ -764,6 +826,8 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
 #[derive(Debug, Deserialize)]
 pub struct StripeWebhookDataObjectId {
     pub id: String,
+    pub object: String,
+    pub payment_intent: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -792,6 +856,253 @@ pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookObjectRefund {
+    pub data: StripeWebhookDataRefund,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDataRefund {
+    pub object: StripeRefundObject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeRefundObject {
+    pub id: String,
+    pub status: RefundStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookObjectDispute {
+    pub data: StripeWebhookDataDispute,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDataDispute {
+    pub object: StripeDisputeObject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeObject {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+    pub created: i64,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Won,
+    Lost,
+}
+
+impl From<StripeDisputeStatus> for enums::DisputeStage {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match status {
+            StripeDisputeStatus::WarningNeedsResponse
+            | StripeDisputeStatus::WarningUnderReview
+            | StripeDisputeStatus::WarningClosed => Self::PreDispute,
+            StripeDisputeStatus::NeedsResponse
+            | StripeDisputeStatus::UnderReview
+            | StripeDisputeStatus::ChargeRefunded
+            | StripeDisputeStatus::Won
+            | StripeDisputeStatus::Lost => Self::Dispute,
+        }
+    }
+}
+
+impl From<StripeDisputeStatus> for enums::DisputeStatus {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match status {
+            StripeDisputeStatus::WarningNeedsResponse | StripeDisputeStatus::NeedsResponse => {
+                Self::DisputeOpened
+            }
+            StripeDisputeStatus::WarningUnderReview | StripeDisputeStatus::UnderReview => {
+                Self::DisputeChallenged
+            }
+            StripeDisputeStatus::WarningClosed => Self::DisputeCancelled,
+            StripeDisputeStatus::ChargeRefunded | StripeDisputeStatus::Lost => Self::DisputeLost,
+            StripeDisputeStatus::Won => Self::DisputeWon,
+        }
+    }
+}
+
+fn primitive_date_time_from_unix_timestamp(
+    timestamp: i64,
+) -> Result<time::PrimitiveDateTime, error_stack::Report<errors::ConnectorError>> {
+    let date_time = time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+    Ok(time::PrimitiveDateTime::new(
+        date_time.date(),
+        date_time.time(),
+    ))
+}
+
+impl TryFrom<StripeDisputeObject> for api::DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: StripeDisputeObject) -> Result<Self, Self::Error> {
+        let challenge_required_by = item
+            .evidence_details
+            .and_then(|evidence_details| evidence_details.due_by)
+            .map(primitive_date_time_from_unix_timestamp)
+            .transpose()?;
+        Ok(Self {
+            amount: item.amount.to_string(),
+            currency: item.currency.to_uppercase(),
+            dispute_stage: enums::DisputeStage::from(item.status.clone()),
+            connector_status: item.status.to_string(),
+            connector_dispute_id: item.id,
+            connector_reason: item.reason,
+            connector_reason_code: None,
+            challenge_required_by,
+            created_at: Some(primitive_date_time_from_unix_timestamp(item.created)?),
+            updated_at: None,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub status: StripeDisputeStatus,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, StripeDisputeResponse, T, types::AcceptDisputeResponse>>
+    for types::RouterData<F, T, types::AcceptDisputeResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, StripeDisputeResponse, T, types::AcceptDisputeResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AcceptDisputeResponse {
+                dispute_status: enums::DisputeStatus::DisputeAccepted,
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Evidence {
+    #[serde(rename = "evidence[cancellation_policy]")]
+    pub cancellation_policy: Option<String>,
+    #[serde(rename = "evidence[customer_communication]")]
+    pub customer_communication: Option<String>,
+    #[serde(rename = "evidence[customer_signature]")]
+    pub customer_signature: Option<String>,
+    #[serde(rename = "evidence[receipt]")]
+    pub receipt: Option<String>,
+    #[serde(rename = "evidence[refund_policy]")]
+    pub refund_policy: Option<String>,
+    #[serde(rename = "evidence[service_documentation]")]
+    pub service_documentation: Option<String>,
+    #[serde(rename = "evidence[shipping_documentation]")]
+    pub shipping_documentation: Option<String>,
+    #[serde(rename = "evidence[uncategorized_file]")]
+    pub uncategorized_file: Option<String>,
+    #[serde(rename = "evidence[uncategorized_text]")]
+    pub uncategorized_text: Option<String>,
+    pub submit: bool,
+}
+
+impl From<&types::SubmitEvidenceRouterData> for Evidence {
+    fn from(item: &types::SubmitEvidenceRouterData) -> Self {
+        let submit_evidence_request_data = item.request.clone();
+        Self {
+            cancellation_policy: submit_evidence_request_data.cancellation_policy_provider_file_id,
+            customer_communication: submit_evidence_request_data
+                .customer_communication_provider_file_id,
+            customer_signature: submit_evidence_request_data.customer_signature_provider_file_id,
+            receipt: submit_evidence_request_data.receipt_provider_file_id,
+            refund_policy: submit_evidence_request_data.refund_policy_provider_file_id,
+            service_documentation: submit_evidence_request_data
+                .service_documentation_provider_file_id,
+            shipping_documentation: submit_evidence_request_data
+                .shipping_documentation_provider_file_id,
+            uncategorized_file: submit_evidence_request_data.uncategorized_file_provider_file_id,
+            uncategorized_text: submit_evidence_request_data.uncategorized_text,
+            // Evidence is submitted right away, it cannot be updated afterwards
+            submit: true,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, StripeDisputeResponse, T, types::SubmitEvidenceResponse>>
+    for types::RouterData<F, T, types::SubmitEvidenceResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, StripeDisputeResponse, T, types::SubmitEvidenceResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::SubmitEvidenceResponse {
+                dispute_status: enums::DisputeStatus::from(item.response.status.clone()),
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeFileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
+pub fn construct_file_upload_request(
+    file_upload_router_data: types::UploadFileRequestData,
+) -> CustomResult<reqwest::multipart::Form, errors::ConnectorError> {
+    let mut multipart = reqwest::multipart::Form::new();
+    multipart = multipart.text("purpose", "dispute_evidence");
+    let file_data = reqwest::multipart::Part::bytes(file_upload_router_data.file)
+        .file_name(file_upload_router_data.file_key)
+        .mime_str(file_upload_router_data.file_type.as_ref())
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failure in constructing file data")?;
+    multipart = multipart.part("file", file_data);
+    Ok(multipart)
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, StripeFileUploadResponse, T, types::UploadFileResponse>>
+    for types::RouterData<F, T, types::UploadFileResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, StripeFileUploadResponse, T, types::UploadFileResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::UploadFileResponse {
+                provider_file_id: item.response.file_id,
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentMethodData {
     type Error = errors::ConnectorError;
     fn try_from(
@@ -855,3 +1166,92 @@ impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentM
         }
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct CreateCustomerRequest {
+    pub description: Option<String>,
+    pub email: Option<Secret<String, pii::Email>>,
+    #[serde(rename = "metadata[customer_id]")]
+    pub metadata_customer_id: Option<String>,
+}
+
+impl TryFrom<&types::ConnectorCustomerRouterData> for CreateCustomerRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorCustomerRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: item.request.description.clone(),
+            email: item.request.email.clone(),
+            metadata_customer_id: item.request.customer_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeCustomerResponse {
+    pub id: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripeCustomerResponse,
+            T,
+            types::ConnectorCustomerResponseData,
+        >,
+    > for types::RouterData<F, T, types::ConnectorCustomerResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeCustomerResponse,
+            T,
+            types::ConnectorCustomerResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::ConnectorCustomerResponseData {
+                connector_customer_id: item.response.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripePaymentMethodDetachResponse {
+    pub id: String,
+    pub customer: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripePaymentMethodDetachResponse,
+            T,
+            types::MandateRevokeResponseData,
+        >,
+    > for types::RouterData<F, T, types::MandateRevokeResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripePaymentMethodDetachResponse,
+            T,
+            types::MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // A payment method which is no longer attached to the customer cannot be charged again
+        let mandate_status = match item.response.customer {
+            None => enums::MandateStatus::Revoked,
+            Some(_) => enums::MandateStatus::Active,
+        };
+        Ok(Self {
+            response: Ok(types::MandateRevokeResponseData { mandate_status }),
+            ..item.data
+        })
+    }
+}
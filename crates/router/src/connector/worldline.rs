@@ -395,6 +395,42 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Worldline {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -651,6 +687,83 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Worldline {}
+impl api::PayoutFulfill for Worldline {}
+impl api::PayoutCancel for Worldline {}
+impl api::PayoutReverse for Worldline {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl api::ConnectorMandateRevoke for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Worldline
+{
+}
+
+impl api::ConnectorCustomer for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Worldline
+{
+}
+
+impl api::Dispute for Worldline {}
+impl api::AcceptDispute for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Worldline
+{
+}
+
+impl api::SubmitEvidence for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldline
+{
+}
+
+impl api::FileUpload for Worldline {}
+impl api::UploadFile for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Worldline
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldline {
     fn get_webhook_object_reference_id(
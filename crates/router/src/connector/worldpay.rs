@@ -346,6 +346,42 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentPreAuthenticate for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::PreAuthenticate,
+        types::PaymentsPreAuthenticateData,
+        types::PaymentsResponseData,
+    > for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl api::PaymentAuthenticate for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::Authenticate,
+        types::PaymentsAuthenticateData,
+        types::PaymentsResponseData,
+    > for Worldpay
+{
+    // Not Implemented (R)
+}
+
 impl api::PaymentSession for Worldpay {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -593,6 +629,83 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payouts for Worldpay {}
+impl api::PayoutFulfill for Worldpay {}
+impl api::PayoutCancel for Worldpay {}
+impl api::PayoutReverse for Worldpay {}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::ConnectorMandateRevoke for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Worldpay
+{
+}
+
+impl api::ConnectorCustomer for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > for Worldpay
+{
+}
+
+impl api::Dispute for Worldpay {}
+impl api::AcceptDispute for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Worldpay
+{
+}
+
+impl api::SubmitEvidence for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldpay
+{
+}
+
+impl api::FileUpload for Worldpay {}
+impl api::UploadFile for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Worldpay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldpay {
     fn get_webhook_object_reference_id(
@@ -23,3 +23,6 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+
+/// Minimum length of the secret with which the webhooks sent to a merchant are signed
+pub(crate) const MIN_WEBHOOK_SECRET_LENGTH: usize = 32;
@@ -1,11 +1,21 @@
 pub mod admin;
 pub mod api_keys;
 pub mod configs;
+pub mod connector_health;
 pub mod customers;
+pub mod disputes;
 pub mod errors;
+pub mod exports;
+pub mod files;
+pub mod idempotency;
 pub mod mandate;
+#[cfg(feature = "mock_three_ds")]
+pub mod mock_three_ds;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
+pub mod rate_limit;
 pub mod refunds;
+pub mod subscriptions;
 pub mod utils;
 pub mod webhooks;
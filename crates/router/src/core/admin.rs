@@ -1,5 +1,8 @@
+use std::collections::HashSet;
+
 use common_utils::ext_traits::ValueExt;
 use error_stack::{report, FutureExt, ResultExt};
+use masking::PeekInterface;
 use storage_models::{enums, merchant_account};
 use uuid::Uuid;
 
@@ -10,7 +13,8 @@ use crate::{
     pii::Secret,
     services::api as service_api,
     types::{
-        self, api,
+        self,
+        api::{self, enums as api_enums},
         storage::{self, MerchantAccount},
         transformers::{ForeignInto, ForeignTryInto},
     },
@@ -41,6 +45,10 @@ pub async fn create_merchant_account(
             })?,
     );
 
+    if let Some(ref webhook_details) = req.webhook_details {
+        validate_webhook_secret(webhook_details)?;
+    }
+
     let webhook_details = Some(
         utils::Encode::<api::WebhookDetails>::encode_to_value(&req.webhook_details)
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
@@ -49,13 +57,7 @@ pub async fn create_merchant_account(
     );
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        validate_routing_algorithm(routing_algorithm)?;
     }
 
     let merchant_account = storage::MerchantAccountNew {
@@ -93,6 +95,197 @@ pub async fn create_merchant_account(
     ))
 }
 
+fn validate_routing_algorithm(
+    routing_algorithm: &serde_json::Value,
+) -> RouterResult<api::RoutingAlgorithm> {
+    validate_routing_algorithm_is_supported(routing_algorithm)?;
+    let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "routing_algorithm",
+        })
+        .attach_printable("Invalid routing algorithm given")?;
+
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(_) => (),
+        api::RoutingAlgorithm::Priority(ref connectors) => {
+            validate_routing_connector_list(connectors)?;
+        }
+        api::RoutingAlgorithm::VolumeSplit(ref splits) => {
+            let connectors: Vec<_> = splits.iter().map(|split| split.connector).collect();
+            validate_routing_connector_list(&connectors)?;
+            utils::when(splits.iter().any(|split| split.split == 0), || {
+                invalid_routing_algorithm("the split of each connector must be greater than 0")
+            })?;
+            let total_split: u32 = splits.iter().map(|split| u32::from(split.split)).sum();
+            utils::when(total_split != 100, || {
+                invalid_routing_algorithm("the splits of the connectors must add up to 100")
+            })?;
+        }
+        api::RoutingAlgorithm::RuleBased(ref routing_rules) => {
+            validate_routing_connector_list(&routing_rules.default_connectors)?;
+            let rule_names: HashSet<_> =
+                routing_rules.rules.iter().map(|rule| &rule.name).collect();
+            utils::when(rule_names.len() != routing_rules.rules.len(), || {
+                invalid_routing_algorithm("a rule name must not be repeated")
+            })?;
+            for rule in routing_rules.rules.iter() {
+                validate_routing_rule(rule)?;
+            }
+        }
+    }
+
+    Ok(routing_algorithm)
+}
+
+fn validate_routing_rule(rule: &api::RoutingRule) -> RouterResult<()> {
+    validate_routing_connector_list(&rule.connectors)?;
+    utils::when(rule.conditions.is_empty(), || {
+        invalid_routing_algorithm(&format!(
+            "rule {} must have at least one condition",
+            rule.name
+        ))
+    })?;
+    for condition in rule.conditions.iter() {
+        match condition {
+            api::RoutingCondition::Amount {
+                min: Some(min),
+                max: Some(max),
+            } if min > max => invalid_routing_algorithm(&format!(
+                "the amount range of rule {} must have min less than or equal to max",
+                rule.name
+            ))?,
+            api::RoutingCondition::CardBin { values }
+                if values.iter().any(|bin| {
+                    bin.is_empty()
+                        || bin.len() > 8
+                        || !bin.chars().all(|digit| digit.is_ascii_digit())
+                }) =>
+            {
+                invalid_routing_algorithm(&format!(
+                    "the card BINs of rule {} must consist of 1 to 8 digits",
+                    rule.name
+                ))?
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+fn validate_routing_connector_list(
+    connectors: &[api_enums::RoutableConnectors],
+) -> RouterResult<()> {
+    let unique_connectors: HashSet<_> = connectors.iter().collect();
+    utils::when(connectors.is_empty(), || {
+        invalid_routing_algorithm("at least one connector must be provided")
+    })?;
+    utils::when(unique_connectors.len() != connectors.len(), || {
+        invalid_routing_algorithm("a connector must not be repeated")
+    })
+}
+
+/// The round robin, max conversion and min cost routing algorithms are not implemented yet, reject
+/// them explicitly rather than as an unknown routing algorithm
+fn validate_routing_algorithm_is_supported(
+    routing_algorithm: &serde_json::Value,
+) -> RouterResult<()> {
+    let algorithm = routing_algorithm
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|algorithm| algorithm.parse::<api_enums::RoutingAlgorithm>().ok());
+    match algorithm {
+        Some(
+            algorithm @ (api_enums::RoutingAlgorithm::RoundRobin
+            | api_enums::RoutingAlgorithm::MaxConversion
+            | api_enums::RoutingAlgorithm::MinCost),
+        ) => invalid_routing_algorithm(&format!(
+            "the {algorithm} routing algorithm is not supported, use one of single, priority, \
+             volume_split or rule_based"
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn invalid_routing_algorithm(message: &str) -> RouterResult<()> {
+    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid routing algorithm: {message}"),
+    }))
+}
+
+/// Outgoing webhooks signed with a short secret could have their signature forged
+fn validate_webhook_secret(webhook_details: &api::WebhookDetails) -> RouterResult<()> {
+    let is_too_short = webhook_details
+        .webhook_secret
+        .as_ref()
+        .map_or(false, |secret| {
+            secret.peek().len() < consts::MIN_WEBHOOK_SECRET_LENGTH
+        });
+    utils::when(is_too_short, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "webhook_secret must be at least {} characters long",
+                consts::MIN_WEBHOOK_SECRET_LENGTH
+            ),
+        }))
+    })
+}
+
+/// Ensure that every connector used by the routing algorithm is enabled for the merchant
+async fn validate_routing_connectors_enabled(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    routing_algorithm: &api::RoutingAlgorithm,
+) -> RouterResult<()> {
+    let enabled_connectors: HashSet<String> = db
+        .find_merchant_connector_account_by_merchant_id_list(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?
+        .into_iter()
+        .filter(|mca| mca.disabled != Some(true))
+        .map(|mca| mca.connector_name)
+        .collect();
+
+    match get_routing_connectors(routing_algorithm)
+        .into_iter()
+        .find(|connector| !enabled_connectors.contains(&connector.to_string()))
+    {
+        Some(connector) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Invalid routing algorithm: connector {connector} is not enabled for the merchant"
+            ),
+        })),
+        None => Ok(()),
+    }
+}
+
+pub fn get_routing_connectors(
+    routing_algorithm: &api::RoutingAlgorithm,
+) -> Vec<api_enums::RoutableConnectors> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(connector) => vec![*connector],
+        api::RoutingAlgorithm::Priority(connectors) => connectors.clone(),
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            splits.iter().map(|split| split.connector).collect()
+        }
+        api::RoutingAlgorithm::RuleBased(routing_rules) => {
+            let mut connectors = routing_rules.default_connectors.clone();
+            for connector in routing_rules
+                .rules
+                .iter()
+                .flat_map(|rule| rule.connectors.iter())
+            {
+                if !connectors.contains(connector) {
+                    connectors.push(*connector);
+                }
+            }
+            connectors
+        }
+    }
+}
+
 pub async fn get_merchant_account(
     db: &dyn StorageInterface,
     req: api::MerchantId,
@@ -127,13 +320,12 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        let routing_algorithm = validate_routing_algorithm(routing_algorithm)?;
+        validate_routing_connectors_enabled(db, merchant_id, &routing_algorithm).await?;
+    }
+
+    if let Some(ref webhook_details) = req.webhook_details {
+        validate_webhook_secret(webhook_details)?;
     }
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
@@ -541,3 +733,84 @@ pub async fn check_merchant_account_kv_status(
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_validate_webhook_secret_length() {
+        let webhook_details = |webhook_secret: Option<&str>| api::WebhookDetails {
+            webhook_version: None,
+            webhook_username: None,
+            webhook_password: None,
+            webhook_url: None,
+            webhook_secret: webhook_secret.map(|secret| Secret::new(secret.to_string())),
+            payment_created_enabled: None,
+            payment_succeeded_enabled: None,
+            payment_failed_enabled: None,
+        };
+        assert!(validate_webhook_secret(&webhook_details(None)).is_ok());
+        assert!(validate_webhook_secret(&webhook_details(Some("whsec_short"))).is_err());
+        assert!(validate_webhook_secret(&webhook_details(Some(
+            "whsec_xfN3bKx8L2gZpTq7Yc4RmW9vJe6HsA1d"
+        )))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_volume_split_routing_algorithm() {
+        let routing_algorithm = serde_json::json!({
+            "type": "volume_split",
+            "data": [
+                {"connector": "stripe", "split": 70},
+                {"connector": "adyen", "split": 30}
+            ]
+        });
+        assert!(validate_routing_algorithm(&routing_algorithm).is_ok());
+
+        let routing_algorithm = serde_json::json!({
+            "type": "volume_split",
+            "data": [
+                {"connector": "stripe", "split": 70},
+                {"connector": "adyen", "split": 20}
+            ]
+        });
+        assert!(validate_routing_algorithm(&routing_algorithm).is_err());
+    }
+
+    #[test]
+    fn test_validate_priority_routing_algorithm() {
+        let routing_algorithm = serde_json::json!({
+            "type": "priority",
+            "data": ["checkout", "stripe"]
+        });
+        assert!(validate_routing_algorithm(&routing_algorithm).is_ok());
+
+        let routing_algorithm = serde_json::json!({
+            "type": "priority",
+            "data": ["stripe", "stripe"]
+        });
+        assert!(validate_routing_algorithm(&routing_algorithm).is_err());
+
+        let routing_algorithm = serde_json::json!({ "type": "priority", "data": [] });
+        assert!(validate_routing_algorithm(&routing_algorithm).is_err());
+    }
+
+    #[test]
+    fn test_validate_unsupported_routing_algorithm() {
+        for algorithm in ["round_robin", "max_conversion", "min_cost"] {
+            let routing_algorithm = serde_json::json!({
+                "type": algorithm,
+                "data": ["stripe", "adyen"]
+            });
+            let error = validate_routing_algorithm(&routing_algorithm).unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { message }
+                    if message.contains(&format!("the {algorithm} routing algorithm is not supported"))
+            ));
+        }
+    }
+}
@@ -1,17 +1,27 @@
 use common_utils::{date_time, errors::CustomResult, fp_utils};
 use error_stack::{report, IntoReport, ResultExt};
 use masking::{PeekInterface, Secret};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing, Flow};
+use time::PrimitiveDateTime;
 
 use crate::{
+    configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    db::StorageInterface,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::{api_keys::CachedApiKey, StorageInterface},
     services::ApplicationResponse,
-    types::{api, storage, transformers::ForeignInto},
+    types::{
+        api::{self, enums as api_enums},
+        storage,
+        transformers::ForeignInto,
+    },
     utils,
 };
 
+const LEGACY_API_KEY_NAME: &str = "Legacy API key";
+/// Time in seconds for which the migration of a legacy API key is locked
+const LEGACY_API_KEY_MIGRATION_LOCK_TTL: i64 = 30;
+
 // Defining new types `PlaintextApiKey` and `HashedApiKey` in the hopes of reducing the possibility
 // of plaintext API key being stored in the data store.
 pub struct PlaintextApiKey(Secret<String>);
@@ -44,6 +54,22 @@ impl PlaintextApiKey {
         self.0.peek()
     }
 
+    /// Unkeyed hash of the API key, by which verified API keys are cached without the plaintext
+    /// API key being stored in the cache
+    pub fn digest(&self) -> String {
+        blake3::hash(self.0.peek().as_bytes()).to_hex().to_string()
+    }
+
+    pub fn decode_hash_key(
+        hash_key: &Secret<String>,
+    ) -> CustomResult<[u8; Self::HASH_KEY_LEN], errors::ApiKeyError> {
+        hex::decode(hash_key.peek())
+            .into_report()
+            .change_context(errors::ApiKeyError::FailedToReadHashKeyFromHex)?
+            .try_into()
+            .map_err(|_| report!(errors::ApiKeyError::FailedToReadHashKeyFromHex))
+    }
+
     pub fn keyed_hash(&self, key: &[u8; Self::HASH_KEY_LEN]) -> HashedApiKey {
         /*
         Decisions regarding API key hashing algorithm chosen:
@@ -99,6 +125,7 @@ pub async fn create_api_key(
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
+    validate_scopes(api_key.scopes.as_deref())?;
     let hash_key = PlaintextApiKey::new_hash_key();
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -112,6 +139,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key.scopes.as_deref().map(format_scopes),
     };
 
     let api_key = store
@@ -196,6 +224,396 @@ impl From<HashedApiKey> for storage::HashedApiKey {
     }
 }
 
+impl From<&str> for PlaintextApiKey {
+    fn from(api_key: &str) -> Self {
+        Self(api_key.to_owned().into())
+    }
+}
+
+fn validate_scopes(scopes: Option<&[api_enums::ApiKeyScope]>) -> RouterResult<()> {
+    fp_utils::when(scopes.map_or(false, <[_]>::is_empty), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scopes must not be empty".to_string(),
+        }))
+    })
+}
+
+fn format_scopes(scopes: &[api_enums::ApiKeyScope]) -> Vec<String> {
+    scopes.iter().map(ToString::to_string).collect()
+}
+
+/// Parse the scopes stored with an API key, ignoring unknown scopes so that they grant no access
+pub fn parse_scopes(scopes: Vec<String>) -> Vec<api_enums::ApiKeyScope> {
+    scopes
+        .iter()
+        .filter_map(|scope| scope.parse().ok())
+        .collect()
+}
+
+/// The scopes of which an API key must hold at least one to perform the flow. The flows which are
+/// not authenticated by merchant API keys need no scope, and cannot be performed with scoped API
+/// keys.
+fn get_permitted_scopes(flow: &Flow) -> &'static [api_enums::ApiKeyScope] {
+    use api_enums::ApiKeyScope as Scope;
+
+    match flow {
+        Flow::PaymentsCreate
+        | Flow::PaymentsUpdate
+        | Flow::PaymentsConfirm
+        | Flow::PaymentsCapture
+        | Flow::PaymentsCancel
+        | Flow::PaymentsIncrementalAuthorization
+        | Flow::PaymentsPreAuthenticate
+        | Flow::PaymentsAuthenticate
+        | Flow::PaymentsSessionToken => &[Scope::PaymentsWrite],
+        Flow::PaymentsRetrieve | Flow::RoutingDryRun => {
+            &[Scope::PaymentsRead, Scope::PaymentsWrite]
+        }
+        Flow::PaymentsList => &[
+            Scope::PaymentsRead,
+            Scope::PaymentsWrite,
+            Scope::ReportingRead,
+        ],
+        Flow::RefundsCreate | Flow::RefundsUpdate => &[Scope::RefundsWrite],
+        Flow::RefundsRetrieve => &[Scope::RefundsRead, Scope::RefundsWrite],
+        Flow::RefundsList => &[
+            Scope::RefundsRead,
+            Scope::RefundsWrite,
+            Scope::ReportingRead,
+        ],
+        Flow::CustomersCreate
+        | Flow::CustomersUpdate
+        | Flow::CustomersDelete
+        | Flow::EphemeralKeyCreate
+        | Flow::EphemeralKeyDelete => &[Scope::CustomersWrite],
+        Flow::CustomersRetrieve | Flow::CustomersGetMandates => {
+            &[Scope::CustomersRead, Scope::CustomersWrite]
+        }
+        Flow::PaymentMethodsCreate | Flow::PaymentMethodsUpdate | Flow::PaymentMethodsDelete => {
+            &[Scope::PaymentMethodsWrite]
+        }
+        Flow::PaymentMethodsList
+        | Flow::CustomerPaymentMethodsList
+        | Flow::PaymentMethodsRetrieve => &[Scope::PaymentMethodsRead, Scope::PaymentMethodsWrite],
+        Flow::MandatesRevoke => &[Scope::MandatesWrite],
+        Flow::MandatesRetrieve => &[Scope::MandatesRead, Scope::MandatesWrite],
+        Flow::DisputesAccept | Flow::DisputesEvidenceSubmit => &[Scope::DisputesWrite],
+        Flow::DisputesRetrieve => &[Scope::DisputesRead, Scope::DisputesWrite],
+        Flow::DisputesList => &[
+            Scope::DisputesRead,
+            Scope::DisputesWrite,
+            Scope::ReportingRead,
+        ],
+        Flow::PayoutsCreate | Flow::PayoutsUpdate | Flow::PayoutsCancel | Flow::PayoutsReverse => {
+            &[Scope::PayoutsWrite]
+        }
+        Flow::PayoutsRetrieve | Flow::PayoutsAccounts => &[Scope::PayoutsRead, Scope::PayoutsWrite],
+        Flow::PayoutsList => &[
+            Scope::PayoutsRead,
+            Scope::PayoutsWrite,
+            Scope::ReportingRead,
+        ],
+        Flow::SubscriptionsCreate
+        | Flow::SubscriptionsPause
+        | Flow::SubscriptionsResume
+        | Flow::SubscriptionsCancel => &[Scope::SubscriptionsWrite],
+        Flow::SubscriptionsRetrieve => &[Scope::SubscriptionsRead, Scope::SubscriptionsWrite],
+        Flow::CreateFile | Flow::DeleteFile => &[Scope::FilesWrite],
+        // Exports are downloaded as files
+        Flow::RetrieveFile => &[Scope::FilesRead, Scope::FilesWrite, Scope::ReportingRead],
+        Flow::ExportCreate | Flow::ExportRetrieve => &[Scope::ReportingRead],
+        Flow::MerchantsAccountCreate
+        | Flow::MerchantsAccountRetrieve
+        | Flow::MerchantsAccountUpdate
+        | Flow::MerchantsAccountDelete
+        | Flow::PaymentConnectorsCreate
+        | Flow::PaymentConnectorsRetrieve
+        | Flow::PaymentConnectorsUpdate
+        | Flow::PaymentConnectorsDelete
+        | Flow::PaymentConnectorsList
+        | Flow::ConfigKeyCreate
+        | Flow::ConfigKeyFetch
+        | Flow::ConfigKeyUpdate
+        | Flow::ApiKeyCreate
+        | Flow::ApiKeyRetrieve
+        | Flow::ApiKeyUpdate
+        | Flow::ApiKeyRevoke
+        | Flow::ApiKeyList
+        | Flow::ConnectorHealthRetrieve
+        | Flow::ConnectorHealthOverride
+        | Flow::ConnectorHealthOverrideDelete
+        | Flow::PaymentsStart
+        | Flow::IncomingWebhookReceive
+        | Flow::ValidatePaymentMethod => &[],
+    }
+}
+
+/// Whether an API key with the scopes can perform the flow. API keys without scopes can perform
+/// all flows.
+pub fn is_flow_permitted(scopes: Option<&[api_enums::ApiKeyScope]>, flow: &Flow) -> bool {
+    scopes.map_or(true, |scopes| {
+        get_permitted_scopes(flow)
+            .iter()
+            .any(|scope| scopes.contains(scope))
+    })
+}
+
+fn is_api_key_expired(expires_at: Option<PrimitiveDateTime>, now: PrimitiveDateTime) -> bool {
+    expires_at.map_or(false, |expires_at| expires_at <= now)
+}
+
+/// Number of seconds for which the verified API key is cached, so that the API key is not cached
+/// past its expiry
+fn get_api_key_cache_ttl(
+    expires_at: Option<PrimitiveDateTime>,
+    now: PrimitiveDateTime,
+    cache_ttl_secs: i64,
+) -> i64 {
+    expires_at.map_or(cache_ttl_secs, |expires_at| {
+        (expires_at - now).whole_seconds().clamp(1, cache_ttl_secs)
+    })
+}
+
+fn verify_stored_api_key(
+    plaintext_api_key: &PlaintextApiKey,
+    api_key: &storage::ApiKey,
+) -> CustomResult<(), errors::ApiKeyError> {
+    let hash_key = PlaintextApiKey::decode_hash_key(&api_key.hash_key)?;
+    let hashed_api_key = HashedApiKey(api_key.hashed_api_key.peek().to_owned());
+    plaintext_api_key.verify_hash(&hash_key, &hashed_api_key)
+}
+
+/// Update the last used time of the API key, unless it was updated within the configured interval,
+/// so that the API keys authenticating many requests do not update it on each request
+async fn update_api_key_last_used(
+    store: &dyn StorageInterface,
+    settings: &settings::ApiKeysSettings,
+    key_id: &str,
+    now: PrimitiveDateTime,
+) {
+    let should_update = store
+        .acquire_api_key_last_used_update_lock(key_id, settings.last_used_update_interval_secs)
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(api_key_last_used_update_lock_error=?error);
+            true
+        });
+    if !should_update {
+        return;
+    }
+
+    if let Err(error) = store
+        .update_api_key(
+            key_id.to_owned(),
+            storage::ApiKeyUpdate::LastUsedUpdate { last_used: now },
+        )
+        .await
+    {
+        logger::error!(api_key_last_used_update_error=?error);
+    }
+}
+
+/// Verify the API key against the hashed API keys sharing its prefix, returning the verified API
+/// key, or `None` if the API key is not a hashed API key.
+/// Verified API keys are cached, and their last used time is updated whenever they are used, at
+/// most once in the configured interval.
+#[instrument(skip_all)]
+pub async fn verify_api_key(
+    store: &dyn StorageInterface,
+    settings: &settings::ApiKeysSettings,
+    api_key: &str,
+) -> RouterResult<Option<CachedApiKey>> {
+    let plaintext_api_key = PlaintextApiKey::from(api_key);
+    let digest = plaintext_api_key.digest();
+    let now = date_time::now();
+
+    let cached_api_key = store
+        .find_cached_api_key(&digest)
+        .await
+        .map_err(|error| logger::error!(api_key_cache_error=?error))
+        .ok()
+        .flatten();
+    if let Some(api_key) = cached_api_key {
+        fp_utils::when(is_api_key_expired(api_key.expires_at, now), || {
+            Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("API key has expired")
+        })?;
+        update_api_key_last_used(store, settings, &api_key.key_id, now).await;
+        return Ok(Some(api_key));
+    }
+
+    let api_key = store
+        .find_api_keys_by_prefix(&plaintext_api_key.prefix())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find API keys by prefix")?
+        .into_iter()
+        .find(|api_key| verify_stored_api_key(&plaintext_api_key, api_key).is_ok());
+    let api_key = match api_key {
+        Some(api_key) => api_key,
+        None => return Ok(None),
+    };
+    fp_utils::when(is_api_key_expired(api_key.expires_at, now), || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized)).attach_printable("API key has expired")
+    })?;
+
+    update_api_key_last_used(store, settings, &api_key.key_id, now).await;
+
+    let cached_api_key = get_cached_api_key(api_key);
+    let ttl = get_api_key_cache_ttl(cached_api_key.expires_at, now, settings.cache_ttl_secs);
+    if let Err(error) = store.cache_api_key(&digest, &cached_api_key, ttl).await {
+        logger::error!(api_key_cache_error=?error);
+    }
+
+    // The API key could have been revoked or updated while it was being verified, the cache then
+    // being written after the revocation or update redacted it. The API key is read again once
+    // cached, and the cache is redacted if the API key has since changed.
+    let current_api_key = store
+        .find_api_key_optional(&cached_api_key.key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find API key")?
+        .map(get_cached_api_key);
+    if !is_cached_api_key_current(&cached_api_key, current_api_key.as_ref()) {
+        if let Err(error) = store.redact_cached_api_key(&cached_api_key.key_id).await {
+            logger::error!(api_key_cache_redaction_error=?error);
+        }
+    }
+
+    let api_key = current_api_key
+        .ok_or(errors::ApiErrorResponse::Unauthorized)
+        .into_report()
+        .attach_printable("API key has been revoked")?;
+    fp_utils::when(is_api_key_expired(api_key.expires_at, now), || {
+        Err(report!(errors::ApiErrorResponse::Unauthorized)).attach_printable("API key has expired")
+    })?;
+    Ok(Some(api_key))
+}
+
+/// API key of the request resolved to the merchant, which is put in the extensions of the request
+/// once resolved by the rate limiter, so that the API key is not verified again on authentication
+#[derive(Clone, Debug)]
+pub enum VerifiedApiKey {
+    Hashed(CachedApiKey),
+    /// Legacy API key, stored in plaintext in the merchant account
+    Legacy(storage::MerchantAccount),
+}
+
+/// Resolve the API key to the merchant, by the hashed API keys, or by the legacy API keys stored in
+/// the merchant accounts while they are enabled
+#[instrument(skip_all)]
+pub async fn resolve_api_key(
+    store: &dyn StorageInterface,
+    settings: &settings::ApiKeysSettings,
+    api_key: &str,
+) -> RouterResult<VerifiedApiKey> {
+    match verify_api_key(store, settings, api_key).await? {
+        Some(api_key) => Ok(VerifiedApiKey::Hashed(api_key)),
+        None if settings.legacy_merchant_keys_enabled => store
+            .find_merchant_account_by_api_key(api_key)
+            .await
+            .map(VerifiedApiKey::Legacy)
+            .change_context(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("Merchant not authenticated"),
+        None => Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key not found"),
+    }
+}
+
+fn get_cached_api_key(api_key: storage::ApiKey) -> CachedApiKey {
+    CachedApiKey {
+        key_id: api_key.key_id,
+        merchant_id: api_key.merchant_id,
+        expires_at: api_key.expires_at,
+        scopes: api_key.scopes.map(parse_scopes),
+    }
+}
+
+/// Whether the cached API key is the API key as currently stored, that is the API key has been
+/// neither revoked nor updated since it was cached
+fn is_cached_api_key_current(
+    cached_api_key: &CachedApiKey,
+    current_api_key: Option<&CachedApiKey>,
+) -> bool {
+    current_api_key.map_or(false, |current_api_key| {
+        current_api_key.merchant_id == cached_api_key.merchant_id
+            && current_api_key.expires_at == cached_api_key.expires_at
+            && current_api_key.scopes == cached_api_key.scopes
+    })
+}
+
+/// Migrate the legacy API key of the merchant, which is stored in plaintext in the merchant
+/// account, to a hashed API key, by which the merchant is authenticated from then on. The legacy
+/// API key is cleared from the merchant account once migrated, so that the API key cannot be
+/// authenticated as a legacy API key, and migrated again, once the hashed API key is revoked.
+#[instrument(skip_all)]
+pub async fn migrate_legacy_api_key(
+    store: &dyn StorageInterface,
+    merchant_id: &str,
+    api_key: &str,
+) -> RouterResult<()> {
+    // Concurrent first requests of the merchant would each insert a hashed API key
+    let is_lock_acquired = store
+        .acquire_legacy_api_key_migration_lock(merchant_id, LEGACY_API_KEY_MIGRATION_LOCK_TTL)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the legacy API key migration lock")?;
+    if !is_lock_acquired {
+        return Ok(());
+    }
+
+    let plaintext_api_key = PlaintextApiKey::from(api_key);
+    // The legacy API key may have been migrated by a request which held the lock earlier
+    let is_migrated = store
+        .find_api_keys_by_prefix(&plaintext_api_key.prefix())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find API keys by prefix")?
+        .iter()
+        .any(|api_key| verify_stored_api_key(&plaintext_api_key, api_key).is_ok());
+    if is_migrated {
+        // The legacy API key may not have been cleared if the migration failed after the hashed
+        // API key was inserted
+        return clear_legacy_api_key(store, merchant_id).await;
+    }
+
+    let hash_key = PlaintextApiKey::new_hash_key();
+    let now = date_time::now();
+    let api_key = storage::ApiKeyNew {
+        key_id: PlaintextApiKey::new_key_id(),
+        merchant_id: merchant_id.to_owned(),
+        name: LEGACY_API_KEY_NAME.to_owned(),
+        description: Some("Migrated from the API key of the merchant account".to_owned()),
+        hash_key: Secret::from(hex::encode(hash_key)),
+        hashed_api_key: plaintext_api_key.keyed_hash(&hash_key).into(),
+        prefix: plaintext_api_key.prefix(),
+        created_at: now,
+        expires_at: None,
+        last_used: Some(now),
+        scopes: None,
+    };
+
+    store
+        .insert_api_key(api_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to migrate legacy API key")?;
+    logger::info!(merchant_id, "Legacy API key migrated to a hashed API key");
+    clear_legacy_api_key(store, merchant_id).await
+}
+
+async fn clear_legacy_api_key(store: &dyn StorageInterface, merchant_id: &str) -> RouterResult<()> {
+    store
+        .update_specific_fields_in_merchant(
+            merchant_id,
+            storage::MerchantAccountUpdate::LegacyApiKeyClear,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to clear the migrated legacy API key")?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -216,4 +634,95 @@ mod tests {
             .verify_hash(&hash_key, &hashed_api_key)
             .unwrap();
     }
+
+    #[test]
+    fn test_hash_key_decoding() {
+        let hash_key = PlaintextApiKey::new_hash_key();
+        let decoded_hash_key =
+            PlaintextApiKey::decode_hash_key(&Secret::from(hex::encode(hash_key))).unwrap();
+        assert_eq!(hash_key, decoded_hash_key);
+
+        assert!(PlaintextApiKey::decode_hash_key(&Secret::from("abcd".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_cached_api_key_current() {
+        let cached_api_key = CachedApiKey {
+            key_id: "key_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            expires_at: None,
+            scopes: Some(vec![api_enums::ApiKeyScope::PaymentsRead]),
+        };
+        assert!(is_cached_api_key_current(
+            &cached_api_key,
+            Some(&cached_api_key)
+        ));
+        // Revoked while being verified
+        assert!(!is_cached_api_key_current(&cached_api_key, None));
+        // Updated while being verified
+        let updated_api_key = CachedApiKey {
+            expires_at: Some(date_time::now()),
+            ..cached_api_key.clone()
+        };
+        assert!(!is_cached_api_key_current(
+            &cached_api_key,
+            Some(&updated_api_key)
+        ));
+    }
+
+    #[test]
+    fn test_api_key_cache_ttl() {
+        let now = date_time::now();
+        assert_eq!(get_api_key_cache_ttl(None, now, 300), 300);
+        assert_eq!(
+            get_api_key_cache_ttl(Some(now + time::Duration::seconds(60)), now, 300),
+            60
+        );
+        assert_eq!(
+            get_api_key_cache_ttl(Some(now + time::Duration::hours(1)), now, 300),
+            300
+        );
+        assert!(is_api_key_expired(Some(now), now));
+        assert!(!is_api_key_expired(None, now));
+    }
+
+    #[test]
+    fn test_flow_permitted_by_scopes() {
+        use api_enums::ApiKeyScope as Scope;
+
+        assert!(is_flow_permitted(None, &Flow::PaymentsCreate));
+
+        let reporting = [Scope::PaymentsRead, Scope::ReportingRead];
+        assert!(is_flow_permitted(Some(&reporting), &Flow::PaymentsList));
+        assert!(is_flow_permitted(Some(&reporting), &Flow::ExportCreate));
+        assert!(!is_flow_permitted(Some(&reporting), &Flow::PaymentsCreate));
+        assert!(!is_flow_permitted(Some(&reporting), &Flow::RefundsCreate));
+
+        let refunds = [Scope::RefundsWrite];
+        assert!(is_flow_permitted(Some(&refunds), &Flow::RefundsRetrieve));
+        assert!(!is_flow_permitted(Some(&refunds), &Flow::PaymentsCapture));
+        assert!(!is_flow_permitted(
+            Some(&refunds),
+            &Flow::MerchantsAccountRetrieve
+        ));
+    }
+
+    #[test]
+    fn test_scopes_parsing() {
+        let scopes = format_scopes(&[
+            api_enums::ApiKeyScope::PaymentsWrite,
+            api_enums::ApiKeyScope::ReportingRead,
+        ]);
+        assert_eq!(scopes, vec!["payments:write", "reporting:read"]);
+
+        let mut stored_scopes = scopes;
+        stored_scopes.push("unknown:write".to_string());
+        assert_eq!(
+            parse_scopes(stored_scopes),
+            vec![
+                api_enums::ApiKeyScope::PaymentsWrite,
+                api_enums::ApiKeyScope::ReportingRead
+            ]
+        );
+    }
 }
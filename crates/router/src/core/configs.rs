@@ -5,6 +5,17 @@ use crate::{
     types::{api, transformers::ForeignInto},
 };
 
+pub async fn set_config(
+    store: &dyn StorageInterface,
+    config: api::Config,
+) -> RouterResponse<api::Config> {
+    let config = store
+        .insert_config(config.foreign_into())
+        .await
+        .map_err(|err| err.to_duplicate_response(errors::ApiErrorResponse::DuplicateConfig))?;
+    Ok(ApplicationResponse::Json(config.foreign_into()))
+}
+
 pub async fn read_config(store: &dyn StorageInterface, key: &str) -> RouterResponse<api::Config> {
     let config = store
         .find_config_by_key_cached(key)
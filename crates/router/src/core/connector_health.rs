@@ -0,0 +1,213 @@
+use std::{str::FromStr, time::Duration};
+
+use error_stack::{IntoReport, ResultExt};
+use futures::future::join_all;
+use router_env::{instrument, opentelemetry, tracing};
+
+use super::errors::{self, RouterResponse, RouterResult};
+use crate::{
+    db::connector_health::ConnectorHealth,
+    logger,
+    routes::{metrics, AppState},
+    services,
+    types::api::{admin, enums as api_enums},
+};
+
+/// Record the outcome of a call to the connector, opening the circuit breaker of the connector if
+/// it is failing. Errors are only logged, so that the call itself is not failed by the tracking.
+pub async fn record_connector_call(
+    state: &AppState,
+    connector: &str,
+    succeeded: bool,
+    latency: Duration,
+) {
+    let settings = &state.conf.connector_health;
+    if !settings.enabled {
+        return;
+    }
+    let latency_ms = u64::try_from(latency.as_millis()).unwrap_or(u64::MAX);
+    match state
+        .store
+        .record_connector_call(connector, succeeded, latency_ms, settings)
+        .await
+    {
+        Ok(true) => {
+            logger::warn!(connector, "Circuit breaker of the connector opened");
+            metrics::CONNECTOR_CIRCUIT_OPENED.add(
+                &metrics::CONTEXT,
+                1,
+                &[opentelemetry::KeyValue::new(
+                    "connector",
+                    connector.to_string(),
+                )],
+            );
+        }
+        Ok(false) => {}
+        Err(error) => logger::error!(connector_health_error=?error),
+    }
+}
+
+/// Order the connectors so that the healthy connectors come first, otherwise keeping the order of
+/// priority, so that payments are routed to unhealthy connectors only when none of the connectors
+/// is healthy
+pub async fn prioritize_healthy_connectors(
+    state: &AppState,
+    connectors: Vec<String>,
+) -> Vec<String> {
+    if !state.conf.connector_health.enabled {
+        return connectors;
+    }
+    let health = join_all(
+        connectors
+            .iter()
+            .map(|connector| is_connector_healthy(state, connector)),
+    )
+    .await;
+    let (healthy, unhealthy): (Vec<_>, Vec<_>) = connectors
+        .into_iter()
+        .zip(health)
+        .partition(|(_, healthy)| *healthy);
+    healthy
+        .into_iter()
+        .chain(unhealthy)
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+async fn is_connector_healthy(state: &AppState, connector: &str) -> bool {
+    match state
+        .store
+        .get_connector_health(connector, &state.conf.connector_health)
+        .await
+    {
+        Ok(health) => get_health_status(&health) == api_enums::ConnectorHealthStatus::Healthy,
+        // Payments are routed as usual when the health of the connector is unknown
+        Err(error) => {
+            logger::error!(connector_health_error=?error);
+            true
+        }
+    }
+}
+
+fn get_health_status(health: &ConnectorHealth) -> api_enums::ConnectorHealthStatus {
+    match health.health_override {
+        Some(status) => status,
+        None if health.circuit_open => api_enums::ConnectorHealthStatus::Unhealthy,
+        None => api_enums::ConnectorHealthStatus::Healthy,
+    }
+}
+
+fn validate_connector(connector: &str) -> RouterResult<()> {
+    api_enums::Connector::from_str(connector)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        })?;
+    Ok(())
+}
+
+async fn get_connector_health_response(
+    state: &AppState,
+    connector: String,
+) -> RouterResult<admin::ConnectorHealthResponse> {
+    let settings = &state.conf.connector_health;
+    let health = state
+        .store
+        .get_connector_health(&connector, settings)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let stats = health.stats;
+
+    Ok(admin::ConnectorHealthResponse {
+        connector,
+        status: get_health_status(&health),
+        circuit_open: health.circuit_open,
+        health_override: health.health_override,
+        window_secs: settings.window_secs,
+        calls: stats.calls,
+        failed_calls: stats.failed_calls,
+        #[allow(clippy::as_conversions)]
+        success_rate: (stats.calls > 0)
+            .then(|| (stats.calls - stats.failed_calls) as f64 / stats.calls as f64),
+        average_latency_ms: (stats.calls > 0).then(|| stats.total_latency_ms / stats.calls),
+    })
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_connector_health(
+    state: &AppState,
+    connector: String,
+) -> RouterResponse<admin::ConnectorHealthResponse> {
+    validate_connector(&connector)?;
+    let response = get_connector_health_response(state, connector).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state))]
+pub async fn override_connector_health(
+    state: &AppState,
+    connector: String,
+    req: admin::ConnectorHealthOverrideRequest,
+) -> RouterResponse<admin::ConnectorHealthResponse> {
+    validate_connector(&connector)?;
+    let ttl = req
+        .ttl_secs
+        .unwrap_or(state.conf.connector_health.override_ttl_secs);
+    if ttl <= 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "ttl_secs must be positive".to_string(),
+        })?
+    }
+
+    state
+        .store
+        .set_connector_health_override(&connector, req.status, ttl)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    logger::info!(%connector, status = %req.status, ttl, "Health of the connector overridden");
+
+    let response = get_connector_health_response(state, connector).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_connector_health_override(
+    state: &AppState,
+    connector: String,
+) -> RouterResponse<admin::ConnectorHealthResponse> {
+    validate_connector(&connector)?;
+    state
+        .store
+        .delete_connector_health_override(&connector)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let response = get_connector_health_response(state, connector).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_status() {
+        let mut health = ConnectorHealth::default();
+        assert_eq!(
+            get_health_status(&health),
+            api_enums::ConnectorHealthStatus::Healthy
+        );
+
+        health.circuit_open = true;
+        assert_eq!(
+            get_health_status(&health),
+            api_enums::ConnectorHealthStatus::Unhealthy
+        );
+
+        health.health_override = Some(api_enums::ConnectorHealthStatus::Healthy);
+        assert_eq!(
+            get_health_status(&health),
+            api_enums::ConnectorHealthStatus::Healthy
+        );
+    }
+}
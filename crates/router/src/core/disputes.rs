@@ -0,0 +1,282 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt};
+use crate::{
+    core::{files, payments, utils as core_utils},
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+#[instrument(skip_all)]
+pub async fn retrieve_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<api::DisputeResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })
+        })?;
+    let dispute_response = dispute.foreign_into();
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn retrieve_disputes_list(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::DisputeListConstraints,
+) -> RouterResponse<Vec<api::DisputeResponse>> {
+    let disputes = state
+        .store
+        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes")?;
+    let disputes_list = disputes
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+    Ok(services::ApplicationResponse::Json(disputes_list))
+}
+
+#[instrument(skip_all)]
+pub async fn accept_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<api::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                dispute_id: dispute_id.clone(),
+            })
+        })?;
+    // Only disputes which are yet to be responded to can be accepted
+    utils::when(
+        dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened,
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                    reason: format!(
+                        "This dispute cannot be accepted because it has status {}",
+                        dispute.dispute_status
+                    ),
+                }
+            ))
+        },
+    )?;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_accept_dispute_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        &dispute,
+    )
+    .await?;
+    logger::debug!(accept_dispute_router_data=?router_data);
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())
+    .attach_printable("Failed while calling accept dispute connector api")?;
+    let accept_dispute_response = response.response.map_err(|err| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: dispute.connector.clone(),
+            status_code: err.status_code,
+        })
+    })?;
+    let update_dispute = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: accept_dispute_response.dispute_status,
+        connector_status: accept_dispute_response.connector_status,
+    };
+    let updated_dispute = db
+        .update_dispute(dispute, update_dispute)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+    let dispute_response = updated_dispute.foreign_into();
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
+
+#[instrument(skip_all)]
+pub async fn submit_evidence(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::SubmitEvidenceRequest,
+) -> RouterResponse<api::DisputeResponse> {
+    let db = &*state.store;
+    let dispute_id = req.dispute_id.clone();
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                dispute_id: dispute_id.clone(),
+            })
+        })?;
+    // Evidence can only be submitted for disputes which are yet to be responded to
+    utils::when(
+        dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened,
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                    reason: format!(
+                        "Evidence cannot be submitted because the dispute has status {}",
+                        dispute.dispute_status
+                    ),
+                }
+            ))
+        },
+    )?;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let upload_file_to_connector = |file_id| {
+        files::helpers::get_or_upload_file_to_connector(
+            state,
+            file_id,
+            &merchant_account,
+            &dispute,
+            &payment_intent,
+            &payment_attempt,
+        )
+    };
+    let submit_evidence_request_data = types::SubmitEvidenceRequestData {
+        dispute_id: dispute.dispute_id.clone(),
+        connector_dispute_id: dispute.connector_dispute_id.clone(),
+        cancellation_policy_provider_file_id: upload_file_to_connector(req.cancellation_policy)
+            .await?,
+        customer_communication_provider_file_id: upload_file_to_connector(
+            req.customer_communication,
+        )
+        .await?,
+        customer_signature_provider_file_id: upload_file_to_connector(req.customer_signature)
+            .await?,
+        receipt_provider_file_id: upload_file_to_connector(req.receipt).await?,
+        refund_policy_provider_file_id: upload_file_to_connector(req.refund_policy).await?,
+        service_documentation_provider_file_id: upload_file_to_connector(req.service_documentation)
+            .await?,
+        shipping_documentation_provider_file_id: upload_file_to_connector(
+            req.shipping_documentation,
+        )
+        .await?,
+        uncategorized_file_provider_file_id: upload_file_to_connector(req.uncategorized_file)
+            .await?,
+        uncategorized_text: req.uncategorized_text,
+    };
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_submit_evidence_router_data(
+        state,
+        &payment_intent,
+        &payment_attempt,
+        &merchant_account,
+        &dispute,
+        submit_evidence_request_data,
+    )
+    .await?;
+    logger::debug!(submit_evidence_router_data=?router_data);
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())
+    .attach_printable("Failed while calling submit evidence connector api")?;
+    let submit_evidence_response = response.response.map_err(|err| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: dispute.connector.clone(),
+            status_code: err.status_code,
+        })
+    })?;
+    let update_dispute = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: submit_evidence_response.dispute_status,
+        connector_status: submit_evidence_response.connector_status,
+    };
+    let updated_dispute = db
+        .update_dispute(dispute, update_dispute)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+    let dispute_response = updated_dispute.foreign_into();
+    Ok(services::ApplicationResponse::Json(dispute_response))
+}
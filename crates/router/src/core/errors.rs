@@ -260,6 +260,8 @@ pub enum ConnectorError {
     WebhookResourceObjectNotFound,
     #[error("Invalid Date/time format")]
     InvalidDateFormat,
+    #[error("File Validation failed")]
+    FileValidationFailed { reason: String },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -381,6 +383,10 @@ pub enum WebhooksFlowError {
     MerchantWebhookURLNotConfigured,
     #[error("Payments core flow failed")]
     PaymentsCoreFailed,
+    #[error("Refunds core flow failed")]
+    RefundsCoreFailed,
+    #[error("Payouts core flow failed")]
+    PayoutsCoreFailed,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
@@ -389,12 +395,40 @@ pub enum WebhooksFlowError {
     CallToMerchantFailed,
     #[error("Webhook not received by merchant")]
     NotReceivedByMerchant,
+    #[error("Webhook source verification failed")]
+    WebhookSourceVerificationFailed,
+    #[error("Dispute webhook status validation failed")]
+    DisputeWebhookValidationFailed,
+    #[error("Resource not found")]
+    ResourceNotFound,
+    #[error("Failed to encode outgoing webhook")]
+    OutgoingWebhookEncodingFailed,
+    #[error("Webhook event updation failed")]
+    WebhookEventUpdationFailed,
+    #[error("Failed to schedule outgoing webhook for retry")]
+    OutgoingWebhookRetrySchedulingFailed,
+    #[error("Failed to sign outgoing webhook")]
+    OutgoingWebhookSigningFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    #[error("Failed to upload file to file storage")]
+    UploadFailed,
+    #[error("Failed to retrieve file from file storage")]
+    RetrieveFailed,
+    #[error("Failed to delete file from file storage")]
+    DeleteFailed,
+    #[error("Invalid file key provided")]
+    InvalidFileKey,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ApiKeyError {
     #[error("Failed to read API key hash from hexadecimal string")]
     FailedToReadHashFromHex,
+    #[error("Failed to read API key hash key from hexadecimal string")]
+    FailedToReadHashKeyFromHex,
     #[error("Failed to verify provided API key hash against stored API key hash")]
     HashVerificationFailed,
 }
@@ -78,6 +78,16 @@ pub enum ApiErrorResponse {
         message = "{message}",
     )]
     GenericUnauthorized { message: String },
+    #[error(
+        error_type = ErrorType::InvalidRequestError, code = "IR_19",
+        message = "Too many requests, retry after {retry_after_secs} seconds"
+    )]
+    RateLimitExceeded { retry_after_secs: u64 },
+    #[error(
+        error_type = ErrorType::InvalidRequestError, code = "IR_20",
+        message = "The API key used does not have the scopes required for this request"
+    )]
+    AccessForbidden,
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -115,6 +125,14 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The config with the specified key already exists in our records")]
+    DuplicateConfig,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The idempotency key has already been used with a different request")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A request with the same idempotency key is still being processed")]
+    IdempotentRequestInProgress,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
@@ -135,12 +153,34 @@ pub enum ApiErrorResponse {
     MandateNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Dispute does not exist in our records")]
+    DisputeNotFound { dispute_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Subscription does not exist in our records")]
+    SubscriptionNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File does not exist in our records")]
+    FileNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File not available")]
+    FileNotAvailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard")]
     RefundNotPossible { connector: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Mandate Validation Failed" )]
     MandateValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The payment amount exceeds the amount remaining on the mandate")]
+    MandateAmountLimitExceeded,
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The mandate cannot be charged at this time")]
+    MandateNotInEffect { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "The mandate has been charged the maximum number of times for the current period")]
+    MandateChargeFrequencyExceeded { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Dispute status validation failed")]
+    DisputeStatusValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Subscription status validation failed")]
+    SubscriptionStatusValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "File validation failed")]
+    FileValidationFailed { reason: String },
     #[error(error_type= ErrorType::ValidationError, code = "HE_03", message = "The payment has not succeeded yet. Please pass a successful payment to initiate refund")]
     PaymentNotSucceeded,
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
@@ -190,6 +230,7 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::InvalidEphemeralKey
             | Self::InvalidJwtToken
             | Self::GenericUnauthorized { .. } => StatusCode::UNAUTHORIZED, // 401
+            Self::AccessForbidden => StatusCode::FORBIDDEN, // 403
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -214,10 +255,18 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
             | Self::PaymentUnexpectedState { .. }
-            | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            | Self::MandateValidationFailed { .. }
+            | Self::MandateAmountLimitExceeded
+            | Self::MandateNotInEffect { .. }
+            | Self::MandateChargeFrequencyExceeded { .. }
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::SubscriptionStatusValidationFailed { .. }
+            | Self::FileValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
-            Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::DuplicateRefundRequest
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST, // 400
             Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::MandateActive
@@ -234,14 +283,23 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::ResourceIdNotFound
             | Self::ConfigNotFound
             | Self::AddressNotFound
-            | Self::ApiKeyNotFound => StatusCode::BAD_REQUEST, // 400
+            | Self::ApiKeyNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound { .. }
+            | Self::SubscriptionNotFound
+            | Self::FileNotFound
+            | Self::FileNotAvailable => StatusCode::BAD_REQUEST, // 400
             Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
-            | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
-            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
-            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
+            | Self::DuplicateMandate
+            | Self::DuplicateConfig => StatusCode::BAD_REQUEST, // 400
+            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,  // 503
+            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,           // 400
+            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,     // 501
+            Self::IdempotencyKeyReused => StatusCode::CONFLICT,             // 409
+            Self::IdempotentRequestInProgress => StatusCode::CONFLICT,      // 409
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS, // 429
         }
     }
 
@@ -0,0 +1,586 @@
+use std::{borrow::Cow, future::Future};
+
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde_json::Value;
+use time::PrimitiveDateTime;
+
+use super::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    consts,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services::{self, file_storage},
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, ProcessTrackerExt},
+    },
+    utils,
+};
+
+const EXPORT_RUNNER: &str = "EXPORT_WORKFLOW";
+const EXPORT_TASK: &str = "EXPORT";
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ExportTrackingData {
+    pub merchant_id: String,
+    pub file_id: String,
+    pub request: api::ExportRequest,
+}
+
+fn get_export_process_tracker_id(merchant_id: &str, file_id: &str) -> String {
+    pt_utils::get_process_tracker_id(EXPORT_RUNNER, EXPORT_TASK, file_id, merchant_id)
+}
+
+fn get_file_type(format: api_enums::ExportFormat) -> &'static str {
+    match format {
+        api_enums::ExportFormat::Csv => "text/csv",
+        api_enums::ExportFormat::Jsonl => "application/x-ndjson",
+    }
+}
+
+fn get_file_name(request: &api::ExportRequest) -> String {
+    format!(
+        "{}_{}_{}.{}",
+        request.entity,
+        request.start_time.date(),
+        request.end_time.date(),
+        request.format
+    )
+}
+
+fn validate_export_request(state: &AppState, request: &api::ExportRequest) -> RouterResult<()> {
+    if request.start_time >= request.end_time {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must be before end_time".to_string(),
+        })?
+    }
+    let max_range_days = state.conf.export.max_range_days;
+    if request.end_time - request.start_time > time::Duration::days(max_range_days) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("at most {max_range_days} days of records can be exported at once"),
+        })?
+    }
+    Ok(())
+}
+
+fn get_export_response(
+    state: &AppState,
+    file_metadata: storage::FileMetadata,
+) -> api::ExportResponse {
+    let (status, download_url) = if file_metadata.available {
+        (
+            api_enums::ExportStatus::Completed,
+            Some(format!(
+                "{}/files/{}",
+                state.conf.server.base_url, file_metadata.file_id
+            )),
+        )
+    } else {
+        (api_enums::ExportStatus::Pending, None)
+    };
+    api::ExportResponse {
+        export_id: file_metadata.file_id,
+        file_name: file_metadata.file_name,
+        status,
+        download_url,
+        created_at: file_metadata.created_at,
+    }
+}
+
+/// Request an export of the records of the merchant, which are written to a file by the
+/// scheduler, so that large exports do not hold up the request
+#[instrument(skip_all)]
+pub async fn create_export(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    request: api::ExportRequest,
+) -> RouterResponse<api::ExportResponse> {
+    validate_export_request(state, &request)?;
+    let db = &*state.store;
+    let merchant_id = merchant_account.merchant_id;
+    let file_id = utils::generate_id(consts::ID_LENGTH, "file");
+    let file_new = storage::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_id.clone(),
+        file_name: Some(get_file_name(&request)),
+        file_size: 0,
+        file_type: get_file_type(request.format).to_string(),
+        file_key: format!("{merchant_id}/{file_id}"),
+        file_upload_provider: None,
+        provider_file_id: None,
+        available: false,
+    };
+    let file_metadata = db
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata")?;
+
+    let tracking_data = ExportTrackingData {
+        merchant_id: merchant_id.clone(),
+        file_id: file_id.clone(),
+        request,
+    };
+    let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        get_export_process_tracker_id(&merchant_id, &file_id),
+        EXPORT_TASK,
+        EXPORT_RUNNER,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to schedule the export")?;
+
+    Ok(services::ApplicationResponse::Json(get_export_response(
+        state,
+        file_metadata,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_export(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    export_id: String,
+) -> RouterResponse<api::ExportResponse> {
+    let db = &*state.store;
+    let file_metadata = db
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &export_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))?;
+    // Only the files written by an export can be retrieved as exports
+    db.find_process_by_id(&get_export_process_tracker_id(
+        &merchant_account.merchant_id,
+        &export_id,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?
+    .ok_or(errors::ApiErrorResponse::FileNotFound)
+    .into_report()?;
+
+    Ok(services::ApplicationResponse::Json(get_export_response(
+        state,
+        file_metadata,
+    )))
+}
+
+/// A record of the export, which is written as a row of the CSV file or a line of the JSON lines
+/// file
+trait ExportRecord {
+    const FIELDS: &'static [&'static str];
+
+    fn id(&self) -> i32;
+
+    /// The values of the fields, in the order of `FIELDS`
+    fn values(&self) -> Vec<Value>;
+}
+
+fn string<T: ToString>(value: T) -> Value {
+    Value::String(value.to_string())
+}
+
+fn optional_string<T: ToString>(value: Option<T>) -> Value {
+    value.map(string).unwrap_or_default()
+}
+
+fn timestamp(value: PrimitiveDateTime) -> Value {
+    common_utils::custom_serde::iso8601::serialize(&value, serde_json::value::Serializer)
+        .unwrap_or_default()
+}
+
+// Payments have no connector transaction ID, which is exported with their attempts
+impl ExportRecord for storage::PaymentIntent {
+    const FIELDS: &'static [&'static str] = &[
+        "payment_id",
+        "status",
+        "amount",
+        "amount_captured",
+        "currency",
+        "connector",
+        "customer_id",
+        "description",
+        "created_at",
+        "modified_at",
+    ];
+
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn values(&self) -> Vec<Value> {
+        vec![
+            string(&self.payment_id),
+            string(self.status),
+            Value::from(self.amount),
+            Value::from(self.amount_captured),
+            optional_string(self.currency),
+            optional_string(self.connector_id.as_ref()),
+            optional_string(self.customer_id.as_ref()),
+            optional_string(self.description.as_ref()),
+            timestamp(self.created_at),
+            timestamp(self.modified_at),
+        ]
+    }
+}
+
+// The fees charged by connectors are not recorded, so no processor fee is exported
+impl ExportRecord for storage::PaymentAttempt {
+    const FIELDS: &'static [&'static str] = &[
+        "attempt_id",
+        "payment_id",
+        "status",
+        "connector",
+        "connector_transaction_id",
+        "amount",
+        "amount_to_capture",
+        "currency",
+        "payment_method",
+        "surcharge_amount",
+        "tax_amount",
+        "offer_amount",
+        "error_code",
+        "error_message",
+        "created_at",
+        "modified_at",
+    ];
+
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn values(&self) -> Vec<Value> {
+        vec![
+            string(&self.attempt_id),
+            string(&self.payment_id),
+            string(self.status),
+            optional_string(self.connector.as_ref()),
+            optional_string(self.connector_transaction_id.as_ref()),
+            Value::from(self.amount),
+            Value::from(self.amount_to_capture),
+            optional_string(self.currency),
+            optional_string(self.payment_method),
+            Value::from(self.surcharge_amount),
+            Value::from(self.tax_amount),
+            Value::from(self.offer_amount),
+            optional_string(self.error_code.as_ref()),
+            optional_string(self.error_message.as_ref()),
+            timestamp(self.created_at),
+            timestamp(self.modified_at),
+        ]
+    }
+}
+
+impl ExportRecord for storage::Refund {
+    const FIELDS: &'static [&'static str] = &[
+        "refund_id",
+        "payment_id",
+        "attempt_id",
+        "connector",
+        "connector_transaction_id",
+        "connector_refund_id",
+        "refund_status",
+        "refund_type",
+        "refund_amount",
+        "total_amount",
+        "currency",
+        "refund_reason",
+        "refund_error_code",
+        "refund_error_message",
+        "created_at",
+        "modified_at",
+    ];
+
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn values(&self) -> Vec<Value> {
+        vec![
+            string(&self.refund_id),
+            string(&self.payment_id),
+            string(&self.attempt_id),
+            string(&self.connector),
+            string(&self.connector_transaction_id),
+            optional_string(self.connector_refund_id.as_ref()),
+            string(self.refund_status),
+            string(self.refund_type),
+            Value::from(self.refund_amount),
+            Value::from(self.total_amount),
+            string(self.currency),
+            optional_string(self.refund_reason.as_ref()),
+            optional_string(self.refund_error_code.as_ref()),
+            optional_string(self.refund_error_message.as_ref()),
+            timestamp(self.created_at),
+            timestamp(self.updated_at),
+        ]
+    }
+}
+
+/// Quote the CSV field if it contains a delimiter, a quote or a line break. Fields which
+/// spreadsheets would evaluate as a formula are prefixed with `'`, so that they are read as text.
+fn escape_csv_field(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@']) {
+        Cow::Owned(format!("'{field}"))
+    } else {
+        Cow::Borrowed(field)
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.into_owned()
+    }
+}
+
+fn get_csv_field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => escape_csv_field(value),
+        // Numbers and booleans are written as is, negative amounts are not formulas
+        value => value.to_string(),
+    }
+}
+
+struct ExportWriter {
+    format: api_enums::ExportFormat,
+    fields: &'static [&'static str],
+    file: Vec<u8>,
+}
+
+impl ExportWriter {
+    fn new(format: api_enums::ExportFormat, fields: &'static [&'static str]) -> Self {
+        let mut writer = Self {
+            format,
+            fields,
+            file: Vec::new(),
+        };
+        if format == api_enums::ExportFormat::Csv {
+            writer.write_csv_row(fields.iter().map(|field| escape_csv_field(field)));
+        }
+        writer
+    }
+
+    /// Take the records written since the last chunk was taken
+    fn take_chunk(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.file)
+    }
+
+    fn write_csv_row(&mut self, fields: impl Iterator<Item = String>) {
+        self.file
+            .extend_from_slice(fields.collect::<Vec<_>>().join(",").as_bytes());
+        self.file.extend_from_slice(b"\r\n");
+    }
+
+    fn write_record(&mut self, values: Vec<Value>) -> CustomResult<(), serde_json::Error> {
+        match self.format {
+            api_enums::ExportFormat::Csv => self.write_csv_row(values.iter().map(get_csv_field)),
+            api_enums::ExportFormat::Jsonl => {
+                let record: serde_json::Map<String, Value> = self
+                    .fields
+                    .iter()
+                    .map(|field| field.to_string())
+                    .zip(values)
+                    .collect();
+                serde_json::to_writer(&mut self.file, &record).into_report()?;
+                self.file.push(b'\n');
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the records to the file one batch at a time, paging through the records by their `id`,
+/// so that only a batch of the records is held in memory. Returns the size of the file.
+async fn write_records<T, F, Fut>(
+    file_storage: &dyn file_storage::FileStorageInterface,
+    file_key: &str,
+    format: api_enums::ExportFormat,
+    batch_size: i64,
+    fetch_batch: F,
+) -> RouterResult<usize>
+where
+    T: ExportRecord,
+    F: Fn(i32) -> Fut,
+    Fut: Future<Output = CustomResult<Vec<T>, errors::StorageError>>,
+{
+    let mut writer = ExportWriter::new(format, T::FIELDS);
+    // Any file left by an earlier run of the export is overwritten
+    let header = writer.take_chunk();
+    let mut file_size = header.len();
+    file_storage
+        .upload_file(file_key, header)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to upload the exported file to file storage")?;
+
+    let mut after_id = 0;
+    loop {
+        let batch = fetch_batch(after_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read the records to be exported")?;
+        let is_last_batch = usize::try_from(batch_size).map_or(true, |size| batch.len() < size);
+        for record in &batch {
+            writer
+                .write_record(record.values())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write the exported record")?;
+        }
+
+        let chunk = writer.take_chunk();
+        file_size += chunk.len();
+        file_storage
+            .append_file(file_key, chunk)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to upload the exported file to file storage")?;
+
+        match batch.last() {
+            Some(record) if !is_last_batch => after_id = record.id(),
+            _ => break,
+        }
+    }
+    Ok(file_size)
+}
+
+/// Write the records requested by the export to the file of the export, making the file available
+/// for download
+#[instrument(skip(state))]
+pub async fn write_export(state: &AppState, tracking_data: ExportTrackingData) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = tracking_data.merchant_id.as_str();
+    let request = &tracking_data.request;
+    let (start_time, end_time) = (request.start_time, request.end_time);
+    let batch_size = state.conf.export.batch_size;
+
+    let file_metadata = db
+        .find_file_metadata_by_merchant_id_file_id(merchant_id, &tracking_data.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)?;
+
+    let file_storage_client = file_storage::get_file_storage_client(&state.conf.file_storage);
+    let file_key = file_metadata.file_key.as_str();
+    let file_size = match request.entity {
+        api_enums::ExportEntity::PaymentIntent => {
+            write_records(
+                file_storage_client.as_ref(),
+                file_key,
+                request.format,
+                batch_size,
+                move |after_id| {
+                    db.get_payment_intents_for_export(
+                        merchant_id,
+                        start_time,
+                        end_time,
+                        after_id,
+                        batch_size,
+                    )
+                },
+            )
+            .await?
+        }
+        api_enums::ExportEntity::PaymentAttempt => {
+            write_records(
+                file_storage_client.as_ref(),
+                file_key,
+                request.format,
+                batch_size,
+                move |after_id| {
+                    db.get_payment_attempts_for_export(
+                        merchant_id,
+                        start_time,
+                        end_time,
+                        after_id,
+                        batch_size,
+                    )
+                },
+            )
+            .await?
+        }
+        api_enums::ExportEntity::Refund => {
+            write_records(
+                file_storage_client.as_ref(),
+                file_key,
+                request.format,
+                batch_size,
+                move |after_id| {
+                    db.get_refunds_for_export(
+                        merchant_id,
+                        start_time,
+                        end_time,
+                        after_id,
+                        batch_size,
+                    )
+                },
+            )
+            .await?
+        }
+    };
+
+    let file_size = i32::try_from(file_size)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Exported file is too large")?;
+    db.update_file_metadata(
+        file_metadata,
+        storage::FileMetadataUpdate::ExportUpdate { file_size },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to update file_metadata of the export")?;
+    logger::info!(file_size, "Records exported");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_export_writer() {
+        let fields = &["id", "description", "amount"];
+        let values = vec![
+            string("pay_1"),
+            string("shoes, \"red\""),
+            Value::from(Some(100)),
+        ];
+
+        let mut writer = ExportWriter::new(api_enums::ExportFormat::Csv, fields);
+        writer.write_record(values.clone()).unwrap();
+        writer
+            .write_record(vec![string("pay_2"), Value::Null, Value::Null])
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(writer.file).unwrap(),
+            "id,description,amount\r\npay_1,\"shoes, \"\"red\"\"\",100\r\npay_2,,\r\n"
+        );
+
+        let mut writer = ExportWriter::new(api_enums::ExportFormat::Csv, fields);
+        writer
+            .write_record(vec![
+                string("=HYPERLINK(\"http://example.com\")"),
+                string("@SUM(A1:A2)"),
+                Value::from(-100),
+            ])
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(writer.take_chunk()).unwrap(),
+            "id,description,amount\r\n\"'=HYPERLINK(\"\"http://example.com\"\")\",'@SUM(A1:A2),-100\r\n"
+        );
+        assert!(writer.take_chunk().is_empty());
+
+        let mut writer = ExportWriter::new(api_enums::ExportFormat::Jsonl, fields);
+        writer.write_record(values).unwrap();
+        let line = String::from_utf8(writer.file).unwrap();
+        assert!(line.ends_with('\n'));
+        assert_eq!(
+            serde_json::from_str::<Value>(&line).unwrap(),
+            serde_json::json!({"id": "pay_1", "description": "shoes, \"red\"", "amount": 100})
+        );
+    }
+}
@@ -0,0 +1,106 @@
+pub mod helpers;
+
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::errors::{self, RouterResponse, StorageErrorExt};
+use crate::{
+    consts,
+    routes::AppState,
+    services::{self, file_storage},
+    types::{api, storage},
+    utils,
+};
+
+#[instrument(skip_all)]
+pub async fn files_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    create_file_request: api::CreateFileRequest,
+) -> RouterResponse<api::CreateFileResponse> {
+    helpers::validate_file_upload(state, &merchant_account, &create_file_request).await?;
+    let file_id = utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", merchant_account.merchant_id, file_id);
+    let file_new = storage::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        file_name: create_file_request.file_name.clone(),
+        file_size: create_file_request.file_size,
+        file_type: create_file_request.file_type.to_string(),
+        file_key: file_key.clone(),
+        file_upload_provider: None,
+        provider_file_id: None,
+        available: false,
+    };
+    let db = &*state.store;
+    let file_metadata = db
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata")?;
+    file_storage::get_file_storage_client(&state.conf.file_storage)
+        .upload_file(&file_key, create_file_request.file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to upload file to file storage")?;
+    // The file is made available only once it has been persisted in the file storage
+    db.update_file_metadata(
+        file_metadata,
+        storage::FileMetadataUpdate::StorageUpdate { available: true },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Unable to update file_metadata with file_id: {file_id}"))?;
+    Ok(services::ApplicationResponse::Json(
+        api::CreateFileResponse { file_id },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn files_delete_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let file_metadata = db
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))?;
+    file_storage::get_file_storage_client(&state.conf.file_storage)
+        .delete_file(&file_metadata.file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to delete file from file storage")?;
+    db.delete_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to delete file_metadata with file_id: {file_id}")
+        })?;
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+#[instrument(skip_all)]
+pub async fn files_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))?;
+    let file_data = helpers::retrieve_file_from_file_storage(state, &file_metadata).await?;
+    let content_type = file_metadata
+        .file_type
+        .parse::<mime::Mime>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse file content type")?;
+    Ok(services::ApplicationResponse::FileData((
+        file_data,
+        content_type,
+    )))
+}
@@ -0,0 +1,168 @@
+use error_stack::{report, IntoReport, ResultExt};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    routes::AppState,
+    services::{self, file_storage},
+    types::{self, api, storage},
+    utils::{self, OptionExt},
+};
+
+/// Validate the file against the constraints of the connector it is going to be submitted to
+pub async fn validate_file_upload(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    create_file_request: &api::CreateFileRequest,
+) -> RouterResult<()> {
+    utils::when(create_file_request.file_size <= 0, || {
+        Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file_size should be greater than 0".to_owned(),
+        }))
+    })?;
+    match create_file_request.purpose {
+        api::FilePurpose::DisputeEvidence => {
+            let dispute_id = create_file_request
+                .dispute_id
+                .as_ref()
+                .get_required_value("dispute_id")?;
+            let dispute = state
+                .store
+                .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, dispute_id)
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                        dispute_id: dispute_id.to_owned(),
+                    })
+                })?;
+            let connector_data = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &dispute.connector,
+                api::GetToken::Connector,
+            )?;
+            connector_data
+                .connector
+                .validate_file_upload(
+                    create_file_request.purpose,
+                    create_file_request.file_size,
+                    create_file_request.file_type.clone(),
+                )
+                .map_err(|error| match error.current_context() {
+                    errors::ConnectorError::FileValidationFailed { reason } => {
+                        let reason = reason.to_owned();
+                        error.change_context(errors::ApiErrorResponse::FileValidationFailed {
+                            reason,
+                        })
+                    }
+                    _ => error.to_payment_failed_response(),
+                })
+        }
+    }
+}
+
+pub async fn retrieve_file_from_file_storage(
+    state: &AppState,
+    file_metadata: &storage::FileMetadata,
+) -> RouterResult<Vec<u8>> {
+    utils::when(!file_metadata.available, || {
+        Err(report!(errors::ApiErrorResponse::FileNotAvailable))
+            .attach_printable("File not available in file storage")
+    })?;
+    file_storage::get_file_storage_client(&state.conf.file_storage)
+        .retrieve_file(&file_metadata.file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve file from file storage")
+}
+
+/// Get the identifier of the file at the dispute's connector, uploading the file to the connector
+/// if it has not been uploaded there already
+pub async fn get_or_upload_file_to_connector(
+    state: &AppState,
+    file_id: Option<String>,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<String>> {
+    let file_id = match file_id {
+        Some(file_id) => file_id,
+        None => return Ok(None),
+    };
+    let db = &*state.store;
+    let file_metadata = db
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))?;
+    if let (Some(file_upload_provider), Some(provider_file_id)) = (
+        &file_metadata.file_upload_provider,
+        &file_metadata.provider_file_id,
+    ) {
+        if file_upload_provider == &dispute.connector {
+            return Ok(Some(provider_file_id.to_owned()));
+        }
+    }
+    let file = retrieve_file_from_file_storage(state, &file_metadata).await?;
+    let file_type = file_metadata
+        .file_type
+        .parse::<mime::Mime>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse file content type")?;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_upload_file_router_data(
+        state,
+        payment_intent,
+        payment_attempt,
+        merchant_account,
+        &dispute.connector,
+        types::UploadFileRequestData {
+            file_key: file_metadata.file_key.clone(),
+            file,
+            file_type,
+            file_size: file_metadata.file_size,
+        },
+    )
+    .await?;
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())
+    .attach_printable("Failed while calling upload file connector api")?;
+    let upload_file_response = response.response.map_err(|err| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: dispute.connector.clone(),
+            status_code: err.status_code,
+        })
+    })?;
+    let provider_file_id = upload_file_response.provider_file_id;
+    db.update_file_metadata(
+        file_metadata,
+        storage::FileMetadataUpdate::ProviderUpdate {
+            file_upload_provider: dispute.connector.clone(),
+            provider_file_id: provider_file_id.clone(),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Unable to update file_metadata with file_id: {file_id}"))?;
+    Ok(Some(provider_file_id))
+}
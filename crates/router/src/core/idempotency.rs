@@ -0,0 +1,439 @@
+use common_utils::crypto::{HmacSha512, SignMessage};
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::IdempotencySettings,
+    db::StorageInterface,
+    headers, logger,
+    scheduler::utils as pt_utils,
+    services::encryption,
+    types::storage::{self, enums as storage_enums, ProcessTrackerExt},
+};
+
+const IDEMPOTENCY_KEY_CLEANUP_RUNNER: &str = "IDEMPOTENCY_KEY_CLEANUP_WORKFLOW";
+const IDEMPOTENCY_KEY_CLEANUP_TASK: &str = "IDEMPOTENCY_KEY_CLEANUP";
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct IdempotencyKeyCleanupTrackingData {
+    pub merchant_id: String,
+}
+
+/// Outcome of claiming an idempotency key for a request
+pub enum IdempotentRequest {
+    /// The key was not used before, the request is to be processed
+    Process(storage::IdempotencyKey),
+    /// The request was already processed with the key, its response is to be replayed
+    Replay {
+        status_code: u16,
+        response_body: String,
+    },
+}
+
+/// Idempotency key sent with the request, retrieving resources being idempotent by nature
+pub fn get_idempotency_key(request: &actix_web::HttpRequest) -> Option<&str> {
+    if request.method() == actix_web::http::Method::GET {
+        return None;
+    }
+    request
+        .headers()
+        .get(headers::IDEMPOTENCY_KEY)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+}
+
+/// Fingerprint of a request sent with an idempotency key, computed over the raw body of the request
+/// before the body is consumed by the extractors of the route. Multipart bodies are streamed to the
+/// route as is, and only the method and path of such requests are fingerprinted.
+#[derive(Clone, Debug)]
+pub struct RequestFingerprint(pub String);
+
+/// Digest identifying the request sent with an idempotency key, keyed with a secret of the
+/// server so that the digest cannot be brute forced to recover the request, such as the card
+/// details of the request.
+pub fn generate_request_fingerprint(
+    secret: &str,
+    method: &str,
+    path: &str,
+    body: &[u8],
+) -> RouterResult<String> {
+    let mut message = format!("{method} {path} ").into_bytes();
+    message.extend_from_slice(body);
+    let digest = HmacSha512
+        .sign_message(secret.as_bytes(), &message)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate request fingerprint")?;
+    Ok(hex::encode(digest))
+}
+
+/// Errors raised on validating the request before it is processed, the request making no change
+/// which would be repeated if it is corrected and retried with the same key
+pub fn is_request_validation_error(error: &errors::ApiErrorResponse) -> bool {
+    matches!(
+        error,
+        errors::ApiErrorResponse::MissingRequiredField { .. }
+            | errors::ApiErrorResponse::InvalidDataFormat { .. }
+            | errors::ApiErrorResponse::InvalidRequestData { .. }
+            | errors::ApiErrorResponse::InvalidDataValue { .. }
+            | errors::ApiErrorResponse::InvalidRequestUrl
+            | errors::ApiErrorResponse::InvalidHttpMethod
+            | errors::ApiErrorResponse::ClientSecretNotGiven
+            | errors::ApiErrorResponse::ClientSecretInvalid
+            | errors::ApiErrorResponse::PreconditionFailed { .. }
+    )
+}
+
+fn is_expired(idempotency_key: &storage::IdempotencyKey, ttl: i64) -> bool {
+    idempotency_key.created_at + time::Duration::seconds(ttl) < common_utils::date_time::now()
+}
+
+/// Claim the idempotency key for the request, replaying the stored response if the same request
+/// was already processed with the key
+#[instrument(skip(db, settings))]
+pub async fn claim_idempotency_key(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    idempotency_key: &str,
+    request_fingerprint: String,
+    settings: &IdempotencySettings,
+) -> RouterResult<IdempotentRequest> {
+    let ttl = settings.key_ttl_secs;
+    let idempotency_key_new = storage::IdempotencyKeyNew {
+        merchant_id: merchant_id.to_string(),
+        idempotency_key: idempotency_key.to_string(),
+        request_fingerprint,
+        created_at: common_utils::date_time::now(),
+    };
+    match db
+        .insert_idempotency_key(idempotency_key_new.clone(), ttl)
+        .await
+    {
+        Ok(claimed) => {
+            if let Err(error) = add_idempotency_key_cleanup_task(db, merchant_id, ttl).await {
+                logger::error!(
+                    ?error,
+                    "Failed to schedule the cleanup of expired idempotency keys"
+                );
+            }
+            return Ok(IdempotentRequest::Process(claimed));
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => {}
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to insert idempotency key")
+        }
+    }
+
+    let existing = db
+        .find_idempotency_key_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find idempotency key")?;
+
+    // Expired keys are reclaimed, as if they were never used
+    if is_expired(&existing, ttl) {
+        db.delete_idempotency_key_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete expired idempotency key")?;
+        return db
+            .insert_idempotency_key(idempotency_key_new, ttl)
+            .await
+            .map(IdempotentRequest::Process)
+            .map_err(|error| {
+                // Another request reclaimed the key in the meantime
+                if error.current_context().is_db_unique_violation() {
+                    error.change_context(errors::ApiErrorResponse::IdempotentRequestInProgress)
+                } else {
+                    error.change_context(errors::ApiErrorResponse::InternalServerError)
+                }
+            });
+    }
+
+    if existing.request_fingerprint != idempotency_key_new.request_fingerprint {
+        return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused));
+    }
+
+    match (existing.response_status_code, existing.response_body) {
+        (Some(status_code), Some(response_body)) => Ok(IdempotentRequest::Replay {
+            status_code: u16::try_from(status_code).unwrap_or(200),
+            response_body: decrypt_response_body(
+                &response_body,
+                &settings.response_encryption_key,
+            )?,
+        }),
+        _ => Err(report!(
+            errors::ApiErrorResponse::IdempotentRequestInProgress
+        )),
+    }
+}
+
+/// Response of the request encrypted to be stored for the idempotency key, so that the secrets
+/// of the response, such as the client secret of a payment, are replayed without being stored in
+/// plaintext
+fn encrypt_response_body(response_body: String, encryption_key: &str) -> RouterResult<String> {
+    let key = hex::decode(encryption_key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid idempotent response encryption key")?;
+    encryption::encrypt(&response_body, &key)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the idempotent response")
+}
+
+fn decrypt_response_body(response_body: &str, encryption_key: &str) -> RouterResult<String> {
+    let key = hex::decode(encryption_key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid idempotent response encryption key")?;
+    let response_body = hex::decode(response_body)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid idempotent response")?;
+    encryption::decrypt(response_body, &key)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the idempotent response")
+}
+
+/// Store the response of the request, to be replayed for later requests with the same key. The key
+/// is released if the response cannot be stored, so that the client can retry the request rather
+/// than being told that it is in progress until the key expires.
+#[instrument(skip_all)]
+pub async fn save_idempotent_response(
+    db: &dyn StorageInterface,
+    idempotency_key: storage::IdempotencyKey,
+    status_code: u16,
+    response_body: String,
+    settings: &IdempotencySettings,
+) {
+    let response_body =
+        match encrypt_response_body(response_body, &settings.response_encryption_key) {
+            Ok(response_body) => response_body,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to store the response for the idempotency key"
+                );
+                release_idempotency_key(db, &idempotency_key).await;
+                return;
+            }
+        };
+    let idempotency_key_update = storage::IdempotencyKeyUpdate::ResponseUpdate {
+        response_status_code: i16::try_from(status_code).unwrap_or(i16::MAX),
+        response_body,
+    };
+    let ttl = settings.key_ttl_secs;
+    if let Err(error) = db
+        .update_idempotency_key(idempotency_key.clone(), idempotency_key_update, ttl)
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to store the response for the idempotency key"
+        );
+        release_idempotency_key(db, &idempotency_key).await;
+    }
+}
+
+/// Release the idempotency key of a request whose response is not replayed, such as a request
+/// failing validation or a response failing to be stored, so that the request can be retried with
+/// the same key
+#[instrument(skip_all)]
+pub async fn release_idempotency_key(
+    db: &dyn StorageInterface,
+    idempotency_key: &storage::IdempotencyKey,
+) {
+    if let Err(error) = db
+        .delete_idempotency_key_by_merchant_id_idempotency_key(
+            &idempotency_key.merchant_id,
+            &idempotency_key.idempotency_key,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to release the idempotency key");
+    }
+}
+
+/// Schedule the deletion of the idempotency keys of the merchant once they expire. A merchant has
+/// a single cleanup task, which is run again once keys are claimed after it has finished.
+pub async fn add_idempotency_key_cleanup_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    ttl: i64,
+) -> Result<(), errors::ProcessTrackerError> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        IDEMPOTENCY_KEY_CLEANUP_RUNNER,
+        IDEMPOTENCY_KEY_CLEANUP_TASK,
+        "idempotency_keys",
+        merchant_id,
+    );
+    let schedule_time = common_utils::date_time::now() + time::Duration::seconds(ttl);
+
+    match db.find_process_by_id(&process_tracker_id).await? {
+        Some(process) if process.status == storage_enums::ProcessTrackerStatus::Finish => {
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: Some(String::from("Pending")),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await?;
+            Ok(())
+        }
+        // The cleanup of the keys of the merchant is already scheduled
+        Some(_) => Ok(()),
+        None => {
+            let tracking_data = IdempotencyKeyCleanupTrackingData {
+                merchant_id: merchant_id.to_string(),
+            };
+            let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+                process_tracker_id,
+                IDEMPOTENCY_KEY_CLEANUP_TASK,
+                IDEMPOTENCY_KEY_CLEANUP_RUNNER,
+                tracking_data,
+                schedule_time,
+            )?;
+            match db.insert_process(process_tracker_entry).await {
+                Ok(_) => Ok(()),
+                // Another request scheduled the cleanup in the meantime
+                Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+                Err(error) => Err(error.into()),
+            }
+        }
+    }
+}
+
+/// Delete the expired idempotency keys of the merchant, returning the time at which the oldest of
+/// the remaining keys expires
+#[instrument(skip(db))]
+pub async fn delete_expired_idempotency_keys(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    ttl: i64,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let expiry = time::Duration::seconds(ttl);
+    db.delete_idempotency_keys_by_merchant_id_created_before(
+        merchant_id,
+        common_utils::date_time::now() - expiry,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to delete expired idempotency keys")?;
+
+    let oldest = db
+        .find_oldest_idempotency_key_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find idempotency keys")?;
+    Ok(oldest.map(|idempotency_key| idempotency_key.created_at + expiry))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_request_fingerprint() {
+        let secret = "fingerprint_secret";
+        let body = br#"{"amount":100,"card_cvc":"123"}"#;
+        let fingerprint = generate_request_fingerprint(secret, "POST", "/payments", body).unwrap();
+        assert_eq!(
+            fingerprint,
+            generate_request_fingerprint(secret, "POST", "/payments", body).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_request_fingerprint(
+                secret,
+                "POST",
+                "/payments",
+                br#"{"amount":100,"card_cvc":"456"}"#
+            )
+            .unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_request_fingerprint(secret, "POST", "/refunds", body).unwrap()
+        );
+        // The fingerprint is keyed with the secret of the server
+        assert_ne!(
+            fingerprint,
+            generate_request_fingerprint("other_secret", "POST", "/payments", body).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_request_fingerprint_with_card_details() {
+        let secret = "fingerprint_secret";
+        // The same key sent with another card conflicts with the first request rather than
+        // replaying its response
+        assert_ne!(
+            generate_request_fingerprint(
+                secret,
+                "POST",
+                "/payments",
+                br#"{"amount":100,"payment_method_data":{"card":{"card_number":"4242424242424242"}}}"#,
+            )
+            .unwrap(),
+            generate_request_fingerprint(
+                secret,
+                "POST",
+                "/payments",
+                br#"{"amount":100,"payment_method_data":{"card":{"card_number":"4111111111111111"}}}"#,
+            )
+            .unwrap()
+        );
+        assert_ne!(
+            generate_request_fingerprint(
+                secret,
+                "POST",
+                "/vs/v1/payment_intents",
+                b"amount=100&payment_method_data[card][number]=4242424242424242",
+            )
+            .unwrap(),
+            generate_request_fingerprint(
+                secret,
+                "POST",
+                "/vs/v1/payment_intents",
+                b"amount=100&payment_method_data[card][number]=4111111111111111",
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_response_body_encryption() {
+        let encryption_key = hex::encode([7_u8; 32]);
+        let response_body =
+            r#"{"payment_id":"pay_1","client_secret":"pay_1_secret_1"}"#.to_string();
+        let encrypted = encrypt_response_body(response_body.clone(), &encryption_key).unwrap();
+        // The client secret is stored encrypted, and replayed with the response
+        assert!(!encrypted.contains("pay_1_secret_1"));
+        assert_eq!(
+            decrypt_response_body(&encrypted, &encryption_key).unwrap(),
+            response_body
+        );
+        assert!(decrypt_response_body(&encrypted, &hex::encode([8_u8; 32])).is_err());
+    }
+
+    #[test]
+    fn test_request_validation_error() {
+        assert!(is_request_validation_error(
+            &errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "amount"
+            }
+        ));
+        assert!(!is_request_validation_error(
+            &errors::ApiErrorResponse::InternalServerError
+        ));
+    }
+}
@@ -1,24 +1,31 @@
-use error_stack::{report, ResultExt};
+use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, logger, tracing};
 use storage_models::enums as storage_enums;
+use time::PrimitiveDateTime;
 
-use super::payments::helpers;
+use super::payments::{self, helpers};
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks,
+    },
     db::StorageInterface,
     routes::AppState,
     services,
     types::{
         self,
         api::{
-            customers,
+            self, customers,
             mandates::{self, MandateResponseExt},
         },
         storage,
         transformers::ForeignInto,
     },
+    utils,
 };
 
+const MANDATE_USAGE_UPDATE_ATTEMPTS: usize = 3;
+
 #[instrument(skip(state))]
 pub async fn get_mandate(
     state: &AppState,
@@ -35,23 +42,48 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
+    let db = &*state.store;
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+    utils::when(
+        mandate.mandate_status == storage_enums::MandateStatus::Revoked,
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "Mandate has already been revoked".to_string(),
+            }))
+        },
+    )?;
+
+    // Mandates which were not set up with the connector only need to be revoked with us
+    let mandate_status = match mandate.connector_mandate_id.clone() {
+        Some(connector_mandate_id) => {
+            revoke_connector_mandate(state, &merchant_account, &mandate, connector_mandate_id)
+                .await?
+        }
+        None => storage_enums::MandateStatus::Revoked,
+    };
+
     let mandate = db
         .update_mandate_by_merchant_id_mandate_id(
             &merchant_account.merchant_id,
             &req.mandate_id,
-            storage::MandateUpdate::StatusUpdate {
-                mandate_status: storage::enums::MandateStatus::Revoked,
-            },
+            storage::MandateUpdate::StatusUpdate { mandate_status },
         )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
+    if mandate.mandate_status == storage_enums::MandateStatus::Revoked {
+        trigger_mandate_outgoing_webhook(state, &merchant_account, &mandate).await;
+    }
+
     Ok(services::ApplicationResponse::Json(
         mandates::MandateRevokedResponse {
             mandate_id: mandate.mandate_id,
@@ -60,6 +92,124 @@ pub async fn revoke_mandate(
     ))
 }
 
+/// Revoke the mandate with the connector it was set up with, returning the status of the mandate
+/// reported by the connector. Connectors which do not support revoking mandates have the mandate
+/// revoked only with us.
+#[instrument(skip_all)]
+async fn revoke_connector_mandate(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: &storage::Mandate,
+    connector_mandate_id: String,
+) -> RouterResult<storage_enums::MandateStatus> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &mandate.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = core_utils::construct_mandate_revoke_router_data(
+        state,
+        merchant_account,
+        mandate,
+        connector_mandate_id,
+    )
+    .await?;
+    logger::debug!(mandate_revoke_router_data=?router_data);
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())
+    .attach_printable("Failed while calling revoke mandate connector api")?;
+
+    get_connector_mandate_status(&mandate.connector, response.response).map_err(|error| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: error.code,
+            message: error.message,
+            connector: mandate.connector.clone(),
+            status_code: error.status_code,
+        })
+    })
+}
+
+/// The status of the mandate from the response of the connector to revoking the mandate
+fn get_connector_mandate_status(
+    connector: &str,
+    response: Result<types::MandateRevokeResponseData, types::ErrorResponse>,
+) -> Result<storage_enums::MandateStatus, types::ErrorResponse> {
+    match response {
+        Ok(response) => Ok(response.mandate_status),
+        Err(error) if error.code == types::ErrorResponse::get_not_implemented().code => {
+            logger::info!(
+                %connector,
+                "Connector does not support revoking mandates, revoking the mandate locally"
+            );
+            Ok(storage_enums::MandateStatus::Revoked)
+        }
+        // The payment method of the mandate was already detached from the customer at Stripe, or
+        // deleted along with the customer, so it can no longer be charged
+        Err(error)
+            if connector == api::enums::Connector::Stripe.to_string()
+                && error.code == "resource_missing" =>
+        {
+            logger::info!(
+                %connector,
+                "Payment method of the mandate no longer exists with the connector"
+            );
+            Ok(storage_enums::MandateStatus::Revoked)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Notify the merchant of the revocation of the mandate
+#[instrument(skip_all)]
+async fn trigger_mandate_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: &storage::Mandate,
+) {
+    let mandate_response =
+        match mandates::MandateResponse::from_db_mandate(state, mandate.clone(), merchant_account)
+            .await
+        {
+            Ok(mandate_response) => mandate_response,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to construct the mandate response for the outgoing webhook"
+                );
+                return;
+            }
+        };
+
+    let result = webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        &mandate.connector,
+        storage_enums::EventType::MandateRevoked,
+        storage_enums::EventClass::Mandates,
+        None,
+        mandate.mandate_id.clone(),
+        storage_enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(mandate_response),
+    )
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to trigger outgoing webhook for the mandate");
+    }
+}
+
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
     state: &AppState,
@@ -109,33 +259,8 @@ where
                 .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
                 .await
                 .change_context(errors::ApiErrorResponse::MandateNotFound)?;
-            let mandate = match mandate.mandate_type {
-                storage_enums::MandateType::SingleUse => state
-                    .store
-                    .update_mandate_by_merchant_id_mandate_id(
-                        &resp.merchant_id,
-                        mandate_id,
-                        storage::MandateUpdate::StatusUpdate {
-                            mandate_status: storage_enums::MandateStatus::Revoked,
-                        },
-                    )
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound),
-                storage_enums::MandateType::MultiUse => state
-                    .store
-                    .update_mandate_by_merchant_id_mandate_id(
-                        &resp.merchant_id,
-                        mandate_id,
-                        storage::MandateUpdate::CaptureAmountUpdate {
-                            amount_captured: Some(
-                                mandate.amount_captured.unwrap_or(0) + resp.request.get_amount(),
-                            ),
-                        },
-                    )
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound),
-            }?;
-
+            // Single-use mandates are revoked, and the usage of multi-use mandates counted, when
+            // the mandate is reserved before the charge is made
             resp.payment_method_id = Some(mandate.payment_method_id);
         }
         None => {
@@ -199,6 +324,260 @@ where
     Ok(resp)
 }
 
+/// The start of the calendar day, week (starting on Monday), month or year containing `time`,
+/// over which the charges of a mandate are counted
+fn get_period_start(
+    time: PrimitiveDateTime,
+    period: storage_enums::MandateFrequencyPeriod,
+) -> Option<PrimitiveDateTime> {
+    let date = time.date();
+    let start_date = match period {
+        storage_enums::MandateFrequencyPeriod::Day => Some(date),
+        storage_enums::MandateFrequencyPeriod::Week => date.checked_sub(time::Duration::days(
+            date.weekday().number_days_from_monday().into(),
+        )),
+        storage_enums::MandateFrequencyPeriod::Month => date.replace_day(1).ok(),
+        storage_enums::MandateFrequencyPeriod::Year => {
+            time::Date::from_ordinal_date(date.year(), 1).ok()
+        }
+    }?;
+    Some(PrimitiveDateTime::new(start_date, time::Time::MIDNIGHT))
+}
+
+/// The usage of the mandate once it is charged `amount` at `now`, failing if the charge is not
+/// permitted by the validity period, the amount limit or the charge frequency of the mandate
+pub fn get_mandate_usage_after_charge(
+    mandate: &storage::Mandate,
+    amount: i64,
+    now: PrimitiveDateTime,
+) -> RouterResult<storage::MandateUpdate> {
+    if let Some(start_date) = mandate.start_date.filter(|start_date| now < *start_date) {
+        Err(report!(errors::ApiErrorResponse::MandateNotInEffect {
+            reason: format!("The mandate can only be charged from {start_date}"),
+        }))?
+    }
+    if let Some(end_date) = mandate.end_date.filter(|end_date| now >= *end_date) {
+        Err(report!(errors::ApiErrorResponse::MandateNotInEffect {
+            reason: format!("The mandate expired at {end_date}"),
+        }))?
+    }
+
+    let amount_captured = mandate
+        .amount_captured
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(errors::ApiErrorResponse::MandateAmountLimitExceeded)
+        .into_report()?;
+    let amount_limit_exceeded = match mandate.mandate_type {
+        storage_enums::MandateType::SingleUse => mandate
+            .mandate_amount
+            .map_or(true, |mandate_amount| amount > mandate_amount),
+        storage_enums::MandateType::MultiUse => mandate
+            .mandate_amount
+            .map_or(false, |mandate_amount| amount_captured > mandate_amount),
+    };
+    utils::when(amount_limit_exceeded, || {
+        Err(report!(
+            errors::ApiErrorResponse::MandateAmountLimitExceeded
+        ))
+    })?;
+
+    let (period_charge_count, period_start) =
+        match (mandate.frequency_max_charges, mandate.frequency_period) {
+            (Some(max_charges), Some(period)) => {
+                let period_start = get_period_start(now, period)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("Failed to compute the start of the mandate period")?;
+                // The charges are counted afresh in each period
+                let period_charge_count = if mandate.period_start == Some(period_start) {
+                    mandate.period_charge_count
+                } else {
+                    0
+                };
+                utils::when(period_charge_count >= max_charges, || {
+                    Err(report!(
+                        errors::ApiErrorResponse::MandateChargeFrequencyExceeded {
+                            reason: format!(
+                            "The mandate can be charged at most {max_charges} times per {period}"
+                        ),
+                        }
+                    ))
+                })?;
+                (period_charge_count + 1, Some(period_start))
+            }
+            _ => (mandate.period_charge_count, mandate.period_start),
+        };
+
+    Ok(storage::MandateUpdate::UsageUpdate {
+        amount_captured: Some(amount_captured),
+        period_charge_count,
+        period_start,
+    })
+}
+
+/// The update reserving the mandate for a charge of `amount` at `now`. Single-use mandates are
+/// revoked by the charge, while the usage of multi-use mandates is counted against their limits.
+pub fn get_mandate_reservation(
+    mandate: &storage::Mandate,
+    amount: i64,
+    now: PrimitiveDateTime,
+) -> RouterResult<storage::MandateUpdate> {
+    utils::when(
+        mandate.mandate_status != storage_enums::MandateStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "mandate is not active".into()
+            }))
+        },
+    )?;
+    let mandate_update = get_mandate_usage_after_charge(mandate, amount, now)?;
+
+    Ok(match mandate.mandate_type {
+        storage_enums::MandateType::SingleUse => storage::MandateUpdate::StatusUpdate {
+            mandate_status: storage_enums::MandateStatus::Revoked,
+        },
+        storage_enums::MandateType::MultiUse => mandate_update,
+    })
+}
+
+/// Reserve the mandate for a charge of `amount` before the charge is made, so that concurrent
+/// charges of the mandate cannot exceed its limits, nor charge a single-use mandate twice. Returns
+/// the reserved mandate, which is to be released if the charge fails.
+#[instrument(skip(state))]
+pub async fn reserve_mandate_usage(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    amount: i64,
+) -> RouterResult<Option<storage::Mandate>> {
+    let db = &*state.store;
+    for _ in 0..MANDATE_USAGE_UPDATE_ATTEMPTS {
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound)
+            })?;
+        let mandate_update =
+            get_mandate_reservation(&mandate, amount, common_utils::date_time::now())?;
+
+        // The update only applies if the mandate is still active and unused by other charges
+        if let Some(mandate) = db
+            .update_mandate_usage(&mandate, mandate_update)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating the usage of the mandate")?
+        {
+            return Ok(Some(mandate));
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+        reason: "The mandate is being charged by another payment, please try again".to_string(),
+    }))
+}
+
+/// The update releasing the reservation of `reserved_mandate` for a charge of `amount` which
+/// failed, given the current state of the mandate
+pub fn get_mandate_release(
+    mandate: &storage::Mandate,
+    reserved_mandate: &storage::Mandate,
+    amount: i64,
+) -> storage::MandateUpdate {
+    if reserved_mandate.mandate_type == storage_enums::MandateType::SingleUse {
+        return storage::MandateUpdate::StatusUpdate {
+            mandate_status: storage_enums::MandateStatus::Active,
+        };
+    }
+
+    // The charge was only counted in the period it was reserved in
+    let period_charge_count = if reserved_mandate.period_start.is_some()
+        && mandate.period_start == reserved_mandate.period_start
+    {
+        (mandate.period_charge_count - 1).max(0)
+    } else {
+        mandate.period_charge_count
+    };
+    storage::MandateUpdate::UsageUpdate {
+        amount_captured: mandate
+            .amount_captured
+            .map(|amount_captured| (amount_captured - amount).max(0)),
+        period_charge_count,
+        period_start: mandate.period_start,
+    }
+}
+
+/// Release the mandate reserved for a charge of `amount` which failed
+#[instrument(skip(db))]
+pub async fn release_mandate_usage(
+    db: &dyn StorageInterface,
+    reserved_mandate: &storage::Mandate,
+    amount: i64,
+) -> RouterResult<()> {
+    for _ in 0..MANDATE_USAGE_UPDATE_ATTEMPTS {
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                &reserved_mandate.merchant_id,
+                &reserved_mandate.mandate_id,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound)
+            })?;
+        let mandate_update = get_mandate_release(&mandate, reserved_mandate, amount);
+
+        if db
+            .update_mandate_usage(&mandate, mandate_update)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating the usage of the mandate")?
+            .is_some()
+        {
+            return Ok(());
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("The usage of the mandate was updated concurrently")
+}
+
+/// Release the mandate reserved for the charge made by the payment attempt, when the charge is
+/// found to have failed after the connector accepted it
+#[instrument(skip_all)]
+pub async fn release_failed_attempt_mandate_usage(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let mandate_id = match payment_attempt.mandate_id.as_deref() {
+        Some(mandate_id) => mandate_id,
+        None => return Ok(()),
+    };
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&payment_attempt.merchant_id, mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+    // The mandate is not reserved by the payment setting it up, and a single-use mandate which is
+    // no longer revoked was already released
+    if mandate.created_at >= payment_attempt.created_at
+        || (mandate.mandate_type == storage_enums::MandateType::SingleUse
+            && mandate.mandate_status != storage_enums::MandateStatus::Revoked)
+    {
+        return Ok(());
+    }
+
+    // The usage was reserved in the period the attempt was made in
+    let period_start = match (mandate.frequency_max_charges, mandate.frequency_period) {
+        (Some(_), Some(period)) => get_period_start(payment_attempt.created_at, period),
+        _ => None,
+    };
+    let reserved_mandate = storage::Mandate {
+        period_start,
+        ..mandate
+    };
+    release_mandate_usage(db, &reserved_mandate, payment_attempt.amount).await
+}
+
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
     fn get_setup_future_usage(&self) -> Option<storage_models::enums::FutureUsage>;
@@ -207,3 +586,235 @@ pub trait MandateBehaviour {
     fn get_payment_method_data(&self) -> api_models::payments::PaymentMethod;
     fn get_setup_mandate_details(&self) -> Option<&api_models::payments::MandateData>;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_mandate() -> storage::Mandate {
+        storage::Mandate {
+            id: 1,
+            mandate_id: "man_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_method_id: "pm_123".to_string(),
+            mandate_status: storage_enums::MandateStatus::Active,
+            mandate_type: storage_enums::MandateType::MultiUse,
+            customer_accepted_at: None,
+            customer_ip_address: None,
+            customer_user_agent: None,
+            network_transaction_id: None,
+            previous_attempt_id: None,
+            created_at: datetime!(2023-01-01 00:00),
+            mandate_amount: Some(1000),
+            mandate_currency: Some(storage_enums::Currency::USD),
+            amount_captured: Some(600),
+            connector: "stripe".to_string(),
+            connector_mandate_id: None,
+            start_date: Some(datetime!(2023-02-01 00:00)),
+            end_date: Some(datetime!(2024-02-01 00:00)),
+            frequency_max_charges: Some(2),
+            frequency_period: Some(storage_enums::MandateFrequencyPeriod::Week),
+            period_charge_count: 1,
+            period_start: Some(datetime!(2023-03-06 00:00)),
+        }
+    }
+
+    fn get_error(result: RouterResult<storage::MandateUpdate>) -> errors::ApiErrorResponse {
+        result.unwrap_err().current_context().clone()
+    }
+
+    #[test]
+    fn test_period_start() {
+        let time = datetime!(2023-03-08 15:30);
+        assert_eq!(
+            get_period_start(time, storage_enums::MandateFrequencyPeriod::Day),
+            Some(datetime!(2023-03-08 00:00))
+        );
+        assert_eq!(
+            get_period_start(time, storage_enums::MandateFrequencyPeriod::Week),
+            Some(datetime!(2023-03-06 00:00))
+        );
+        assert_eq!(
+            get_period_start(time, storage_enums::MandateFrequencyPeriod::Month),
+            Some(datetime!(2023-03-01 00:00))
+        );
+        assert_eq!(
+            get_period_start(time, storage_enums::MandateFrequencyPeriod::Year),
+            Some(datetime!(2023-01-01 00:00))
+        );
+    }
+
+    #[test]
+    fn test_mandate_usage_after_charge() {
+        let mandate = get_mandate();
+
+        assert!(matches!(
+            get_mandate_usage_after_charge(&mandate, 400, datetime!(2023-03-08 12:00)).unwrap(),
+            storage::MandateUpdate::UsageUpdate {
+                amount_captured: Some(1000),
+                period_charge_count: 2,
+                period_start: Some(start),
+            } if start == datetime!(2023-03-06 00:00)
+        ));
+        // The charges are counted afresh in the next week
+        assert!(matches!(
+            get_mandate_usage_after_charge(&mandate, 400, datetime!(2023-03-13 12:00)).unwrap(),
+            storage::MandateUpdate::UsageUpdate {
+                period_charge_count: 1,
+                period_start: Some(start),
+                ..
+            } if start == datetime!(2023-03-13 00:00)
+        ));
+    }
+
+    #[test]
+    fn test_mandate_usage_limits() {
+        let mut mandate = get_mandate();
+        let now = datetime!(2023-03-08 12:00);
+
+        assert!(matches!(
+            get_error(get_mandate_usage_after_charge(&mandate, 401, now)),
+            errors::ApiErrorResponse::MandateAmountLimitExceeded
+        ));
+        assert!(matches!(
+            get_error(get_mandate_usage_after_charge(
+                &mandate,
+                100,
+                datetime!(2023-01-15 00:00)
+            )),
+            errors::ApiErrorResponse::MandateNotInEffect { .. }
+        ));
+        assert!(matches!(
+            get_error(get_mandate_usage_after_charge(
+                &mandate,
+                100,
+                datetime!(2024-02-01 00:00)
+            )),
+            errors::ApiErrorResponse::MandateNotInEffect { .. }
+        ));
+
+        mandate.period_charge_count = 2;
+        assert!(matches!(
+            get_error(get_mandate_usage_after_charge(&mandate, 100, now)),
+            errors::ApiErrorResponse::MandateChargeFrequencyExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn test_single_use_mandate_reservation() {
+        let mut mandate = storage::Mandate {
+            mandate_type: storage_enums::MandateType::SingleUse,
+            amount_captured: None,
+            ..get_mandate()
+        };
+        let now = datetime!(2023-03-08 12:00);
+
+        assert!(matches!(
+            get_mandate_reservation(&mandate, 1000, now).unwrap(),
+            storage::MandateUpdate::StatusUpdate {
+                mandate_status: storage_enums::MandateStatus::Revoked
+            }
+        ));
+        assert!(matches!(
+            get_error(get_mandate_reservation(&mandate, 1001, now)),
+            errors::ApiErrorResponse::MandateAmountLimitExceeded
+        ));
+
+        // A single-use mandate cannot be reserved again once it is revoked
+        mandate.mandate_status = storage_enums::MandateStatus::Revoked;
+        assert!(matches!(
+            get_error(get_mandate_reservation(&mandate, 1000, now)),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+        assert!(matches!(
+            get_mandate_release(&mandate, &mandate, 1000),
+            storage::MandateUpdate::StatusUpdate {
+                mandate_status: storage_enums::MandateStatus::Active
+            }
+        ));
+    }
+
+    #[test]
+    fn test_multi_use_mandate_release() {
+        let reserved_mandate = get_mandate();
+        let mandate = storage::Mandate {
+            amount_captured: Some(900),
+            period_charge_count: 2,
+            ..get_mandate()
+        };
+
+        assert!(matches!(
+            get_mandate_release(&mandate, &reserved_mandate, 400),
+            storage::MandateUpdate::UsageUpdate {
+                amount_captured: Some(500),
+                period_charge_count: 1,
+                period_start: Some(start),
+            } if start == datetime!(2023-03-06 00:00)
+        ));
+        // The charge is not released from a later period
+        let mandate = storage::Mandate {
+            period_start: Some(datetime!(2023-03-13 00:00)),
+            ..mandate
+        };
+        assert!(matches!(
+            get_mandate_release(&mandate, &reserved_mandate, 400),
+            storage::MandateUpdate::UsageUpdate {
+                period_charge_count: 2,
+                ..
+            }
+        ));
+    }
+
+    fn get_connector_error(code: &str) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: "message".to_string(),
+            reason: None,
+            status_code: 404,
+        }
+    }
+
+    #[test]
+    fn test_connector_mandate_status() {
+        assert_eq!(
+            get_connector_mandate_status(
+                "stripe",
+                Ok(types::MandateRevokeResponseData {
+                    mandate_status: storage_enums::MandateStatus::Active,
+                })
+            )
+            .unwrap(),
+            storage_enums::MandateStatus::Active
+        );
+        // Connectors which do not support revoking mandates have the mandate revoked locally
+        assert_eq!(
+            get_connector_mandate_status("payu", Err(types::ErrorResponse::get_not_implemented()))
+                .unwrap(),
+            storage_enums::MandateStatus::Revoked
+        );
+        assert!(get_connector_mandate_status(
+            "stripe",
+            Err(get_connector_error("api_key_expired"))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_connector_mandate_status_payment_method_missing() {
+        assert_eq!(
+            get_connector_mandate_status("stripe", Err(get_connector_error("resource_missing")))
+                .unwrap(),
+            storage_enums::MandateStatus::Revoked
+        );
+        // The error code is specific to Stripe
+        assert!(get_connector_mandate_status(
+            "adyen",
+            Err(get_connector_error("resource_missing"))
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    connector::mock_three_ds::transformers::{
+        MockThreeDsAuthenticationRequest, MockThreeDsAuthenticationResponse,
+        MockThreeDsPreAuthenticationRequest, MockThreeDsPreAuthenticationResponse,
+        MockThreeDsResultsRequest, MockThreeDsTransStatus,
+    },
+    consts,
+    utils::{self, ByteSliceExt},
+};
+
+const MESSAGE_VERSION: &str = "2.2.0";
+/// Card for which the customer is challenged by the access control server
+pub const CHALLENGE_CARD_NUMBER: &str = "4000000000001091";
+/// Card for which the customer fails to be authenticated
+pub const FAILED_CARD_NUMBER: &str = "4000000000001018";
+
+/// Message exchanged with the access control server, as the challenge request and response
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChallengeMessage {
+    #[serde(rename = "threeDSServerTransID")]
+    three_ds_server_trans_id: String,
+    message_version: String,
+    message_type: String,
+    trans_status: Option<MockThreeDsTransStatus>,
+}
+
+fn encode_message<T: serde::Serialize + std::fmt::Debug>(message: &T) -> RouterResult<String> {
+    let message = utils::Encode::<T>::encode_to_vec(message)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    Ok(consts::BASE64_ENGINE.encode(message))
+}
+
+fn decode_challenge_message(message: &str) -> RouterResult<ChallengeMessage> {
+    consts::BASE64_ENGINE
+        .decode(message)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue { field_name: "cres" })?
+        .parse_struct("ChallengeMessage")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue { field_name: "cres" })
+}
+
+/// Outcome of the authentication of the customer with the card, which is decided by the card
+/// number so that each outcome can be tested
+pub fn get_trans_status(card_number: &str) -> MockThreeDsTransStatus {
+    match card_number {
+        CHALLENGE_CARD_NUMBER => MockThreeDsTransStatus::ChallengeRequired,
+        FAILED_CARD_NUMBER => MockThreeDsTransStatus::NotAuthenticated,
+        _ => MockThreeDsTransStatus::Authenticated,
+    }
+}
+
+fn get_authentication_response(
+    three_ds_server_trans_id: String,
+    trans_status: MockThreeDsTransStatus,
+    creq: Option<String>,
+    base_url: &str,
+) -> MockThreeDsAuthenticationResponse {
+    let authenticated = trans_status == MockThreeDsTransStatus::Authenticated;
+    MockThreeDsAuthenticationResponse {
+        three_ds_server_trans_id,
+        message_version: MESSAGE_VERSION.to_string(),
+        trans_status,
+        acs_url: creq
+            .is_some()
+            .then(|| format!("{base_url}/mock_three_ds/challenge")),
+        creq,
+        authentication_value: authenticated
+            .then(|| consts::BASE64_ENGINE.encode("mock_three_ds_authentication")),
+        eci: authenticated.then(|| "05".to_string()),
+        ds_trans_id: authenticated.then(|| uuid::Uuid::new_v4().to_string()),
+    }
+}
+
+pub fn pre_authenticate(
+    base_url: &str,
+    _request: MockThreeDsPreAuthenticationRequest,
+) -> RouterResult<MockThreeDsPreAuthenticationResponse> {
+    let three_ds_server_trans_id = uuid::Uuid::new_v4().to_string();
+    let three_ds_method_data = encode_message(&serde_json::json!({
+        "threeDSServerTransID": three_ds_server_trans_id,
+    }))?;
+    Ok(MockThreeDsPreAuthenticationResponse {
+        three_ds_server_trans_id,
+        message_version: MESSAGE_VERSION.to_string(),
+        three_ds_method_url: Some(format!("{base_url}/mock_three_ds/method")),
+        three_ds_method_data: Some(three_ds_method_data),
+    })
+}
+
+pub fn authenticate(
+    base_url: &str,
+    request: MockThreeDsAuthenticationRequest,
+) -> RouterResult<MockThreeDsAuthenticationResponse> {
+    let trans_status = get_trans_status(&request.card_number);
+    let creq = match trans_status {
+        MockThreeDsTransStatus::ChallengeRequired => Some(encode_message(&ChallengeMessage {
+            three_ds_server_trans_id: request.three_ds_server_trans_id.clone(),
+            message_version: request.message_version,
+            message_type: "CReq".to_string(),
+            trans_status: None,
+        })?),
+        _ => None,
+    };
+    Ok(get_authentication_response(
+        request.three_ds_server_trans_id,
+        trans_status,
+        creq,
+        base_url,
+    ))
+}
+
+/// Answer the challenge of the customer, which the mock access control server always accepts
+pub fn challenge(creq: &str) -> RouterResult<String> {
+    let challenge_request = decode_challenge_message(creq)?;
+    encode_message(&ChallengeMessage {
+        message_type: "CRes".to_string(),
+        trans_status: Some(MockThreeDsTransStatus::Authenticated),
+        ..challenge_request
+    })
+}
+
+pub fn get_results(
+    base_url: &str,
+    request: MockThreeDsResultsRequest,
+) -> RouterResult<MockThreeDsAuthenticationResponse> {
+    let challenge_response = decode_challenge_message(&request.cres)?;
+    if challenge_response.three_ds_server_trans_id != request.three_ds_server_trans_id {
+        Err(errors::ApiErrorResponse::InvalidDataValue { field_name: "cres" })?
+    }
+    Ok(get_authentication_response(
+        request.three_ds_server_trans_id,
+        challenge_response
+            .trans_status
+            .unwrap_or(MockThreeDsTransStatus::NotAuthenticated),
+        None,
+        base_url,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::connector::mock_three_ds::transformers::MockThreeDsCompletionIndicator;
+
+    fn authentication_request(card_number: &str) -> MockThreeDsAuthenticationRequest {
+        MockThreeDsAuthenticationRequest {
+            three_ds_server_trans_id: "trans_1".to_string(),
+            message_version: MESSAGE_VERSION.to_string(),
+            card_number: card_number.to_string(),
+            amount: 100,
+            currency: "USD".to_string(),
+            three_ds_comp_ind: MockThreeDsCompletionIndicator::Completed,
+            browser_info: None,
+        }
+    }
+
+    #[test]
+    fn test_authentication_outcome_by_card() {
+        let response = authenticate("", authentication_request("4242424242424242")).unwrap();
+        assert_eq!(response.trans_status, MockThreeDsTransStatus::Authenticated);
+        assert_eq!(response.eci.as_deref(), Some("05"));
+        assert!(response.creq.is_none());
+
+        let response = authenticate("", authentication_request(FAILED_CARD_NUMBER)).unwrap();
+        assert_eq!(
+            response.trans_status,
+            MockThreeDsTransStatus::NotAuthenticated
+        );
+        assert!(response.authentication_value.is_none());
+
+        let response = authenticate("", authentication_request(CHALLENGE_CARD_NUMBER)).unwrap();
+        assert_eq!(
+            response.trans_status,
+            MockThreeDsTransStatus::ChallengeRequired
+        );
+
+        let cres = challenge(&response.creq.unwrap()).unwrap();
+        let results = get_results(
+            "",
+            MockThreeDsResultsRequest {
+                three_ds_server_trans_id: "trans_1".to_string(),
+                cres,
+            },
+        )
+        .unwrap();
+        assert_eq!(results.trans_status, MockThreeDsTransStatus::Authenticated);
+    }
+}
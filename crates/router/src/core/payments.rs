@@ -1,7 +1,10 @@
 pub mod access_token;
+pub mod customers;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
+pub mod routing;
 pub mod transformers;
 
 use std::{fmt::Debug, marker::PhantomData, time::Instant};
@@ -13,7 +16,8 @@ use router_env::{instrument, tracing};
 use time;
 
 pub use self::operations::{
-    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentMethodValidate,
+    PaymentAuthenticate, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
+    PaymentIncrementalAuthorization, PaymentMethodValidate, PaymentPreAuthenticate,
     PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
 };
 use self::{
@@ -24,15 +28,18 @@ use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult},
         payment_methods::vault,
+        webhooks,
     },
     db::StorageInterface,
     logger, pii,
     routes::AppState,
-    scheduler::utils as pt_utils,
+    scheduler::{utils as pt_utils, workflows::payment_sync},
     services,
     types::{
-        self, api,
+        self,
+        api::{self, enums as api_enums},
         storage::{self, enums as storage_enums},
+        transformers::{ForeignInto, ForeignTryInto},
     },
     utils::OptionExt,
 };
@@ -109,13 +116,33 @@ where
         )
         .await?;
 
-    let connector_details = route_connector(
-        state,
-        &merchant_account,
-        &mut payment_data,
-        connector_details,
-    )
-    .await?;
+    // Payments routed by the routing algorithm of the merchant can be retried on a fallback
+    // connector, when authorization with the routed connector fails, whether they are confirmed on
+    // creation or later
+    let operation_name = format!("{operation:?}");
+    let should_retry_on_fallback = matches!(connector_details, api::ConnectorCallType::Routing)
+        && (operation_name == "PaymentConfirm"
+            || (operation_name == "PaymentCreate" && payment_data.confirm == Some(true)));
+
+    // The 3DS server connector authenticating the customer is not the connector of the attempt
+    let connector_details = if is_three_ds_authentication_operation(&operation) {
+        connector_details
+    } else {
+        let connector_details = route_connector(
+            state,
+            &merchant_account,
+            &mut payment_data,
+            connector_details,
+        )
+        .await?;
+        if let Some(connector_name) = payment_data.payment_attempt.connector.as_deref() {
+            helpers::validate_three_ds_authentication_connector(
+                &payment_data.payment_attempt,
+                connector_name,
+            )?;
+        }
+        connector_details
+    };
 
     let (operation, mut payment_data) = operation
         .to_update_tracker()?
@@ -134,7 +161,22 @@ where
         .await?;
 
     if should_call_connector(&operation, &payment_data) {
-        payment_data = match connector_details {
+        // Kept to notify the merchant of the payment failing, when the connector declines it
+        let payment_data_before_connector_call = payment_data.clone();
+        let connector_result = match connector_details {
+            api::ConnectorCallType::Single(connector) if should_retry_on_fallback => {
+                retry::call_connector_service_with_retries(
+                    state,
+                    &merchant_account,
+                    &validate_result.payment_id,
+                    connector,
+                    &operation,
+                    payment_data,
+                    &customer,
+                    call_connector_action,
+                )
+                .await
+            }
             api::ConnectorCallType::Single(connector) => {
                 call_connector_service(
                     state,
@@ -146,7 +188,7 @@ where
                     &customer,
                     call_connector_action,
                 )
-                .await?
+                .await
             }
             api::ConnectorCallType::Multiple(connectors) => {
                 call_multiple_connectors_service(
@@ -157,7 +199,7 @@ where
                     payment_data,
                     &customer,
                 )
-                .await?
+                .await
             }
             api::ConnectorCallType::Routing => {
                 let connector = payment_data
@@ -185,10 +227,31 @@ where
                     &customer,
                     call_connector_action,
                 )
-                .await?
+                .await
             }
         };
-        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
+        payment_data = match connector_result {
+            Ok(payment_data) => payment_data,
+            Err(error) => {
+                if is_connector_decline(error.current_context()) {
+                    trigger_declined_payment_webhook(
+                        state,
+                        &merchant_account,
+                        payment_data_before_connector_call,
+                        customer.clone(),
+                        &operation,
+                    )
+                    .await;
+                }
+                return Err(error);
+            }
+        };
+        // The card is kept in the vault while the customer is authenticated with 3DS, to authorize
+        // the payment with it once authenticated
+        if !is_three_ds_authentication_operation(&operation) {
+            vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token)
+                .await
+        }
     }
     Ok((payment_data, req, customer))
 }
@@ -221,13 +284,22 @@ where
 {
     let (payment_data, req, customer) = payments_operation_core(
         state,
-        merchant_account,
+        merchant_account.clone(),
         operation.clone(),
         req,
         call_connector_action,
     )
     .await?;
 
+    trigger_payments_webhook(
+        state,
+        merchant_account,
+        &payment_data,
+        customer.clone(),
+        &operation,
+    )
+    .await;
+
     Res::generate_response(
         Some(req),
         payment_data,
@@ -238,6 +310,147 @@ where
     )
 }
 
+/// Notify the merchant of the outcome of the payment operations initiated by the merchant. Changes
+/// in the status of the payment learnt by syncing with the connector are notified from the
+/// incoming webhooks flow instead.
+#[instrument(skip_all)]
+async fn trigger_payments_webhook<F, Op>(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    customer: Option<storage::Customer>,
+    operation: &Op,
+) where
+    F: Clone,
+    Op: Debug,
+{
+    let payment_intent = &payment_data.payment_intent;
+    let event_type =
+        get_payments_webhook_event_type(&format!("{operation:?}"), payment_intent.status);
+    let (event_type, connector) = match (event_type, &payment_data.payment_attempt.connector) {
+        (Some(event_type), Some(connector)) => (event_type, connector.clone()),
+        _ => return,
+    };
+
+    let payments_response = <api::PaymentsResponse as transformers::ToResponse<
+        api::PaymentsRequest,
+        PaymentData<F>,
+        &Op,
+    >>::generate_response(
+        None,
+        payment_data.clone(),
+        customer,
+        services::AuthFlow::Merchant,
+        &state.conf.server,
+        operation,
+    );
+    let payments_response = match payments_response {
+        Ok(services::ApplicationResponse::Json(payments_response)) => payments_response,
+        _ => {
+            logger::error!("Failed to construct the payments response for the outgoing webhook");
+            return;
+        }
+    };
+
+    let result = webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        &connector,
+        event_type,
+        storage_enums::EventClass::Payments,
+        None,
+        payment_intent.payment_id.clone(),
+        storage_enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+    )
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to trigger outgoing webhook for the payment");
+    }
+}
+
+fn get_payments_webhook_event_type(
+    operation_name: &str,
+    intent_status: storage_enums::IntentStatus,
+) -> Option<storage_enums::EventType> {
+    match operation_name {
+        "PaymentCapture" if intent_status == storage_enums::IntentStatus::Succeeded => {
+            Some(storage_enums::EventType::PaymentCaptured)
+        }
+        "PaymentCreate" | "PaymentConfirm" | "PaymentCapture" | "PaymentCancel" => {
+            let intent_status: api_enums::IntentStatus = intent_status.foreign_into();
+            intent_status.foreign_try_into().ok()
+        }
+        _ => None,
+    }
+}
+
+fn is_connector_decline(error: &errors::ApiErrorResponse) -> bool {
+    matches!(
+        error,
+        errors::ApiErrorResponse::ExternalConnectorError { .. }
+    )
+}
+
+/// A payment declined by the connector fails with an error after the payment has been marked as
+/// failed, the merchant is notified of it with the payment as stored after the decline
+async fn trigger_declined_payment_webhook<F, Op>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+    customer: Option<storage::Customer>,
+    operation: &Op,
+) where
+    F: Clone,
+    Op: Debug,
+{
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_data.payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await;
+    // The payment could have been retried on fallback connectors, the last attempt is notified
+    let payment_attempt = db
+        .find_payment_attempts_by_payment_id_merchant_id(
+            &payment_data.payment_attempt.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map(|attempts| {
+            attempts
+                .into_iter()
+                .max_by_key(|attempt| attempt.created_at)
+        });
+    match (payment_intent, payment_attempt) {
+        (Ok(payment_intent), Ok(payment_attempt)) => {
+            payment_data.payment_intent = payment_intent;
+            if let Some(payment_attempt) = payment_attempt {
+                payment_data.payment_attempt = payment_attempt;
+            }
+        }
+        (Err(error), _) | (_, Err(error)) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the declined payment for the outgoing webhook"
+            );
+            return;
+        }
+    }
+
+    trigger_payments_webhook(
+        state,
+        merchant_account.clone(),
+        &payment_data,
+        customer,
+        operation,
+    )
+    .await;
+}
+
 fn is_start_pay<Op: Debug>(operation: &Op) -> bool {
     format!("{operation:?}").eq("PaymentStart")
 }
@@ -506,9 +719,13 @@ where
     pub force_sync: Option<bool>,
     pub payment_method_data: Option<api::PaymentMethod>,
     pub refunds: Vec<storage::Refund>,
+    pub attempts: Option<Vec<storage::PaymentAttempt>>,
+    pub capture: Option<storage::Capture>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
+    /// The request authenticating the customer with the 3DS server, for the authenticate flow
+    pub three_ds_authenticate_request: Option<api::PaymentsAuthenticateRequest>,
 }
 
 #[derive(Debug, Default)]
@@ -587,17 +804,25 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             payment_data.payment_intent.status,
             storage_enums::IntentStatus::RequiresCapture
         ),
-        "PaymentCapture" => {
+        "PaymentCapture" | "PaymentIncrementalAuthorization" => {
             matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::RequiresCapture
             )
         }
         "PaymentSession" => true,
+        "PaymentPreAuthenticate" | "PaymentAuthenticate" => true,
         _ => false,
     }
 }
 
+fn is_three_ds_authentication_operation<Op: Debug>(operation: &Op) -> bool {
+    matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentPreAuthenticate" | "PaymentAuthenticate"
+    )
+}
+
 #[cfg(feature = "olap")]
 pub async fn list_payments(
     db: &dyn StorageInterface,
@@ -616,6 +841,12 @@ pub async fn list_payments(
                 )
             })?;
 
+    let total_count =
+        helpers::count_by_constraints(db, &constraints, merchant_id, merchant.storage_scheme)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to count the payments matching the constraints")?;
+
     let data: Vec<api::PaymentsResponse> = payment_intent
         .into_iter()
         .map(types::transformers::ForeignInto::foreign_into)
@@ -623,6 +854,7 @@ pub async fn list_payments(
     Ok(services::ApplicationResponse::Json(
         api::PaymentListResponse {
             size: data.len(),
+            total_count,
             data,
         },
     ))
@@ -662,6 +894,119 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+const SCHEDULED_CAPTURE_RUNNER: &str = "SCHEDULED_CAPTURE_WORKFLOW";
+const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+
+fn get_scheduled_capture_process_tracker_id(payment_attempt: &storage::PaymentAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        SCHEDULED_CAPTURE_RUNNER,
+        SCHEDULED_CAPTURE_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    )
+}
+
+/// Schedule the capture of a payment with a scheduled capture method at its `capture_on` time
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = match (payment_attempt.capture_method, payment_attempt.capture_on) {
+        (Some(storage_enums::CaptureMethod::Scheduled), Some(capture_on)) => capture_on,
+        _ => return Ok(()),
+    };
+    let tracking_data = api::PaymentsCaptureRequest {
+        payment_id: Some(payment_attempt.payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        ..Default::default()
+    };
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            get_scheduled_capture_process_tracker_id(payment_attempt),
+            SCHEDULED_CAPTURE_TASK,
+            SCHEDULED_CAPTURE_RUNNER,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Stop the scheduled capture of a payment once the payment has been voided
+pub async fn cancel_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    if payment_attempt.capture_method != Some(storage_enums::CaptureMethod::Scheduled) {
+        return Ok(());
+    }
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_scheduled_capture_process_tracker_id(payment_attempt)],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage_enums::ProcessTrackerStatus::Finish,
+            business_status: Some("PAYMENT_VOIDED".to_string()),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while cancelling the scheduled capture of the payment")?;
+    Ok(())
+}
+
+const CAPTURE_SYNC_RUNNER: &str = "CAPTURE_SYNC_WORKFLOW";
+const CAPTURE_SYNC_TASK: &str = "CAPTURE_SYNC";
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CaptureSyncTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub capture_id: String,
+}
+
+/// Schedule the sync of a capture the connector reported pending, until the connector has
+/// charged or failed the capture
+pub async fn add_capture_sync_task(
+    db: &dyn StorageInterface,
+    capture: &storage::Capture,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = match payment_sync::get_sync_process_schedule_time(
+        db,
+        &capture.connector,
+        &capture.merchant_id,
+        0,
+    )
+    .await?
+    {
+        Some(schedule_time) => schedule_time,
+        None => return Ok(()),
+    };
+    let tracking_data = CaptureSyncTrackingData {
+        merchant_id: capture.merchant_id.clone(),
+        payment_id: capture.payment_id.clone(),
+        attempt_id: capture.attempt_id.clone(),
+        capture_id: capture.capture_id.clone(),
+    };
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            pt_utils::get_process_tracker_id(
+                CAPTURE_SYNC_RUNNER,
+                CAPTURE_SYNC_TASK,
+                &capture.capture_id,
+                &capture.merchant_id,
+            ),
+            CAPTURE_SYNC_TASK,
+            CAPTURE_SYNC_RUNNER,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 pub async fn route_connector<F>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
@@ -679,16 +1024,13 @@ where
         }
 
         api::ConnectorCallType::Routing => {
-            let routing_algorithm: api::RoutingAlgorithm = merchant_account
-                .routing_algorithm
-                .clone()
-                .parse_value("RoutingAlgorithm")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Could not decode merchant routing rules")?;
-
-            let connector_name = match routing_algorithm {
-                api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-            };
+            let routing_input = routing::RoutingInput::from_payment_data(payment_data);
+            let connector_name = routing::get_connector_name_from_routing_algorithm(
+                state,
+                merchant_account,
+                &routing_input,
+            )
+            .await?;
 
             let connector_data = api::ConnectorData::get_connector_by_name(
                 &state.conf.connectors,
@@ -706,3 +1048,55 @@ where
         call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declined_authorization_webhook_event_type() {
+        let decline = errors::ApiErrorResponse::ExternalConnectorError {
+            code: "card_declined".to_string(),
+            message: "Your card was declined.".to_string(),
+            connector: "stripe".to_string(),
+            status_code: 402,
+        };
+        assert!(is_connector_decline(&decline));
+        assert!(!is_connector_decline(
+            &errors::ApiErrorResponse::InternalServerError
+        ));
+
+        // A declined authorization marks the payment as failed before the error is returned
+        for operation_name in ["PaymentCreate", "PaymentConfirm"] {
+            assert_eq!(
+                get_payments_webhook_event_type(
+                    operation_name,
+                    storage_enums::IntentStatus::Failed
+                ),
+                Some(storage_enums::EventType::PaymentFailed)
+            );
+        }
+        assert_eq!(
+            get_payments_webhook_event_type("PaymentStatus", storage_enums::IntentStatus::Failed),
+            None
+        );
+    }
+
+    #[test]
+    fn test_capture_webhook_event_type() {
+        assert_eq!(
+            get_payments_webhook_event_type(
+                "PaymentCapture",
+                storage_enums::IntentStatus::Succeeded
+            ),
+            Some(storage_enums::EventType::PaymentCaptured)
+        );
+        assert_eq!(
+            get_payments_webhook_event_type(
+                "PaymentConfirm",
+                storage_enums::IntentStatus::Succeeded
+            ),
+            Some(storage_enums::EventType::PaymentSucceeded)
+        );
+    }
+}
@@ -1,16 +1,20 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use common_utils::ext_traits::AsyncExt;
 use error_stack::{IntoReport, ResultExt};
+use router_env::opentelemetry;
 
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult},
         payments,
     },
-    routes::AppState,
+    logger,
+    routes::{metrics, AppState},
     services,
     types::{self, api as api_types, storage},
+    utils,
 };
 
 /// This function replaces the request and response type of routerdata with the
@@ -82,57 +86,70 @@ pub async fn add_access_token<
 ) -> RouterResult<types::AddAccessTokenResult> {
     if connector.connector_name.supports_access_token() {
         let merchant_id = &merchant_account.merchant_id;
+        let connector_name = connector.connector.id();
         let store = &*state.store;
+        let metric_attributes = [opentelemetry::KeyValue::new(
+            "connector",
+            connector_name.to_string(),
+        )];
         let old_access_token = store
-            .get_access_token(merchant_id, connector.connector.id())
+            .get_access_token(merchant_id, connector_name)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("DB error when accessing the access token")?;
 
         let res = match old_access_token {
-            Some(access_token) => Ok(Some(access_token)),
+            Some(access_token) => {
+                metrics::ACCESS_TOKEN_CACHE_HIT.add(&metrics::CONTEXT, 1, &metric_attributes);
+                Ok(Some(access_token))
+            }
             None => {
-                let cloned_router_data = router_data.clone();
-                let refresh_token_request_data = types::AccessTokenRequestData::try_from(
-                    router_data.connector_auth_type.clone(),
-                )
-                .into_report()
-                .attach_printable(
-                    "Could not create access token request, invalid connector account credentials",
-                )?;
-
-                let refresh_token_response_data: Result<types::AccessToken, types::ErrorResponse> =
-                    Err(types::ErrorResponse::default());
-                let refresh_token_router_data =
-                    router_data_type_conversion::<_, api_types::AccessTokenAuth, _, _, _, _>(
-                        cloned_router_data,
-                        refresh_token_request_data,
-                        refresh_token_response_data,
-                    );
-                refresh_connector_auth(
-                    state,
-                    connector,
-                    merchant_account,
-                    &refresh_token_router_data,
-                )
-                .await?
-                .async_map(|access_token| async {
-                    //Store the access token in db
-                    let store = &*state.store;
-                    // This error should not be propagated, we don't want payments to fail once we have
-                    // the access token, the next request will create new access token
+                metrics::ACCESS_TOKEN_CACHE_MISS.add(&metrics::CONTEXT, 1, &metric_attributes);
+                // The lock is held with a token of the request, so that only the request holding
+                // the lock can release it
+                let lock_token = utils::generate_id(consts::ID_LENGTH, "lock");
+                let lock_acquired = store
+                    .acquire_access_token_lock(
+                        merchant_id,
+                        connector_name,
+                        &lock_token,
+                        state.conf.access_token.lock_ttl_secs,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("DB error when acquiring the access token lock")?;
+
+                if lock_acquired {
+                    let res = refresh_access_token_with_lock(
+                        state,
+                        connector,
+                        merchant_account,
+                        router_data,
+                    )
+                    .await;
+                    // The lock expires by itself if it cannot be released, so the error is only
+                    // logged
                     let _ = store
-                        .set_access_token(
-                            merchant_id,
-                            connector.connector.id(),
-                            access_token.clone(),
-                        )
+                        .release_access_token_lock(merchant_id, connector_name, &lock_token)
                         .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("DB error when setting the access token");
-                    Some(access_token)
-                })
-                .await
+                        .map_err(|error| logger::error!(access_token_lock_error=?error));
+                    res?
+                } else {
+                    // Another request is refreshing the access token, which is awaited instead of
+                    // requesting another access token from the connector
+                    match wait_for_access_token(state, merchant_id, connector_name).await? {
+                        Some(access_token) => Ok(Some(access_token)),
+                        None => {
+                            refresh_and_cache_access_token(
+                                state,
+                                connector,
+                                merchant_account,
+                                router_data,
+                            )
+                            .await?
+                        }
+                    }
+                }
             }
         };
 
@@ -148,6 +165,140 @@ pub async fn add_access_token<
     }
 }
 
+async fn refresh_access_token_with_lock<
+    F: Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Res: Debug + Clone + 'static,
+>(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::RouterData<F, Req, Res>,
+) -> RouterResult<Result<Option<types::AccessToken>, types::ErrorResponse>> {
+    // The access token may have been refreshed by another request which released the lock after
+    // the access token was looked up
+    let access_token = state
+        .store
+        .get_access_token(&merchant_account.merchant_id, connector.connector.id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("DB error when accessing the access token")?;
+
+    match access_token {
+        Some(access_token) => Ok(Ok(Some(access_token))),
+        None => {
+            refresh_and_cache_access_token(state, connector, merchant_account, router_data).await
+        }
+    }
+}
+
+/// Wait for the access token being refreshed by another request, until the configured number of
+/// checks is exhausted
+async fn wait_for_access_token(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+) -> RouterResult<Option<types::AccessToken>> {
+    let settings = &state.conf.access_token;
+    for _ in 0..settings.lock_wait_retries {
+        tokio::time::sleep(Duration::from_millis(settings.lock_wait_interval_ms)).await;
+        let access_token = state
+            .store
+            .get_access_token(merchant_id, connector_name)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("DB error when accessing the access token")?;
+        if access_token.is_some() {
+            return Ok(access_token);
+        }
+    }
+    logger::warn!(
+        merchant_id,
+        connector_name,
+        "Access token was not refreshed by the request holding the lock"
+    );
+    Ok(None)
+}
+
+async fn refresh_and_cache_access_token<
+    F: Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Res: Debug + Clone + 'static,
+>(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::RouterData<F, Req, Res>,
+) -> RouterResult<Result<Option<types::AccessToken>, types::ErrorResponse>> {
+    let merchant_id = &merchant_account.merchant_id;
+    let cloned_router_data = router_data.clone();
+    let refresh_token_request_data =
+        types::AccessTokenRequestData::try_from(router_data.connector_auth_type.clone())
+            .into_report()
+            .attach_printable(
+                "Could not create access token request, invalid connector account credentials",
+            )?;
+
+    let refresh_token_response_data: Result<types::AccessToken, types::ErrorResponse> =
+        Err(types::ErrorResponse::default());
+    let refresh_token_router_data =
+        router_data_type_conversion::<_, api_types::AccessTokenAuth, _, _, _, _>(
+            cloned_router_data,
+            refresh_token_request_data,
+            refresh_token_response_data,
+        );
+    let res = refresh_connector_auth(
+        state,
+        connector,
+        merchant_account,
+        &refresh_token_router_data,
+    )
+    .await?
+    .async_map(|access_token| async {
+        metrics::ACCESS_TOKEN_REFRESHED.add(
+            &metrics::CONTEXT,
+            1,
+            &[opentelemetry::KeyValue::new(
+                "connector",
+                connector.connector.id().to_string(),
+            )],
+        );
+        //Store the access token in db
+        let store = &*state.store;
+        let ttl = get_access_token_cache_ttl(
+            access_token.expires,
+            state.conf.access_token.refresh_before_expiry_secs,
+        );
+        // This error should not be propagated, we don't want payments to fail once we have
+        // the access token, the next request will create new access token
+        let _ = store
+            .set_access_token(
+                merchant_id,
+                connector.connector.id(),
+                access_token.clone(),
+                ttl,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("DB error when setting the access token");
+        Some(access_token)
+    })
+    .await;
+
+    Ok(res)
+}
+
+/// Number of seconds for which the access token is cached, which expires the cached access token
+/// before the access token itself so that it is refreshed before it expires, unless the access
+/// token is too short lived to be refreshed early
+fn get_access_token_cache_ttl(expires: i64, refresh_before_expiry: i64) -> i64 {
+    if expires > refresh_before_expiry {
+        expires - refresh_before_expiry
+    } else {
+        expires
+    }
+}
+
 pub async fn refresh_connector_auth(
     state: &AppState,
     connector: &api_types::ConnectorData,
@@ -177,3 +328,15 @@ pub async fn refresh_connector_auth(
 
     Ok(access_token_router_data.response)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_token_cache_ttl() {
+        assert_eq!(get_access_token_cache_ttl(3600, 60), 3540);
+        assert_eq!(get_access_token_cache_ttl(30, 60), 30);
+        assert_eq!(get_access_token_cache_ttl(3600, 0), 3600);
+    }
+}
@@ -0,0 +1,59 @@
+use error_stack::ResultExt;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::{self, access_token},
+    },
+    routes::AppState,
+    services,
+    types::{self, api},
+};
+
+/// Creates a customer at the connector for the payment method of a mandate to be attached to.
+/// Returns `None` for connectors which do not maintain customers of their own, or do not need
+/// them to charge the payment method of a mandate again.
+pub async fn create_connector_customer<F: Clone, Req: Clone, Res: Clone>(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    router_data: &types::RouterData<F, Req, Res>,
+    customer_request_data: types::ConnectorCustomerData,
+) -> RouterResult<Option<String>> {
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::ConnectorCustomerResponseData,
+    > = connector.connector.get_connector_integration();
+
+    let customer_response_data: Result<types::ConnectorCustomerResponseData, types::ErrorResponse> =
+        Err(types::ErrorResponse::get_not_implemented());
+    let customer_router_data =
+        access_token::router_data_type_conversion::<_, api::CreateConnectorCustomer, _, _, _, _>(
+            router_data.clone(),
+            customer_request_data,
+            customer_response_data,
+        );
+
+    let resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &customer_router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while creating the customer at the connector")?;
+
+    match resp.response {
+        Ok(response) => Ok(Some(response.connector_customer_id)),
+        Err(error) if error.code == types::ErrorResponse::get_not_implemented().code => Ok(None),
+        Err(error) => Err(errors::ApiErrorResponse::ExternalConnectorError {
+            code: error.code,
+            message: error.message,
+            connector: connector.connector_name.to_string(),
+            status_code: error.status_code,
+        }
+        .into()),
+    }
+}
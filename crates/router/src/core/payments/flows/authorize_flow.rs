@@ -5,8 +5,9 @@ use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
         mandate,
-        payments::{self, access_token, transformers, PaymentData},
+        payments::{self, access_token, customers, transformers, PaymentData},
     },
+    logger,
     routes::AppState,
     scheduler::metrics,
     services,
@@ -97,14 +98,65 @@ impl types::PaymentsAuthorizeRouterData {
                     types::PaymentsAuthorizeData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+                // The payment method of a mandate is attached to a customer at the connector, for
+                // it to be charged again off session
+                let mut router_data = self.clone();
+                if self.request.setup_mandate_details.is_some()
+                    && matches!(
+                        call_connector_action,
+                        payments::CallConnectorAction::Trigger
+                    )
+                {
+                    router_data.request.connector_customer = customers::create_connector_customer(
+                        state,
+                        connector,
+                        self,
+                        types::ConnectorCustomerData {
+                            customer_id: self.request.customer_id.clone(),
+                            email: self.request.email.clone(),
+                            description: self.description.clone(),
+                        },
+                    )
+                    .await?;
+                }
+                let reserved_mandate = match self.request.mandate_id.as_ref() {
+                    Some(mandate_ids) => {
+                        mandate::reserve_mandate_usage(
+                            state,
+                            &self.merchant_id,
+                            &mandate_ids.mandate_id,
+                            self.request.amount,
+                        )
+                        .await?
+                    }
+                    None => None,
+                };
                 let resp = services::execute_connector_processing_step(
                     state,
                     connector_integration,
-                    self,
+                    &router_data,
                     call_connector_action,
                 )
-                .await
-                .map_err(|error| error.to_payment_failed_response())?;
+                .await;
+
+                if let Some(reserved_mandate) = reserved_mandate {
+                    let charge_failed = resp.as_ref().map_or(true, |resp| {
+                        resp.response.is_err()
+                            || resp.status == storage::enums::AttemptStatus::Failure
+                    });
+                    if charge_failed {
+                        if let Err(error) = mandate::release_mandate_usage(
+                            &*state.store,
+                            &reserved_mandate,
+                            self.request.amount,
+                        )
+                        .await
+                        {
+                            logger::error!(mandate_usage_error=?error);
+                        }
+                    }
+                }
+                let resp = resp.map_err(|error| error.to_payment_failed_response())?;
 
                 Ok(
                     mandate::mandate_procedure(state, resp, maybe_customer, merchant_account)
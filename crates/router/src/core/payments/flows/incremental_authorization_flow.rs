@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use error_stack::report;
+
+use super::{ConstructFlowSpecificData, Feature};
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult},
+        payments::{self, access_token, transformers, PaymentData},
+    },
+    routes::AppState,
+    services,
+    types::{self, api, storage},
+};
+
+#[async_trait]
+impl
+    ConstructFlowSpecificData<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for PaymentData<api::IncrementalAuthorization>
+{
+    async fn construct_router_data<'a>(
+        &self,
+        state: &AppState,
+        connector_id: &str,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<types::PaymentsIncrementalAuthorizationRouterData> {
+        transformers::construct_payment_router_data::<
+            api::IncrementalAuthorization,
+            types::PaymentsIncrementalAuthorizationData,
+        >(state, self.clone(), connector_id, merchant_account)
+        .await
+    }
+}
+
+#[async_trait]
+impl Feature<api::IncrementalAuthorization, types::PaymentsIncrementalAuthorizationData>
+    for types::RouterData<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >
+{
+    async fn decide_flows<'a>(
+        self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+        customer: &Option<storage::Customer>,
+        call_connector_action: payments::CallConnectorAction,
+        _merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<Self> {
+        self.decide_flow(
+            state,
+            connector,
+            customer,
+            Some(true),
+            call_connector_action,
+        )
+        .await
+    }
+
+    async fn add_access_token<'a>(
+        &self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<types::AddAccessTokenResult> {
+        access_token::add_access_token(state, connector, merchant_account, self).await
+    }
+}
+
+impl types::PaymentsIncrementalAuthorizationRouterData {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn decide_flow<'a, 'b>(
+        &'b self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+        _maybe_customer: &Option<storage::Customer>,
+        _confirm: Option<bool>,
+        call_connector_action: payments::CallConnectorAction,
+    ) -> RouterResult<Self> {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::IncrementalAuthorization,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        > = connector.connector.get_connector_integration();
+
+        // Connectors not supporting incremental authorizations do not build a request for it
+        let connector_request = connector_integration
+            .build_request(self, &state.conf.connectors)
+            .map_err(|error| error.to_payment_failed_response())?;
+        if connector_request.is_none() {
+            return Err(report!(errors::ConnectorError::NotImplemented(format!(
+                "Incremental authorization for {}",
+                connector.connector_name
+            )))
+            .to_payment_failed_response());
+        }
+
+        let resp = services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            self,
+            call_connector_action,
+        )
+        .await
+        .map_err(|error| error.to_payment_failed_response())?;
+
+        Ok(resp)
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
         mandate,
-        payments::{self, access_token, transformers, PaymentData},
+        payments::{self, access_token, customers, transformers, PaymentData},
     },
     routes::AppState,
     services,
@@ -81,10 +81,31 @@ impl types::VerifyRouterData {
                     types::VerifyRequestData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
+                // The payment method of a mandate is attached to a customer at the connector, for
+                // it to be charged again off session
+                let mut router_data = self.clone();
+                if self.request.setup_mandate_details.is_some()
+                    && matches!(
+                        call_connector_action,
+                        payments::CallConnectorAction::Trigger
+                    )
+                {
+                    router_data.request.connector_customer = customers::create_connector_customer(
+                        state,
+                        connector,
+                        self,
+                        types::ConnectorCustomerData {
+                            customer_id: self.request.customer_id.clone(),
+                            email: self.request.email.clone(),
+                            description: self.description.clone(),
+                        },
+                    )
+                    .await?;
+                }
                 let resp = services::execute_connector_processing_step(
                     state,
                     connector_integration,
-                    self,
+                    &router_data,
                     call_connector_action,
                 )
                 .await
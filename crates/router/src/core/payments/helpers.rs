@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, str::FromStr};
 
 use common_utils::{ext_traits::AsyncExt, fp_utils};
 // TODO : Evaluate all the helper functions ()
@@ -16,6 +16,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        mandate,
         payment_methods::{cards, vault},
     },
     db::StorageInterface,
@@ -23,6 +24,7 @@ use crate::{
     scheduler::{metrics, workflows::payment_sync},
     services,
     types::{
+        self,
         api::{self, enums as api_enums, CustomerAcceptanceExt, MandateValidationFieldsExt},
         storage::{self, enums as storage_enums, ephemeral_key},
         transformers::ForeignInto,
@@ -30,7 +32,7 @@ use crate::{
     utils::{
         self,
         crypto::{self, SignMessage},
-        OptionExt,
+        OptionExt, ValueExt,
     },
 };
 
@@ -247,6 +249,28 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+/// Scheduled payments are captured at `capture_on`, which has to be in the future
+#[instrument(skip_all)]
+pub fn validate_capture_on(
+    capture_method: Option<api_enums::CaptureMethod>,
+    capture_on: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if capture_method != Some(api_enums::CaptureMethod::Scheduled) {
+        return Ok(());
+    }
+
+    let capture_on = capture_on.get_required_value("capture_on").change_context(
+        errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "capture_on",
+        },
+    )?;
+    utils::when(capture_on <= common_utils::date_time::now(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_on should be a time in the future for scheduled payments".to_string()
+        }))
+    })
+}
+
 pub fn validate_mandate(
     req: impl Into<api::MandateValidationFields>,
 ) -> RouterResult<Option<api::MandateTxnType>> {
@@ -300,6 +324,41 @@ fn validate_new_mandate_request(req: api::MandateValidationFields) -> RouterResu
         }))?
     }
 
+    let mandate_amount_data = match &mandate_data.mandate_type {
+        api::MandateType::SingleUse(mandate_amount_data) => Some(mandate_amount_data),
+        api::MandateType::MultiUse(mandate_amount_data) => mandate_amount_data.as_ref(),
+    };
+    if let Some(mandate_amount_data) = mandate_amount_data {
+        validate_mandate_amount_data(mandate_amount_data)?;
+    }
+
+    Ok(())
+}
+
+fn validate_mandate_amount_data(mandate_amount_data: &api::MandateAmountData) -> RouterResult<()> {
+    if let (Some(start_date), Some(end_date)) =
+        (mandate_amount_data.start_date, mandate_amount_data.end_date)
+    {
+        utils::when(end_date <= start_date, || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`end_date` of the mandate must be later than its `start_date`".into()
+            }))
+        })?;
+    }
+    if let Some(end_date) = mandate_amount_data.end_date {
+        utils::when(end_date <= common_utils::date_time::now(), || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`end_date` of the mandate must be a time in the future".into()
+            }))
+        })?;
+    }
+    if let Some(frequency) = mandate_amount_data.frequency {
+        utils::when(frequency.max_charges <= 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "max_charges"
+            }))
+        })?;
+    }
     Ok(())
 }
 
@@ -341,6 +400,16 @@ pub fn create_startpay_url(
     )
 }
 
+pub fn create_three_ds_authentication_url(
+    server: &Server,
+    payment_attempt: &storage::PaymentAttempt,
+) -> String {
+    format!(
+        "{}/payments/{}/3ds/authenticate",
+        server.base_url, payment_attempt.payment_id
+    )
+}
+
 pub fn create_redirect_url(
     server: &Server,
     payment_attempt: &storage::PaymentAttempt,
@@ -378,32 +447,12 @@ pub fn verify_mandate_details(
     request_currency: api_enums::Currency,
     mandate: storage::Mandate,
 ) -> RouterResult<()> {
-    match mandate.mandate_type {
-        storage_enums::MandateType::SingleUse => utils::when(
-            mandate
-                .mandate_amount
-                .map(|mandate_amount| request_amount > mandate_amount)
-                .unwrap_or(true),
-            || {
-                Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
-                    reason: "request amount is greater than mandate amount".to_string()
-                }))
-            },
-        ),
-        storage::enums::MandateType::MultiUse => utils::when(
-            mandate
-                .mandate_amount
-                .map(|mandate_amount| {
-                    (mandate.amount_captured.unwrap_or(0) + request_amount) > mandate_amount
-                })
-                .unwrap_or(false),
-            || {
-                Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
-                    reason: "request amount is greater than mandate amount".to_string()
-                }))
-            },
-        ),
-    }?;
+    // The usage is only checked here, it is reserved once the payment is confirmed
+    mandate::get_mandate_usage_after_charge(
+        &mandate,
+        request_amount,
+        common_utils::date_time::now(),
+    )?;
     utils::when(
         mandate
             .mandate_currency
@@ -469,17 +518,20 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed while getting process schedule time")?;
 
-        match schedule_time {
-            Some(stime) => {
-                metrics::TASKS_ADDED_COUNT.add(&metrics::CONTEXT, 1, &[]); // Metrics
-                super::add_process_sync_task(&*state.store, payment_attempt, stime)
-                    .await
-                    .into_report()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed while adding task to process tracker")
-            }
-            None => Ok(()),
+        if let Some(stime) = schedule_time {
+            metrics::TASKS_ADDED_COUNT.add(&metrics::CONTEXT, 1, &[]); // Metrics
+            super::add_process_sync_task(&*state.store, payment_attempt, stime)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding task to process tracker")?;
         }
+
+        super::add_scheduled_capture_task(&*state.store, payment_attempt)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while adding scheduled capture task to process tracker")
     } else {
         Ok(())
     }
@@ -610,6 +662,71 @@ pub async fn get_connector_default(
     }
 }
 
+/// Key of the config naming the 3DS server connector which authenticates the customers of the
+/// merchant, for payments authenticated by the router
+pub fn get_three_ds_connector_config_key(merchant_id: &str) -> String {
+    format!("three_ds_connector_{merchant_id}")
+}
+
+pub async fn get_three_ds_connector(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+    let key = get_three_ds_connector_config_key(merchant_id);
+    let connector_name = state
+        .store
+        .find_config_by_key_cached(&key)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+                message: "3DS authentication is not enabled for the merchant".to_string(),
+            })
+        })?
+        .config;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+    )
+    .attach_printable("Invalid 3DS server connector configured for the merchant")?;
+    Ok(api::ConnectorCallType::Single(connector_data))
+}
+
+/// 3DS authentication of the customer performed by the router for the payment attempt, if any
+pub fn get_three_ds_authentication(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<types::ThreeDsAuthentication>> {
+    payment_attempt
+        .three_ds_authentication
+        .clone()
+        .map(|authentication| authentication.parse_value("ThreeDsAuthentication"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the 3DS authentication of the payment attempt")
+}
+
+/// Payments authenticated by the router can only be authorized with connectors which accept the
+/// result of the authentication, authorizing them without 3DS elsewhere would lose the liability
+/// shift
+pub fn validate_three_ds_authentication_connector(
+    payment_attempt: &storage::PaymentAttempt,
+    connector_name: &str,
+) -> RouterResult<()> {
+    let is_authenticated = get_three_ds_authentication(payment_attempt)?
+        .map_or(false, |authentication| {
+            authentication.status == types::ThreeDsAuthenticationStatus::Succeeded
+        });
+    let supports_external_three_ds = api_enums::Connector::from_str(connector_name)
+        .map_or(false, |connector| connector.supports_external_three_ds());
+    utils::when(is_authenticated && !supports_external_three_ds, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "connector {connector_name} does not support payments authenticated by the 3DS server"
+            ),
+        }))
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     operation: BoxedOperation<'a, F, R>,
@@ -821,6 +938,145 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Whether the capture is the last capture of the payment. Only payments with the
+/// `manual_multiple` capture method can be captured more than once, and any of their captures
+/// is final once it captures the entire amount left to be captured.
+#[instrument(skip_all)]
+pub(crate) fn is_final_capture(
+    capture_method: storage_enums::CaptureMethod,
+    final_capture: Option<bool>,
+    amount_to_capture: i64,
+    amount_capturable: i64,
+) -> RouterResult<bool> {
+    let is_multiple_capture = capture_method == storage_enums::CaptureMethod::ManualMultiple;
+    utils::when(!is_multiple_capture && final_capture == Some(false), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "final_capture can be false only for the manual_multiple capture method"
+                .to_string()
+        }))
+    })?;
+    Ok(!is_multiple_capture
+        || final_capture.unwrap_or(false)
+        || amount_to_capture >= amount_capturable)
+}
+
+/// Validates a capture against the other captures of the payment, which serializes the
+/// captures of a payment through the captures table. The capture is rejected while another
+/// capture of the payment is in progress, after a final capture and when it would capture more
+/// than the amount left uncaptured.
+#[instrument(skip_all)]
+pub(crate) fn validate_concurrent_captures(
+    captures: &[storage::Capture],
+    capture: &storage::Capture,
+    amount: i64,
+) -> RouterResult<()> {
+    let other_captures = || {
+        captures
+            .iter()
+            .filter(|other| other.capture_id != capture.capture_id)
+    };
+    utils::when(
+        other_captures().any(|other| {
+            matches!(
+                other.status,
+                storage_enums::CaptureStatus::Started | storage_enums::CaptureStatus::Pending
+            )
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Another capture of this payment is in progress, retry once it completes"
+                    .to_string()
+            }))
+        },
+    )?;
+    utils::when(
+        other_captures().any(|other| {
+            other.status == storage_enums::CaptureStatus::Charged && other.final_capture
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The final capture of this payment has already been made".to_string()
+            }))
+        },
+    )?;
+    validate_amount_to_capture(
+        amount - get_amount_captured(captures, 0, &capture.capture_id),
+        Some(capture.amount),
+    )
+}
+
+/// Amount captured of the payment once the capture succeeds: the amounts charged by the other
+/// captures of the payment along with the amount of the capture.
+pub(crate) fn get_amount_captured(
+    captures: &[storage::Capture],
+    amount_to_capture: i64,
+    capture_id: &str,
+) -> i64 {
+    captures
+        .iter()
+        .filter(|other| {
+            other.capture_id != capture_id && other.status == storage_enums::CaptureStatus::Charged
+        })
+        .map(|other| other.amount)
+        .sum::<i64>()
+        + amount_to_capture
+}
+
+/// Status of the attempt once a capture of the payment is charged
+pub(crate) fn get_captured_attempt_status(
+    final_capture: bool,
+    amount_captured: i64,
+    amount: i64,
+) -> storage_enums::AttemptStatus {
+    if final_capture || amount_captured >= amount {
+        storage_enums::AttemptStatus::Charged
+    } else {
+        storage_enums::AttemptStatus::PartialCharged
+    }
+}
+
+/// Status of a capture the connector reported pending, once the connector has synced the
+/// payment. The capture is charged once the connector has captured the amount charged by the
+/// other captures of the payment along with the amount of the capture.
+pub(crate) fn get_pending_capture_status(
+    amount_captured: i64,
+    capture: &storage::Capture,
+    attempt_status: storage_enums::AttemptStatus,
+    connector_amount_captured: Option<i64>,
+) -> storage_enums::CaptureStatus {
+    match attempt_status {
+        storage_enums::AttemptStatus::CaptureFailed | storage_enums::AttemptStatus::Failure => {
+            storage_enums::CaptureStatus::Failed
+        }
+        storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::PartialCharged => {
+            match connector_amount_captured {
+                Some(connector_amount_captured)
+                    if connector_amount_captured < amount_captured + capture.amount =>
+                {
+                    storage_enums::CaptureStatus::Pending
+                }
+                _ => storage_enums::CaptureStatus::Charged,
+            }
+        }
+        _ => storage_enums::CaptureStatus::Pending,
+    }
+}
+
+/// Status of the payment for the status of its attempt. Payments captured partially with the
+/// `manual_multiple` capture method can be captured further.
+pub(crate) fn get_intent_status(
+    attempt_status: storage_enums::AttemptStatus,
+    capture_method: Option<storage_enums::CaptureMethod>,
+) -> storage_enums::IntentStatus {
+    match (attempt_status, capture_method) {
+        (
+            storage_enums::AttemptStatus::PartialCharged,
+            Some(storage_enums::CaptureMethod::ManualMultiple),
+        ) => storage_enums::IntentStatus::RequiresCapture,
+        (attempt_status, _) => attempt_status.foreign_into(),
+    }
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -856,6 +1112,7 @@ pub fn can_call_connector(status: &storage_enums::AttemptStatus) -> bool {
             | storage_enums::AttemptStatus::Voided
             | storage_enums::AttemptStatus::CodInitiated
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartialCharged
             | storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::Failure
     )
@@ -882,6 +1139,26 @@ pub(super) async fn filter_by_constraints(
 }
 
 #[cfg(feature = "olap")]
+pub(super) async fn count_by_constraints(
+    db: &dyn StorageInterface,
+    constraints: &api::PaymentListConstraints,
+    merchant_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> CustomResult<i64, errors::StorageError> {
+    db.count_payment_intents_by_constraints(merchant_id, constraints, storage_scheme)
+        .await
+}
+
+#[cfg(feature = "olap")]
+/// The new amount of the payment on an incremental authorization, the amounts being updated only
+/// once the connector has authorized the increment
+pub fn get_incremental_authorization_amount(
+    attempt_status: storage_enums::AttemptStatus,
+    request: &types::PaymentsIncrementalAuthorizationData,
+) -> Option<i64> {
+    (attempt_status == storage_enums::AttemptStatus::Authorized).then_some(request.total_amount)
+}
+
 pub(super) fn validate_payment_list_request(
     req: &api::PaymentListConstraints,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
@@ -890,6 +1167,23 @@ pub(super) fn validate_payment_list_request(
             message: "limit should be in between 1 and 100".to_string(),
         })
     })?;
+    utils::when(
+        req.starting_after.is_some() && req.ending_before.is_some(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "only one of starting_after and ending_before can be specified"
+                    .to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        matches!((req.amount_gte, req.amount_lte), (Some(gte), Some(lte)) if gte > lte),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount.gte should not be greater than amount.lte".to_string(),
+            })
+        },
+    )?;
     Ok(())
 }
 
@@ -1114,13 +1408,24 @@ pub fn generate_mandate(
                 api::MandateType::SingleUse(data) => new_mandate
                     .set_mandate_amount(Some(data.amount))
                     .set_mandate_currency(Some(data.currency.foreign_into()))
+                    .set_start_date(data.start_date)
+                    .set_end_date(data.end_date)
                     .set_mandate_type(storage_enums::MandateType::SingleUse)
                     .to_owned(),
 
                 api::MandateType::MultiUse(op_data) => match op_data {
                     Some(data) => new_mandate
                         .set_mandate_amount(Some(data.amount))
-                        .set_mandate_currency(Some(data.currency.foreign_into())),
+                        .set_mandate_currency(Some(data.currency.foreign_into()))
+                        .set_start_date(data.start_date)
+                        .set_end_date(data.end_date)
+                        .set_frequency_max_charges(
+                            data.frequency.map(|frequency| frequency.max_charges),
+                        )
+                        .set_frequency_period(
+                            data.frequency
+                                .map(|frequency| frequency.period.foreign_into()),
+                        ),
                     None => &mut new_mandate,
                 }
                 .set_mandate_type(storage_enums::MandateType::MultiUse)
@@ -1214,10 +1519,242 @@ pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> String {
 mod tests {
     use super::*;
 
+    #[allow(clippy::unwrap_used)]
+    fn get_payment_list_constraints(constraints: serde_json::Value) -> api::PaymentListConstraints {
+        serde_json::from_value(constraints).unwrap()
+    }
+
+    #[test]
+    fn test_validate_payment_list_request() {
+        let valid_constraints = [
+            serde_json::json!({}),
+            serde_json::json!({ "limit": 100, "starting_after": "pay_1" }),
+            serde_json::json!({ "limit": 1, "ending_before": "pay_1" }),
+            serde_json::json!({ "amount.gte": 100, "amount.lte": 100 }),
+            serde_json::json!({ "amount.gte": 100 }),
+            serde_json::json!({ "status": "succeeded", "connector": "stripe", "currency": "USD" }),
+        ];
+        for constraints in valid_constraints {
+            let constraints = get_payment_list_constraints(constraints);
+            assert!(validate_payment_list_request(&constraints).is_ok());
+        }
+
+        let invalid_constraints = [
+            serde_json::json!({ "limit": 0 }),
+            serde_json::json!({ "limit": 101 }),
+            // Only one of the cursors can be used to paginate
+            serde_json::json!({ "starting_after": "pay_1", "ending_before": "pay_2" }),
+            serde_json::json!({ "amount.gte": 200, "amount.lte": 100 }),
+        ];
+        for constraints in invalid_constraints {
+            let constraints = get_payment_list_constraints(constraints);
+            assert!(validate_payment_list_request(&constraints).is_err());
+        }
+    }
+
+    #[test]
+    fn test_payment_list_constraints_unknown_filter() {
+        let constraints: Result<api::PaymentListConstraints, _> =
+            serde_json::from_value(serde_json::json!({ "amount": 100 }));
+        assert!(constraints.is_err());
+    }
+
+    #[test]
+    fn test_get_incremental_authorization_amount() {
+        let request = types::PaymentsIncrementalAuthorizationData {
+            total_amount: 1500,
+            additional_amount: 500,
+            currency: storage_enums::Currency::USD,
+            connector_transaction_id: "connector_transaction_id".to_string(),
+        };
+        assert_eq!(
+            get_incremental_authorization_amount(
+                storage_enums::AttemptStatus::Authorized,
+                &request
+            ),
+            Some(1500)
+        );
+        // The amount is left unchanged until the connector authorizes the increment
+        for attempt_status in [
+            storage_enums::AttemptStatus::Pending,
+            storage_enums::AttemptStatus::Failure,
+            storage_enums::AttemptStatus::AuthorizationFailed,
+        ] {
+            assert_eq!(
+                get_incremental_authorization_amount(attempt_status, &request),
+                None
+            );
+        }
+    }
+
     #[test]
     fn test_authenticate_client_secret() {
         let req_cs = Some("1".to_string());
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    fn get_capture(
+        capture_id: &str,
+        status: storage_enums::CaptureStatus,
+        amount: i64,
+        final_capture: bool,
+    ) -> storage::Capture {
+        storage::Capture {
+            id: 0,
+            capture_id: capture_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            status,
+            amount,
+            currency: storage_enums::Currency::USD,
+            connector: "adyen".to_string(),
+            final_capture,
+            error_code: None,
+            error_message: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_validate_concurrent_captures() {
+        let capture = get_capture("cap_2", storage_enums::CaptureStatus::Started, 400, false);
+        let charged = get_capture("cap_1", storage_enums::CaptureStatus::Charged, 500, false);
+        let failed = get_capture("cap_0", storage_enums::CaptureStatus::Failed, 1000, false);
+
+        let captures = [failed.clone(), charged.clone(), capture.clone()];
+        assert!(validate_concurrent_captures(&captures, &capture, 1000).is_ok());
+        // The charged captures and the capture exceed the amount of the payment
+        assert!(validate_concurrent_captures(&captures, &capture, 800).is_err());
+
+        for status in [
+            storage_enums::CaptureStatus::Started,
+            storage_enums::CaptureStatus::Pending,
+        ] {
+            let in_progress = get_capture("cap_1", status, 100, false);
+            let captures = [in_progress, capture.clone()];
+            assert!(validate_concurrent_captures(&captures, &capture, 1000).is_err());
+        }
+
+        let final_charged = get_capture("cap_1", storage_enums::CaptureStatus::Charged, 100, true);
+        let captures = [final_charged, capture.clone()];
+        assert!(validate_concurrent_captures(&captures, &capture, 1000).is_err());
+    }
+
+    #[test]
+    fn test_get_amount_captured() {
+        let capture = get_capture("cap_3", storage_enums::CaptureStatus::Started, 300, false);
+        let captures = [
+            get_capture("cap_1", storage_enums::CaptureStatus::Charged, 100, false),
+            get_capture("cap_2", storage_enums::CaptureStatus::Failed, 200, false),
+            capture.clone(),
+        ];
+        assert_eq!(get_amount_captured(&captures, 0, &capture.capture_id), 100);
+        assert_eq!(
+            get_amount_captured(&captures, capture.amount, &capture.capture_id),
+            400
+        );
+        assert_eq!(
+            get_captured_attempt_status(false, 400, 1000),
+            storage_enums::AttemptStatus::PartialCharged
+        );
+        assert_eq!(
+            get_captured_attempt_status(true, 400, 1000),
+            storage_enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_captured_attempt_status(false, 1000, 1000),
+            storage_enums::AttemptStatus::Charged
+        );
+    }
+
+    #[test]
+    fn test_get_pending_capture_status() {
+        let pending = get_capture("cap_2", storage_enums::CaptureStatus::Pending, 300, false);
+        assert_eq!(
+            get_pending_capture_status(
+                100,
+                &pending,
+                storage_enums::AttemptStatus::CaptureInitiated,
+                None
+            ),
+            storage_enums::CaptureStatus::Pending
+        );
+        // The connector has not yet captured the amount of the capture
+        assert_eq!(
+            get_pending_capture_status(
+                100,
+                &pending,
+                storage_enums::AttemptStatus::PartialCharged,
+                Some(100)
+            ),
+            storage_enums::CaptureStatus::Pending
+        );
+        assert_eq!(
+            get_pending_capture_status(
+                100,
+                &pending,
+                storage_enums::AttemptStatus::PartialCharged,
+                Some(400)
+            ),
+            storage_enums::CaptureStatus::Charged
+        );
+        assert_eq!(
+            get_pending_capture_status(100, &pending, storage_enums::AttemptStatus::Charged, None),
+            storage_enums::CaptureStatus::Charged
+        );
+        assert_eq!(
+            get_pending_capture_status(
+                100,
+                &pending,
+                storage_enums::AttemptStatus::CaptureFailed,
+                None
+            ),
+            storage_enums::CaptureStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_get_intent_status() {
+        let partial_charged = storage_enums::AttemptStatus::PartialCharged;
+        assert_eq!(
+            get_intent_status(
+                partial_charged,
+                Some(storage_enums::CaptureMethod::ManualMultiple)
+            ),
+            storage_enums::IntentStatus::RequiresCapture
+        );
+        assert_eq!(
+            get_intent_status(
+                partial_charged,
+                Some(storage_enums::CaptureMethod::Automatic)
+            ),
+            storage_enums::IntentStatus::Processing
+        );
+        assert_eq!(
+            get_intent_status(partial_charged, None),
+            storage_enums::IntentStatus::Processing
+        );
+        assert_eq!(
+            get_intent_status(
+                storage_enums::AttemptStatus::Charged,
+                Some(storage_enums::CaptureMethod::ManualMultiple)
+            ),
+            storage_enums::IntentStatus::Succeeded
+        );
+    }
+
+    #[test]
+    fn test_is_final_capture() {
+        let manual_multiple = storage_enums::CaptureMethod::ManualMultiple;
+        assert!(!is_final_capture(manual_multiple, None, 50, 100).unwrap_or(true));
+        assert!(is_final_capture(manual_multiple, Some(true), 50, 100).unwrap_or(false));
+        assert!(is_final_capture(manual_multiple, Some(false), 100, 100).unwrap_or(false));
+
+        let manual = storage_enums::CaptureMethod::Manual;
+        assert!(is_final_capture(manual, None, 50, 100).unwrap_or(false));
+        assert!(is_final_capture(manual, Some(false), 50, 100).is_err());
+    }
 }
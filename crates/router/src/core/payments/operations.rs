@@ -1,8 +1,11 @@
+pub mod payment_authenticate;
 pub mod payment_cancel;
 pub mod payment_capture;
 pub mod payment_confirm;
 pub mod payment_create;
+pub mod payment_incremental_authorization;
 pub mod payment_method_validate;
+pub mod payment_pre_authenticate;
 pub mod payment_response;
 pub mod payment_session;
 pub mod payment_start;
@@ -14,9 +17,12 @@ use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 pub use self::{
-    payment_cancel::PaymentCancel, payment_capture::PaymentCapture,
-    payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
-    payment_method_validate::PaymentMethodValidate, payment_response::PaymentResponse,
+    payment_authenticate::PaymentAuthenticate, payment_cancel::PaymentCancel,
+    payment_capture::PaymentCapture, payment_confirm::PaymentConfirm,
+    payment_create::PaymentCreate,
+    payment_incremental_authorization::PaymentIncrementalAuthorization,
+    payment_method_validate::PaymentMethodValidate,
+    payment_pre_authenticate::PaymentPreAuthenticate, payment_response::PaymentResponse,
     payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
     payment_update::PaymentUpdate,
 };
@@ -320,3 +326,174 @@ where
         helpers::get_connector_default(state, previously_used_connector).await
     }
 }
+
+#[async_trait]
+impl<
+        F: Clone + Send,
+        Op: Send + Sync + Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+    > Domain<F, api::PaymentsIncrementalAuthorizationRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_id: &str,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+            Option<storage::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                merchant_id,
+            )
+            .await?,
+        ))
+    }
+
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        Option<api::PaymentMethod>,
+    )> {
+        Ok((Box::new(self), None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &storage::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsIncrementalAuthorizationRequest,
+        previously_used_connector: Option<&String>,
+    ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, previously_used_connector).await
+    }
+}
+
+#[async_trait]
+impl<F: Clone + Send, Op: Send + Sync + Operation<F, api::PaymentsPreAuthenticateRequest>>
+    Domain<F, api::PaymentsPreAuthenticateRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsPreAuthenticateRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_id: &str,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsPreAuthenticateRequest>,
+            Option<storage::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                merchant_id,
+            )
+            .await?,
+        ))
+    }
+
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsPreAuthenticateRequest>,
+        Option<api::PaymentMethod>,
+    )> {
+        helpers::make_pm_data(Box::new(self), state, payment_data).await
+    }
+
+    // The customer is authenticated by the 3DS server connector of the merchant, irrespective of
+    // the connector authorizing the payment
+    async fn get_connector<'a>(
+        &'a self,
+        merchant_account: &storage::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsPreAuthenticateRequest,
+        _previously_used_connector: Option<&String>,
+    ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+        helpers::get_three_ds_connector(state, &merchant_account.merchant_id).await
+    }
+}
+
+#[async_trait]
+impl<F: Clone + Send, Op: Send + Sync + Operation<F, api::PaymentsAuthenticateRequest>>
+    Domain<F, api::PaymentsAuthenticateRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsAuthenticateRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_id: &str,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsAuthenticateRequest>,
+            Option<storage::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                merchant_id,
+            )
+            .await?,
+        ))
+    }
+
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsAuthenticateRequest>,
+        Option<api::PaymentMethod>,
+    )> {
+        helpers::make_pm_data(Box::new(self), state, payment_data).await
+    }
+
+    // The customer is authenticated by the 3DS server connector of the merchant, irrespective of
+    // the connector authorizing the payment
+    async fn get_connector<'a>(
+        &'a self,
+        merchant_account: &storage::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsAuthenticateRequest,
+        _previously_used_connector: Option<&String>,
+    ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+        helpers::get_three_ds_connector(state, &merchant_account.merchant_id).await
+    }
+}
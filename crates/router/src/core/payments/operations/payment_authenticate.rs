@@ -0,0 +1,211 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+use router_derive;
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        self,
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums, Customer},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "authenticate")]
+pub struct PaymentAuthenticate;
+
+#[async_trait]
+impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsAuthenticateRequest>
+    for PaymentAuthenticate
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsAuthenticateRequest,
+        _mandate_type: Option<api::MandateTxnType>,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsAuthenticateRequest>,
+        PaymentData<F>,
+        Option<CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        helpers::authenticate_client_secret(
+            request.client_secret.as_ref(),
+            payment_intent.client_secret.as_ref(),
+        )?;
+
+        let mut payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id(
+                &payment_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        let authentication = helpers::get_three_ds_authentication(&payment_attempt)?;
+        match authentication.map(|authentication| authentication.status) {
+            Some(types::ThreeDsAuthenticationStatus::Pending) => {}
+            Some(types::ThreeDsAuthenticationStatus::ChallengeRequired) => {
+                if request.challenge_response.is_none() {
+                    Err(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "challenge_response",
+                    })?
+                }
+            }
+            _ => Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The customer of this payment is not being authenticated with 3DS"
+                    .to_string(),
+            })?,
+        }
+
+        let browser_info = request
+            .browser_info
+            .clone()
+            .map(|x| utils::Encode::<types::BrowserInformation>::encode_to_value(&x))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "browser_info",
+            })?;
+        payment_attempt.browser_info = browser_info.or(payment_attempt.browser_info);
+
+        let shipping_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+        let billing_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        let amount = payment_attempt.amount.into();
+        // The card authenticated by the pre authentication is retrieved from the vault
+        let token = payment_attempt.payment_token.clone();
+
+        Ok((
+            Box::new(self),
+            PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                amount,
+                email: None,
+                mandate_id: None,
+                setup_mandate: None,
+                token,
+                address: PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
+                    billing: billing_address.as_ref().map(|a| a.foreign_into()),
+                },
+                confirm: None,
+                payment_method_data: None,
+                force_sync: None,
+                refunds: vec![],
+                attempts: None,
+                capture: None,
+                connector_response,
+                sessions_token: vec![],
+                three_ds_authenticate_request: Some(request.clone()),
+                card_cvc: None,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsAuthenticateRequest>
+    for PaymentAuthenticate
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        _customer: Option<Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsAuthenticateRequest>,
+        PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        // The payment is updated with the outcome of the authentication from the 3DS server
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsAuthenticateRequest> for PaymentAuthenticate {
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsAuthenticateRequest,
+        merchant_account: &'a storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsAuthenticateRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+            },
+        ))
+    }
+}
@@ -126,8 +126,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     payment_method_data: None,
                     force_sync: None,
                     refunds: vec![],
+                    attempts: None,
+                    capture: None,
                     connector_response,
                     sessions_token: vec![],
+                    three_ds_authenticate_request: None,
                     card_cvc: None,
                 },
                 None,
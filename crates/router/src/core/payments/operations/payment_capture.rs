@@ -6,6 +6,7 @@ use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations},
@@ -17,7 +18,7 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -59,7 +60,10 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_status(payment_intent.status)?;
 
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
+        // Payments captured partially can be captured further, up to the amount left uncaptured
+        let amount_capturable = payment_intent.amount - payment_intent.amount_captured.unwrap_or(0);
+        helpers::validate_amount_to_capture(amount_capturable, request.amount_to_capture)?;
+        let amount_to_capture = request.amount_to_capture.unwrap_or(amount_capturable);
 
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id(
@@ -72,9 +76,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
+        payment_attempt.amount_to_capture = Some(amount_to_capture);
 
         let capture_method = payment_attempt
             .capture_method
@@ -82,8 +84,61 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_capture_method(capture_method)?;
 
+        let final_capture = helpers::is_final_capture(
+            capture_method,
+            request.final_capture,
+            amount_to_capture,
+            amount_capturable,
+        )?;
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
+        let capture_new = storage::CaptureNew {
+            capture_id: utils::generate_id(consts::ID_LENGTH, "cap"),
+            payment_id: payment_attempt.payment_id.clone(),
+            merchant_id: payment_attempt.merchant_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            status: enums::CaptureStatus::Started,
+            amount: amount_to_capture,
+            currency,
+            connector: payment_attempt
+                .connector
+                .clone()
+                .get_required_value("connector")?,
+            final_capture,
+            created_at: Some(common_utils::date_time::now()),
+            modified_at: Some(common_utils::date_time::now()),
+        };
+        let capture = db.insert_capture(capture_new).await.map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_id.clone(),
+            })
+        })?;
+
+        // Captures of a payment are serialized through the captures table, so that captures
+        // made concurrently cannot capture more than the amount of the payment
+        let captures = db
+            .find_captures_by_merchant_id_payment_id(merchant_id, &payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find captures of the payment")?;
+        if let Err(error) =
+            helpers::validate_concurrent_captures(&captures, &capture, payment_intent.amount)
+        {
+            db.update_capture(
+                capture,
+                storage::CaptureUpdate::ErrorUpdate {
+                    status: enums::CaptureStatus::Failed,
+                    error_code: Some(error.current_context().error_code()),
+                    error_message: Some(error.current_context().error_message()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update capture")?;
+            return Err(error);
+        }
+
         amount = payment_attempt.amount.into();
 
         let connector_response = db
@@ -136,8 +191,11 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 confirm: None,
                 payment_method_data: None,
                 refunds: vec![],
+                attempts: None,
+                capture: Some(capture),
                 connector_response,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 card_cvc: None,
             },
             None,
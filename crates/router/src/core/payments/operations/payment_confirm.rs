@@ -101,6 +101,27 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
+        // Payments for which the router is authenticating the customer are confirmed only once
+        // the customer is authenticated
+        if let Some(authentication) = helpers::get_three_ds_authentication(&payment_attempt)? {
+            if authentication.status != types::ThreeDsAuthenticationStatus::Succeeded {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The customer of this payment is not authenticated with 3DS yet"
+                        .to_string(),
+                })?
+            }
+        }
+
+        // The capture time set on creation may have passed by the time the payment is confirmed
+        helpers::validate_capture_on(
+            request.capture_method.or_else(|| {
+                payment_attempt
+                    .capture_method
+                    .map(ForeignInto::foreign_into)
+            }),
+            request.capture_on.or(payment_attempt.capture_on),
+        )?;
+
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
         helpers::validate_pm_or_token_given(
@@ -179,7 +200,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                attempts: None,
+                capture: None,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 card_cvc: request.card_cvc.clone(),
             },
             Some(CustomerDetails {
@@ -283,12 +307,23 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let payment_method = payment_data.payment_attempt.payment_method;
         let browser_info = payment_data.payment_attempt.browser_info.clone();
 
+        // Customers authenticated by the router are not authenticated again by the connector
+        let authenticated_by_router = helpers::get_three_ds_authentication(
+            &payment_data.payment_attempt,
+        )?
+        .map_or(false, |authentication| {
+            authentication.status == types::ThreeDsAuthenticationStatus::Succeeded
+        });
         let (intent_status, attempt_status) = match payment_data.payment_attempt.authentication_type
         {
             Some(storage_enums::AuthenticationType::NoThreeDs) => (
                 storage_enums::IntentStatus::Processing,
                 storage_enums::AttemptStatus::Pending,
             ),
+            _ if authenticated_by_router => (
+                storage_enums::IntentStatus::Processing,
+                storage_enums::AttemptStatus::Pending,
+            ),
             _ => (
                 storage_enums::IntentStatus::RequiresCustomerAction,
                 storage_enums::AttemptStatus::AuthenticationPending,
@@ -377,6 +412,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_capture_on(request.capture_method, request.capture_on)?;
+
         let mandate_type = helpers::validate_mandate(request)?;
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
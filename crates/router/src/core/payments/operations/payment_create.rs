@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use async_trait::async_trait;
 use common_utils::ext_traits::{AsyncExt, Encode};
-use error_stack::{self, ResultExt};
+use error_stack::{self, IntoReport, ResultExt};
 use router_derive::PaymentOperation;
 use router_env::{instrument, tracing};
 use uuid::Uuid;
@@ -188,9 +188,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 confirm: request.confirm,
                 payment_method_data: request.payment_method_data.clone(),
                 refunds: vec![],
+                attempts: None,
+                capture: None,
                 force_sync: None,
                 connector_response,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 card_cvc: request.card_cvc.clone(),
             },
             Some(CustomerDetails {
@@ -246,9 +249,19 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
     #[instrument(skip_all)]
     async fn add_task_to_process_tracker<'a>(
         &'a self,
-        _state: &'a AppState,
-        _payment_attempt: &storage::PaymentAttempt,
+        state: &'a AppState,
+        payment_attempt: &storage::PaymentAttempt,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
+        // Payments confirmed on creation are captured at `capture_on` like confirmed payments
+        if payment_attempt.confirm {
+            payments::add_scheduled_capture_task(&*state.store, payment_attempt)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while adding scheduled capture task to process tracker",
+                )?;
+        }
         Ok(())
     }
 
@@ -369,6 +382,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
             expected_format: "amount_to_capture lesser than amount".to_string(),
         })?;
 
+        helpers::validate_capture_on(request.capture_method, request.capture_on)?;
+
         helpers::validate_payment_method_fields_present(request)?;
 
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
@@ -149,7 +149,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 address: types::PaymentAddress::default(),
                 force_sync: None,
                 refunds: vec![],
+                attempts: None,
+                capture: None,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 card_cvc: None,
             },
             Some(payments::CustomerDetails {
@@ -0,0 +1,229 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+use router_derive;
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums, Customer},
+        transformers::ForeignInto,
+    },
+    utils::OptionExt,
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "pre_authenticate")]
+pub struct PaymentPreAuthenticate;
+
+#[async_trait]
+impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsPreAuthenticateRequest>
+    for PaymentPreAuthenticate
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsPreAuthenticateRequest,
+        _mandate_type: Option<api::MandateTxnType>,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsPreAuthenticateRequest>,
+        PaymentData<F>,
+        Option<CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        helpers::authenticate_client_secret(
+            request.client_secret.as_ref(),
+            payment_intent.client_secret.as_ref(),
+        )?;
+
+        // A new authentication can be started with another card, until the payment is confirmed
+        if !matches!(
+            payment_intent.status,
+            enums::IntentStatus::RequiresPaymentMethod
+                | enums::IntentStatus::RequiresConfirmation
+                | enums::IntentStatus::RequiresCustomerAction
+        ) {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot authenticate the customer of this payment because it has status {}",
+                    payment_intent.status
+                ),
+            })?
+        }
+
+        let mut payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id(
+                &payment_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        if payment_attempt.authentication_type != Some(enums::AuthenticationType::ThreeDs) {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The customer is authenticated only for payments with authentication_type three_ds".to_string(),
+            })?
+        }
+
+        // Fail before the customer is authenticated, when the payment cannot be authorized with
+        // the result
+        if let Some(connector) = payment_attempt
+            .connector
+            .as_deref()
+            .and_then(|connector| api::enums::Connector::from_str(connector).ok())
+            .filter(|connector| !connector.supports_external_three_ds())
+        {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "connector {connector} does not support payments authenticated by the 3DS server"
+                ),
+            })?
+        }
+
+        payment_attempt.payment_method = Some(enums::PaymentMethodType::Card);
+
+        let shipping_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+        let billing_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+        )
+        .await?;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        let amount = payment_attempt.amount.into();
+
+        Ok((
+            Box::new(self),
+            PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                amount,
+                email: None,
+                mandate_id: None,
+                setup_mandate: None,
+                // The card is stored in the vault, to authorize the payment once authenticated
+                token: None,
+                address: PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
+                    billing: billing_address.as_ref().map(|a| a.foreign_into()),
+                },
+                confirm: None,
+                payment_method_data: Some(request.payment_method_data.clone()),
+                force_sync: None,
+                refunds: vec![],
+                attempts: None,
+                capture: None,
+                connector_response,
+                sessions_token: vec![],
+                three_ds_authenticate_request: None,
+                card_cvc: None,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsPreAuthenticateRequest>
+    for PaymentPreAuthenticate
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        _customer: Option<Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsPreAuthenticateRequest>,
+        PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        // The payment is updated with the outcome of the authentication from the 3DS server
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsPreAuthenticateRequest>
+    for PaymentPreAuthenticate
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsPreAuthenticateRequest,
+        merchant_account: &'a storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsPreAuthenticateRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        if !matches!(request.payment_method_data, api::PaymentMethod::Card(_)) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only cards can be authenticated with 3DS".to_string(),
+            })?
+        }
+
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+            },
+        ))
+    }
+}
@@ -1,27 +1,28 @@
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{report, IntoReport, ResultExt};
 use router_derive;
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        mandate,
+        payments::{self, helpers, PaymentData},
     },
     db::StorageInterface,
+    logger,
     services::RedirectForm,
     types::{
         self, api,
         storage::{self, enums},
-        transformers::ForeignInto,
     },
-    utils,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     ops = "post_tracker",
-    flow = "syncdata,authorizedata,canceldata,capturedata,verifydata,sessiondata"
+    flow = "syncdata,authorizedata,canceldata,capturedata,verifydata,sessiondata,incrementalauthorizationdata,preauthenticatedata,authenticatedata"
 )]
 pub struct PaymentResponse;
 
@@ -60,14 +61,109 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
         db: &dyn StorageInterface,
         payment_id: &api::PaymentIdType,
         payment_data: PaymentData<F>,
-        response: types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>,
+        mut response: types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
-        payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
-            .await
+        let previous_status = payment_data.payment_attempt.status;
+
+        // A capture the connector reported pending is charged or failed once the connector has
+        // synced the payment, through the capture sync task or a webhook of the connector
+        let pending_capture = match (
+            &response.response,
+            payment_data.payment_attempt.capture_method,
+        ) {
+            (
+                Ok(_),
+                Some(
+                    enums::CaptureMethod::Manual
+                    | enums::CaptureMethod::ManualMultiple
+                    | enums::CaptureMethod::Scheduled,
+                ),
+            ) => {
+                let captures = db
+                    .find_captures_by_merchant_id_payment_id(
+                        &payment_data.payment_attempt.merchant_id,
+                        &payment_data.payment_attempt.payment_id,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find captures of the payment")?;
+                captures
+                    .iter()
+                    .find(|capture| capture.status == enums::CaptureStatus::Pending)
+                    .cloned()
+                    .map(|capture| {
+                        let amount_captured =
+                            helpers::get_amount_captured(&captures, 0, &capture.capture_id);
+                        (capture, amount_captured)
+                    })
+            }
+            _ => None,
+        };
+        let capture_status = pending_capture.as_ref().map(|(capture, amount_captured)| {
+            let capture_status = helpers::get_pending_capture_status(
+                *amount_captured,
+                capture,
+                response.status,
+                response.amount_captured,
+            );
+            match capture_status {
+                enums::CaptureStatus::Charged => {
+                    let total_amount_captured = amount_captured + capture.amount;
+                    response.amount_captured = Some(total_amount_captured);
+                    response.status = helpers::get_captured_attempt_status(
+                        capture.final_capture,
+                        total_amount_captured,
+                        payment_data.payment_intent.amount,
+                    );
+                }
+                // A failed partial capture leaves the payment capturable with the amounts
+                // captured earlier intact
+                enums::CaptureStatus::Failed if *amount_captured > 0 => {
+                    response.amount_captured = Some(*amount_captured);
+                    response.status = enums::AttemptStatus::PartialCharged;
+                }
+                _ => {}
+            }
+            capture_status
+        });
+
+        let mut payment_data =
+            payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+                .await?;
+
+        if let (Some((capture, _)), Some(capture_status)) = (pending_capture, capture_status) {
+            if capture_status != enums::CaptureStatus::Pending {
+                let capture = db
+                    .update_capture(
+                        capture,
+                        storage::CaptureUpdate::StatusUpdate {
+                            status: capture_status,
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update capture")?;
+                payment_data.capture = Some(capture);
+            }
+        }
+
+        // A charge of a mandate failing after it was accepted by the connector, found through a
+        // sync or a webhook of the connector, no longer counts towards the usage of the mandate
+        if previous_status != enums::AttemptStatus::Failure
+            && payment_data.payment_attempt.status == enums::AttemptStatus::Failure
+        {
+            if let Err(error) =
+                mandate::release_failed_attempt_mandate_usage(db, &payment_data.payment_attempt)
+                    .await
+            {
+                logger::error!(mandate_usage_error=?error);
+            }
+        }
+        Ok(payment_data)
     }
 }
 
@@ -99,15 +195,84 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         &'b self,
         db: &dyn StorageInterface,
         payment_id: &api::PaymentIdType,
-        payment_data: PaymentData<F>,
-        response: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+        mut payment_data: PaymentData<F>,
+        mut response: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
-        payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+        let capture = payment_data.capture.take().get_required_value("capture")?;
+        let capture_update = match &response.response {
+            Err(error) => storage::CaptureUpdate::ErrorUpdate {
+                status: enums::CaptureStatus::Failed,
+                error_code: Some(error.code.clone()),
+                error_message: Some(error.message.clone()),
+            },
+            Ok(_) => storage::CaptureUpdate::StatusUpdate {
+                status: get_capture_status(response.status),
+            },
+        };
+
+        // The amount captured is the sum of the charged captures of the payment rather than the
+        // amount captured of the payment, which a concurrent update could have left stale
+        let captures = db
+            .find_captures_by_merchant_id_payment_id(&capture.merchant_id, &capture.payment_id)
             .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find captures of the payment")?;
+        let amount_captured = helpers::get_amount_captured(&captures, 0, &capture.capture_id);
+        match (&response.response, get_capture_status(response.status)) {
+            // A failed partial capture leaves the payment capturable with the amounts captured
+            // earlier intact
+            (Err(error), _) if amount_captured > 0 => {
+                db.update_capture(capture, capture_update)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update capture")?;
+                return Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+                    message: error.message.clone(),
+                    code: error.code.clone(),
+                    status_code: error.status_code,
+                    connector: response.connector,
+                }));
+            }
+            (Ok(_), enums::CaptureStatus::Charged) => {
+                let total_amount_captured = amount_captured + capture.amount;
+                response.amount_captured = Some(total_amount_captured);
+                response.status = helpers::get_captured_attempt_status(
+                    capture.final_capture,
+                    total_amount_captured,
+                    payment_data.payment_intent.amount,
+                );
+            }
+            _ => response.amount_captured = None,
+        }
+
+        // The payment is updated while the capture is still in progress, so that the next
+        // capture of the payment is validated against the updated payment
+        let result =
+            payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+                .await;
+        let capture = db
+            .update_capture(capture, capture_update)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update capture")?;
+
+        // A capture the connector reported pending is synced until the connector has charged or
+        // failed it
+        if capture.status == enums::CaptureStatus::Pending {
+            payments::add_capture_sync_task(db, &capture)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding capture sync task to process tracker")?;
+        }
+
+        let mut payment_data = result?;
+        payment_data.capture = Some(capture);
+        Ok(payment_data)
     }
 }
 
@@ -125,8 +290,128 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
     where
         F: 'b + Send,
     {
-        payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+        let payment_data =
+            payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+                .await?;
+
+        // A voided payment should no longer be captured at its scheduled time
+        if payment_data.payment_attempt.status == enums::AttemptStatus::Voided {
+            payments::cancel_scheduled_capture_task(db, &payment_data.payment_attempt).await?;
+        }
+
+        Ok(payment_data)
+    }
+}
+
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAuthorizationData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: PaymentData<F>,
+        response: types::RouterData<
+            F,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        // A declined increment leaves the payment authorized for the amount authorized earlier
+        if let Err(error) = response.response {
+            return Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+                message: error.message,
+                code: error.code,
+                status_code: error.status_code,
+                connector: response.connector,
+            }));
+        }
+
+        let amount =
+            match helpers::get_incremental_authorization_amount(response.status, &response.request)
+            {
+                Some(amount) => amount,
+                None => {
+                    payment_data.amount = payment_data.payment_attempt.amount.into();
+                    return Ok(payment_data);
+                }
+            };
+        payment_data.payment_attempt = db
+            .update_payment_attempt(
+                payment_data.payment_attempt,
+                storage::PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                    amount,
+                    amount_to_capture: Some(amount),
+                },
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+        payment_data.payment_intent = db
+            .update_payment_intent(
+                payment_data.payment_intent,
+                storage::PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount },
+                storage_scheme,
+            )
             .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        Ok(payment_data)
+    }
+}
+
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsPreAuthenticateData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        response: types::RouterData<
+            F,
+            types::PaymentsPreAuthenticateData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        three_ds_authentication_update_tracker(db, payment_data, response, storage_scheme).await
+    }
+}
+
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthenticateData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        response: types::RouterData<
+            F,
+            types::PaymentsAuthenticateData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        three_ds_authentication_update_tracker(db, payment_data, response, storage_scheme).await
     }
 }
 
@@ -154,6 +439,90 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::VerifyRequestData> fo
     }
 }
 
+fn get_capture_status(status: enums::AttemptStatus) -> enums::CaptureStatus {
+    match status {
+        enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => {
+            enums::CaptureStatus::Charged
+        }
+        enums::AttemptStatus::CaptureFailed | enums::AttemptStatus::Failure => {
+            enums::CaptureStatus::Failed
+        }
+        _ => enums::CaptureStatus::Pending,
+    }
+}
+
+async fn three_ds_authentication_update_tracker<F: Clone, T>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, T, types::PaymentsResponseData>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    // The customer can be authenticated again when the 3DS server fails to respond
+    let authentication = match router_data.response {
+        Ok(types::PaymentsResponseData::ThreeDsAuthenticationResponse { authentication }) => {
+            authentication
+        }
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from the 3DS server connector")?,
+        Err(error) => Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+            message: error.message,
+            code: error.code,
+            status_code: error.status_code,
+            connector: router_data.connector,
+        }))?,
+    };
+
+    let (attempt_status, intent_status, error_message) = match authentication.status {
+        types::ThreeDsAuthenticationStatus::Pending
+        | types::ThreeDsAuthenticationStatus::ChallengeRequired => (
+            enums::AttemptStatus::AuthenticationPending,
+            enums::IntentStatus::RequiresCustomerAction,
+            None,
+        ),
+        types::ThreeDsAuthenticationStatus::Succeeded => (
+            enums::AttemptStatus::AuthenticationSuccessful,
+            enums::IntentStatus::RequiresConfirmation,
+            None,
+        ),
+        types::ThreeDsAuthenticationStatus::Failed => (
+            enums::AttemptStatus::AuthenticationFailed,
+            enums::IntentStatus::Failed,
+            Some("3DS authentication of the customer failed".to_string()),
+        ),
+    };
+    let three_ds_authentication =
+        utils::Encode::<types::ThreeDsAuthentication>::encode_to_value(&authentication)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the 3DS authentication")?;
+
+    // The token of the card is kept, to authorize the payment with it once authenticated
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::ThreeDsAuthenticationUpdate {
+                status: attempt_status,
+                payment_token: payment_data.token.clone(),
+                three_ds_authentication,
+                error_message,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: intent_status,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(payment_data)
+}
+
 async fn payment_response_update_tracker<F: Clone, T>(
     db: &dyn StorageInterface,
     _payment_id: &api::PaymentIdType,
@@ -224,7 +593,8 @@ async fn payment_response_update_tracker<F: Clone, T>(
                 )
             }
 
-            types::PaymentsResponseData::SessionResponse { .. } => (None, None),
+            types::PaymentsResponseData::SessionResponse { .. }
+            | types::PaymentsResponseData::ThreeDsAuthenticationResponse { .. } => (None, None),
         },
     };
 
@@ -261,7 +631,10 @@ async fn payment_response_update_tracker<F: Clone, T>(
             status: enums::IntentStatus::Failed,
         },
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: router_data.status.foreign_into(),
+            status: helpers::get_intent_status(
+                router_data.status,
+                payment_data.payment_attempt.capture_method,
+            ),
             return_url: router_data.return_url,
             amount_captured: router_data.amount_captured,
         },
@@ -154,7 +154,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                attempts: None,
+                capture: None,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 connector_response,
                 card_cvc: None,
             },
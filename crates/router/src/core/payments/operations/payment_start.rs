@@ -141,7 +141,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                attempts: None,
+                capture: None,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 card_cvc: None,
             },
             Some(customer_details),
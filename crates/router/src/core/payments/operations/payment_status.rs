@@ -243,6 +243,21 @@ async fn get_tracker_for_sync<
             )
         })?;
 
+    let attempts = db
+        .find_payment_attempts_by_payment_id_merchant_id(
+            &payment_id_str,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while getting payment attempt list for, payment_id: {}, merchant_id: {}",
+                &payment_id_str, merchant_id
+            )
+        })?;
+
     Ok((
         Box::new(operation),
         PaymentData {
@@ -266,7 +281,10 @@ async fn get_tracker_for_sync<
             ),
             payment_attempt,
             refunds,
+            attempts: Some(attempts),
+            capture: None,
             sessions_token: vec![],
+            three_ds_authenticate_request: None,
             card_cvc: None,
         },
         None,
@@ -215,8 +215,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                attempts: None,
+                capture: None,
                 connector_response,
                 sessions_token: vec![],
+                three_ds_authenticate_request: None,
                 card_cvc: request.card_cvc.clone(),
             },
             Some(CustomerDetails {
@@ -423,6 +426,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentUpdate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_capture_on(request.capture_method, request.capture_on)?;
+
         let mandate_type = helpers::validate_mandate(request)?;
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
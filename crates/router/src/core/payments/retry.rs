@@ -0,0 +1,341 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use uuid::Uuid;
+
+use super::{
+    flows::{ConstructFlowSpecificData, Feature},
+    helpers,
+    operations::{Operation, PaymentCreate},
+    routing, CallConnectorAction, PaymentData, PaymentResponse,
+};
+use crate::{
+    core::{
+        connector_health,
+        errors::{self, RouterResult, StorageErrorExt},
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Key of the config holding the maximum number of times a failed payment of the merchant is
+/// retried on a fallback connector
+pub fn get_max_auto_retries_config_key(merchant_id: &str) -> String {
+    format!("max_auto_retries_enabled_{merchant_id}")
+}
+
+/// Call the connector decided by routing, retrying the payment on the fallback connectors of the
+/// merchant whenever the connector soft declines it, up to the number of retries configured for
+/// the merchant
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn call_connector_service_with_retries<F, Op, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    connector: api::ConnectorData,
+    operation: &Op,
+    payment_data: PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    call_connector_action: CallConnectorAction,
+) -> RouterResult<PaymentData<F>>
+where
+    Op: Debug + Sync,
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let max_auto_retries = get_max_auto_retries(state, merchant_account).await;
+    let mut connector_name = connector.connector_name.to_string();
+    let mut attempted_connectors = vec![connector_name.clone()];
+    let mut payment_data = payment_data;
+
+    let mut result = super::call_connector_service(
+        state,
+        merchant_account,
+        payment_id,
+        connector,
+        operation,
+        payment_data.clone(),
+        customer,
+        call_connector_action,
+    )
+    .await;
+
+    for _ in 0..max_auto_retries {
+        match &result {
+            Err(error)
+                if is_retriable_error(error, &state.conf.auto_retries.retriable_error_codes) => {}
+            _ => break,
+        }
+
+        let fallback_connector = get_fallback_connector(
+            state,
+            merchant_account,
+            &payment_data,
+            &attempted_connectors,
+        )
+        .await?;
+        let fallback_connector = match fallback_connector {
+            Some(fallback_connector) => fallback_connector,
+            None => break,
+        };
+        logger::info!(
+            failed_connector = %connector_name,
+            %fallback_connector,
+            "Retrying payment on fallback connector"
+        );
+
+        payment_data =
+            make_new_payment_attempt(state, merchant_account, payment_data, &fallback_connector)
+                .await?;
+
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &fallback_connector,
+            api::GetToken::Connector,
+        )
+        .attach_printable("Routing algorithm gave invalid fallback connector")?;
+        connector_name = fallback_connector;
+        attempted_connectors.push(connector_name.clone());
+
+        result = super::call_connector_service(
+            state,
+            merchant_account,
+            payment_id,
+            connector_data,
+            operation,
+            payment_data.clone(),
+            customer,
+            CallConnectorAction::Trigger,
+        )
+        .await;
+    }
+
+    let mut payment_data = result?;
+    if attempted_connectors.len() > 1 {
+        let attempts = state
+            .store
+            .find_payment_attempts_by_payment_id_merchant_id(
+                &payment_data.payment_attempt.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while getting payment attempt list")?;
+        payment_data.attempts = Some(attempts);
+    }
+    Ok(payment_data)
+}
+
+async fn get_max_auto_retries(state: &AppState, merchant_account: &storage::MerchantAccount) -> u8 {
+    // [#439]: Attempts are looked up by the payment id in KV mode, so the payment cannot have more
+    // than a single attempt
+    if merchant_account.storage_scheme != storage_enums::MerchantStorageScheme::PostgresOnly {
+        return 0;
+    }
+
+    let key = get_max_auto_retries_config_key(&merchant_account.merchant_id);
+    match state.store.find_config_by_key_cached(&key).await {
+        Ok(config) => config.config.parse().unwrap_or_else(|error| {
+            logger::error!(
+                ?error,
+                "Invalid max auto retries configured for the merchant"
+            );
+            0
+        }),
+        // Payments are not retried unless enabled for the merchant
+        Err(_) => 0,
+    }
+}
+
+/// A payment can be retried on a fallback connector, only if the connector explicitly soft
+/// declined it. The outcome of a payment is unknown when the request to the connector times out,
+/// so the pending attempt is left to be resolved by syncing it with the connector instead.
+fn is_retriable_error(
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+    retriable_error_codes: &HashMap<String, HashSet<String>>,
+) -> bool {
+    match error.current_context() {
+        errors::ApiErrorResponse::ExternalConnectorError {
+            code, connector, ..
+        } => retriable_error_codes
+            .get(connector)
+            .map_or(false, |codes| codes.contains(code)),
+        _ => false,
+    }
+}
+
+/// Pick the next connector from the routing algorithm of the merchant, which is yet to be
+/// attempted for the payment
+async fn get_fallback_connector<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    attempted_connectors: &[String],
+) -> RouterResult<Option<String>> {
+    let routing_input = routing::RoutingInput::from_payment_data(payment_data);
+    let routing_decision =
+        routing::decide_connectors(state, merchant_account, &routing_input).await?;
+    // Payments authenticated by the router are only retried on connectors accepting the result
+    let eligible_connectors = routing_decision
+        .eligible_connectors
+        .iter()
+        .map(ToString::to_string)
+        .filter(|connector| {
+            helpers::validate_three_ds_authentication_connector(
+                &payment_data.payment_attempt,
+                connector,
+            )
+            .is_ok()
+        })
+        .collect();
+    Ok(
+        connector_health::prioritize_healthy_connectors(state, eligible_connectors)
+            .await
+            .into_iter()
+            .find(|connector| !attempted_connectors.contains(connector)),
+    )
+}
+
+/// Create a new attempt for the payment with the fallback connector, carrying over the details of
+/// the failed attempt
+#[instrument(skip_all)]
+async fn make_new_payment_attempt<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+    connector: &str,
+) -> RouterResult<PaymentData<F>> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+    let failed_attempt = &payment_data.payment_attempt;
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    let payment_attempt_new = storage::PaymentAttemptNew {
+        payment_id: failed_attempt.payment_id.clone(),
+        merchant_id: failed_attempt.merchant_id.clone(),
+        attempt_id: Uuid::new_v4().to_string(),
+        status: storage_enums::AttemptStatus::Pending,
+        amount: failed_attempt.amount,
+        currency: failed_attempt.currency,
+        save_to_locker: failed_attempt.save_to_locker,
+        connector: Some(connector.to_string()),
+        offer_amount: failed_attempt.offer_amount,
+        surcharge_amount: failed_attempt.surcharge_amount,
+        tax_amount: failed_attempt.tax_amount,
+        payment_method_id: failed_attempt.payment_method_id.clone(),
+        payment_method: failed_attempt.payment_method,
+        payment_flow: failed_attempt.payment_flow,
+        capture_method: failed_attempt.capture_method,
+        capture_on: failed_attempt.capture_on,
+        confirm: failed_attempt.confirm,
+        authentication_type: failed_attempt.authentication_type,
+        created_at,
+        modified_at,
+        last_synced,
+        amount_to_capture: failed_attempt.amount_to_capture,
+        mandate_id: failed_attempt.mandate_id.clone(),
+        browser_info: failed_attempt.browser_info.clone(),
+        payment_token: failed_attempt.payment_token.clone(),
+        three_ds_authentication: failed_attempt.three_ds_authentication.clone(),
+        ..storage::PaymentAttemptNew::default()
+    };
+
+    payment_data.payment_attempt = db
+        .insert_payment_attempt(payment_attempt_new, storage_scheme)
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_data.payment_intent.payment_id.clone(),
+            })
+        })?;
+
+    payment_data.connector_response = db
+        .insert_connector_response(
+            PaymentCreate::make_connector_response(&payment_data.payment_attempt),
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_data.payment_intent.payment_id.clone(),
+            })
+        })?;
+
+    Ok(payment_data)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use error_stack::report;
+
+    use super::*;
+
+    fn retriable_error_codes() -> HashMap<String, HashSet<String>> {
+        HashMap::from([(
+            "stripe".to_string(),
+            HashSet::from(["processing_error".to_string()]),
+        )])
+    }
+
+    fn connector_error(
+        connector: &str,
+        code: &str,
+    ) -> error_stack::Report<errors::ApiErrorResponse> {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: code.to_string(),
+            message: "The payment could not be processed".to_string(),
+            connector: connector.to_string(),
+            status_code: 402,
+        })
+    }
+
+    #[test]
+    fn test_soft_declines_are_retriable() {
+        let retriable_error_codes = retriable_error_codes();
+        assert!(is_retriable_error(
+            &connector_error("stripe", "processing_error"),
+            &retriable_error_codes
+        ));
+        assert!(!is_retriable_error(
+            &connector_error("stripe", "card_declined"),
+            &retriable_error_codes
+        ));
+        assert!(!is_retriable_error(
+            &connector_error("adyen", "processing_error"),
+            &retriable_error_codes
+        ));
+    }
+
+    #[test]
+    fn test_timeouts_are_not_retriable() {
+        let retriable_error_codes = retriable_error_codes();
+        let timeout_error = report!(errors::ApiClientError::RequestTimeoutReceived)
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data: None });
+        assert!(!is_retriable_error(&timeout_error, &retriable_error_codes));
+
+        let internal_error = report!(errors::ApiErrorResponse::InternalServerError);
+        assert!(!is_retriable_error(&internal_error, &retriable_error_codes));
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::HashSet;
+
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use rand::distributions::{Distribution, WeightedIndex};
+use router_env::{instrument, tracing};
+
+use super::PaymentData;
+use crate::{
+    core::{
+        connector_health,
+        errors::{self, RouterResponse, RouterResult},
+    },
+    routes::AppState,
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        storage,
+        transformers::ForeignInto,
+    },
+    utils::OptionExt,
+};
+
+/// The maximum number of digits of the card number considered as its BIN
+const MAX_CARD_BIN_LENGTH: usize = 8;
+
+/// The attributes of a payment against which the routing rules are evaluated
+#[derive(Debug, Default)]
+pub struct RoutingInput {
+    pub currency: Option<api_enums::Currency>,
+    pub amount: Option<i64>,
+    pub card_bin: Option<String>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub billing_country: Option<String>,
+    pub payment_method: Option<api_enums::PaymentMethodType>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl RoutingInput {
+    pub fn from_payment_data<F: Clone>(payment_data: &PaymentData<F>) -> Self {
+        let (card_bin, card_network) = get_card_details(payment_data.payment_method_data.as_ref());
+        Self {
+            currency: Some(payment_data.currency.foreign_into()),
+            amount: Some(payment_data.amount.into()),
+            card_bin,
+            card_network,
+            billing_country: get_billing_country(payment_data.address.billing.as_ref()),
+            payment_method: payment_data
+                .payment_attempt
+                .payment_method
+                .map(ForeignInto::foreign_into),
+            metadata: payment_data.payment_intent.metadata.clone(),
+        }
+    }
+
+    pub fn from_payments_request(req: &api::PaymentsRequest) -> Self {
+        let (card_bin, card_network) = get_card_details(req.payment_method_data.as_ref());
+        Self {
+            currency: req.currency,
+            amount: req.amount.map(Into::into),
+            card_bin,
+            card_network,
+            billing_country: get_billing_country(req.billing.as_ref()),
+            payment_method: req.payment_method,
+            metadata: req.metadata.as_ref().map(|metadata| metadata.data.clone()),
+        }
+    }
+}
+
+/// The outcome of evaluating the routing algorithm of a merchant for a payment
+#[derive(Debug, Default)]
+pub struct RoutingDecision {
+    /// The connectors enabled for the merchant which the payment can be routed to, in the order
+    /// of priority
+    pub eligible_connectors: Vec<api_enums::RoutableConnectors>,
+    /// The name of the routing rule which was applied
+    pub matched_rule: Option<String>,
+}
+
+/// Decide the connector for a payment based on the routing algorithm configured by the merchant
+pub async fn get_connector_name_from_routing_algorithm(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    routing_input: &RoutingInput,
+) -> RouterResult<String> {
+    let routing_decision = decide_connectors(state, merchant_account, routing_input).await?;
+    let eligible_connectors = routing_decision
+        .eligible_connectors
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    // Unhealthy connectors are skipped in favour of the next eligible connectors
+    connector_health::prioritize_healthy_connectors(state, eligible_connectors)
+        .await
+        .into_iter()
+        .next()
+        .ok_or_else(no_enabled_connector_error)
+        .into_report()
+}
+
+pub async fn decide_connectors(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    routing_input: &RoutingInput,
+) -> RouterResult<RoutingDecision> {
+    let routing_algorithm: api::RoutingAlgorithm = merchant_account
+        .routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant routing rules")?;
+
+    let routing_decision = match routing_algorithm {
+        api::RoutingAlgorithm::Single(connector) => RoutingDecision {
+            eligible_connectors: vec![connector],
+            matched_rule: None,
+        },
+        api::RoutingAlgorithm::Priority(connectors) => {
+            let enabled_connectors = get_enabled_connectors(state, merchant_account).await?;
+            RoutingDecision {
+                eligible_connectors: filter_enabled_connectors(connectors, &enabled_connectors),
+                matched_rule: None,
+            }
+        }
+        api::RoutingAlgorithm::VolumeSplit(splits) => {
+            let enabled_connectors = get_enabled_connectors(state, merchant_account).await?;
+            RoutingDecision {
+                eligible_connectors: split_volume(splits, &enabled_connectors),
+                matched_rule: None,
+            }
+        }
+        api::RoutingAlgorithm::RuleBased(routing_rules) => {
+            let enabled_connectors = get_enabled_connectors(state, merchant_account).await?;
+            let matched_rule = routing_rules.rules.into_iter().find(|rule| {
+                rule.conditions
+                    .iter()
+                    .all(|condition| is_condition_satisfied(condition, routing_input))
+            });
+            match matched_rule {
+                Some(rule) => RoutingDecision {
+                    eligible_connectors: filter_enabled_connectors(
+                        rule.connectors,
+                        &enabled_connectors,
+                    ),
+                    matched_rule: Some(rule.name),
+                },
+                None => RoutingDecision {
+                    eligible_connectors: filter_enabled_connectors(
+                        routing_rules.default_connectors,
+                        &enabled_connectors,
+                    ),
+                    matched_rule: None,
+                },
+            }
+        }
+    };
+
+    Ok(routing_decision)
+}
+
+#[instrument(skip_all)]
+pub async fn routing_dry_run(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentsRequest,
+) -> RouterResponse<api::RoutingDryRunResponse> {
+    merchant_account
+        .routing_algorithm
+        .as_ref()
+        .get_required_value("routing_algorithm")
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A routing algorithm is not configured for the merchant".to_string(),
+        })?;
+    let routing_input = RoutingInput::from_payments_request(&req);
+    let routing_decision = decide_connectors(state, &merchant_account, &routing_input).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::RoutingDryRunResponse {
+            connector: routing_decision.eligible_connectors.first().copied(),
+            eligible_connectors: routing_decision.eligible_connectors,
+            matched_rule: routing_decision.matched_rule,
+        },
+    ))
+}
+
+fn is_condition_satisfied(condition: &api::RoutingCondition, input: &RoutingInput) -> bool {
+    match condition {
+        api::RoutingCondition::Currency { values } => input
+            .currency
+            .map_or(false, |currency| values.contains(&currency)),
+        api::RoutingCondition::Amount { min, max } => input.amount.map_or(false, |amount| {
+            min.map_or(true, |min| amount >= min) && max.map_or(true, |max| amount <= max)
+        }),
+        api::RoutingCondition::CardNetwork { values } => input
+            .card_network
+            .map_or(false, |card_network| values.contains(&card_network)),
+        api::RoutingCondition::CardBin { values } => {
+            input.card_bin.as_ref().map_or(false, |card_bin| {
+                values.iter().any(|bin| card_bin.starts_with(bin.as_str()))
+            })
+        }
+        api::RoutingCondition::BillingCountry { values } => {
+            input.billing_country.as_ref().map_or(false, |country| {
+                values
+                    .iter()
+                    .any(|value| value.eq_ignore_ascii_case(country))
+            })
+        }
+        api::RoutingCondition::PaymentMethod { values } => input
+            .payment_method
+            .map_or(false, |payment_method| values.contains(&payment_method)),
+        api::RoutingCondition::Metadata { key, value } => input
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .map_or(false, |actual| {
+                value.as_ref().map_or(true, |expected| expected == actual)
+            }),
+    }
+}
+
+/// Pick a connector at random, in proportion to the splits of the connectors enabled for the
+/// merchant, followed by the remaining enabled connectors in the descending order of their splits
+fn split_volume(
+    splits: Vec<api::ConnectorVolumeSplit>,
+    enabled_connectors: &HashSet<String>,
+) -> Vec<api_enums::RoutableConnectors> {
+    let mut enabled_splits: Vec<_> = splits
+        .into_iter()
+        .filter(|split| enabled_connectors.contains(&split.connector.to_string()))
+        .collect();
+    // The splits of the remaining connectors are used as relative weights, so that the volume of
+    // disabled connectors is shared proportionally among them
+    let chosen_split = WeightedIndex::new(enabled_splits.iter().map(|split| split.split))
+        .map(|weighted_index| enabled_splits.remove(weighted_index.sample(&mut rand::thread_rng())))
+        .ok();
+    enabled_splits.sort_by(|a, b| b.split.cmp(&a.split));
+
+    chosen_split
+        .into_iter()
+        .chain(enabled_splits)
+        .map(|split| split.connector)
+        .collect()
+}
+
+fn filter_enabled_connectors(
+    connectors: Vec<api_enums::RoutableConnectors>,
+    enabled_connectors: &HashSet<String>,
+) -> Vec<api_enums::RoutableConnectors> {
+    connectors
+        .into_iter()
+        .filter(|connector| enabled_connectors.contains(&connector.to_string()))
+        .collect()
+}
+
+async fn get_enabled_connectors(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<HashSet<String>> {
+    Ok(state
+        .store
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?
+        .into_iter()
+        .filter(|mca| mca.disabled != Some(true))
+        .map(|mca| mca.connector_name)
+        .collect())
+}
+
+fn get_card_details(
+    payment_method_data: Option<&api::PaymentMethod>,
+) -> (Option<String>, Option<api_enums::CardNetwork>) {
+    match payment_method_data {
+        Some(api::PaymentMethod::Card(card)) => {
+            let card_number = card.card_number.peek();
+            (
+                Some(card_number.chars().take(MAX_CARD_BIN_LENGTH).collect()),
+                get_card_network(card_number),
+            )
+        }
+        _ => (None, None),
+    }
+}
+
+fn get_billing_country(billing: Option<&api::Address>) -> Option<String> {
+    billing
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country.clone())
+}
+
+/// Identify the card network from the leading digits of the card number
+fn get_card_network(card_number: &str) -> Option<api_enums::CardNetwork> {
+    let prefix = |length: usize| {
+        card_number
+            .get(..length)
+            .and_then(|prefix| prefix.parse::<u32>().ok())
+    };
+    match (prefix(1), prefix(2), prefix(3), prefix(4)) {
+        (Some(4), _, _, _) => Some(api_enums::CardNetwork::Visa),
+        (_, Some(34 | 37), _, _) => Some(api_enums::CardNetwork::AmericanExpress),
+        (_, Some(51..=55), _, _) | (_, _, _, Some(2221..=2720)) => {
+            Some(api_enums::CardNetwork::Mastercard)
+        }
+        (_, Some(65), _, _) | (_, _, Some(644..=649), _) | (_, _, _, Some(6011)) => {
+            Some(api_enums::CardNetwork::Discover)
+        }
+        (_, _, _, Some(3528..=3589)) => Some(api_enums::CardNetwork::Jcb),
+        (_, Some(36 | 38), _, _) | (_, _, Some(300..=305), _) => {
+            Some(api_enums::CardNetwork::DinersClub)
+        }
+        _ => None,
+    }
+}
+
+fn no_enabled_connector_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::PreconditionFailed {
+        message: "None of the connectors in the routing algorithm are enabled for the merchant"
+            .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_card_network() {
+        assert_eq!(
+            get_card_network("4242424242424242"),
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            get_card_network("5555555555554444"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_card_network("378282246310005"),
+            Some(api_enums::CardNetwork::AmericanExpress)
+        );
+        assert_eq!(get_card_network("9999999999999999"), None);
+    }
+
+    #[test]
+    fn test_rule_conditions() {
+        let input = RoutingInput {
+            currency: Some(api_enums::Currency::EUR),
+            amount: Some(15000),
+            card_bin: Some("42424242".to_string()),
+            card_network: Some(api_enums::CardNetwork::Visa),
+            billing_country: Some("DE".to_string()),
+            payment_method: Some(api_enums::PaymentMethodType::Card),
+            metadata: Some(serde_json::json!({ "segment": "enterprise" })),
+        };
+        let satisfied_conditions = [
+            api::RoutingCondition::Currency {
+                values: vec![api_enums::Currency::EUR, api_enums::Currency::GBP],
+            },
+            api::RoutingCondition::Amount {
+                min: Some(10000),
+                max: None,
+            },
+            api::RoutingCondition::CardBin {
+                values: vec!["424242".to_string()],
+            },
+            api::RoutingCondition::BillingCountry {
+                values: vec!["de".to_string()],
+            },
+            api::RoutingCondition::Metadata {
+                key: "segment".to_string(),
+                value: Some(serde_json::json!("enterprise")),
+            },
+        ];
+        assert!(satisfied_conditions
+            .iter()
+            .all(|condition| is_condition_satisfied(condition, &input)));
+
+        let unsatisfied_condition = api::RoutingCondition::Amount {
+            min: None,
+            max: Some(10000),
+        };
+        assert!(!is_condition_satisfied(&unsatisfied_condition, &input));
+    }
+}
@@ -70,6 +70,20 @@ where
             connector_metadata: None,
         });
 
+    // Payments for which the router authenticated the customer are not authenticated again by
+    // the connector, which is sent the result of the authentication instead
+    let auth_type = match helpers::get_three_ds_authentication(&payment_data.payment_attempt)? {
+        Some(authentication)
+            if authentication.status == types::ThreeDsAuthenticationStatus::Succeeded =>
+        {
+            enums::AuthenticationType::NoThreeDs
+        }
+        _ => payment_data
+            .payment_attempt
+            .authentication_type
+            .unwrap_or_default(),
+    };
+
     let router_return_url = Some(helpers::create_redirect_url(
         &state.conf.server,
         &payment_data.payment_attempt,
@@ -90,10 +104,7 @@ where
         router_return_url,
         payment_method_id: payment_data.payment_attempt.payment_method_id.clone(),
         address: payment_data.address.clone(),
-        auth_type: payment_data
-            .payment_attempt
-            .authentication_type
-            .unwrap_or_default(),
+        auth_type,
         connector_meta_data: merchant_connector_account.metadata,
         request: T::try_from(payment_data.clone())?,
         response: response.map_or_else(|| Err(types::ErrorResponse::default()), Ok),
@@ -138,6 +149,7 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.attempts,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -227,6 +239,7 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    attempts: Option<Vec<storage::PaymentAttempt>>,
     payment_method_data: Option<api::PaymentMethod>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -250,6 +263,12 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let attempts_response = attempts.map(|attempts| {
+        attempts
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect()
+    });
 
     Ok(match payment_request {
         Some(request) => {
@@ -264,13 +283,44 @@ where
                     .map_err(|_| errors::ApiErrorResponse::InternalServerError)?;
                 let mut next_action_response = None;
                 if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
-                    next_action_response = Some(api::NextAction {
-                        next_action_type: api::NextActionType::RedirectToUrl,
-                        redirect_to_url: Some(helpers::create_startpay_url(
-                            server,
-                            &payment_attempt,
-                            &payment_intent,
-                        )),
+                    let three_ds_data = helpers::get_three_ds_authentication(&payment_attempt)?
+                        .filter(|authentication| {
+                            matches!(
+                                authentication.status,
+                                types::ThreeDsAuthenticationStatus::Pending
+                                    | types::ThreeDsAuthenticationStatus::ChallengeRequired
+                            )
+                        })
+                        .map(|authentication| api::ThreeDsData {
+                            three_ds_server_transaction_id: authentication
+                                .three_ds_server_transaction_id,
+                            message_version: authentication.message_version,
+                            three_ds_method_url: authentication.three_ds_method_url,
+                            three_ds_method_data: authentication.three_ds_method_data,
+                            acs_url: authentication.acs_url,
+                            challenge_request: authentication.challenge_request,
+                            authentication_url: helpers::create_three_ds_authentication_url(
+                                server,
+                                &payment_attempt,
+                            ),
+                        });
+                    // The customer is authenticated by the SDK for payments authenticated by the
+                    // router, instead of being redirected to the connector
+                    next_action_response = Some(match three_ds_data {
+                        Some(three_ds_data) => api::NextAction {
+                            next_action_type: api::NextActionType::ThreeDsInvoke,
+                            redirect_to_url: None,
+                            three_ds_data: Some(three_ds_data),
+                        },
+                        None => api::NextAction {
+                            next_action_type: api::NextActionType::RedirectToUrl,
+                            redirect_to_url: Some(helpers::create_startpay_url(
+                                server,
+                                &payment_attempt,
+                                &payment_intent,
+                            )),
+                            three_ds_data: None,
+                        },
                     })
                 }
 
@@ -280,7 +330,7 @@ where
                         .set_merchant_id(Some(payment_attempt.merchant_id))
                         .set_status(payment_intent.status.foreign_into())
                         .set_amount(payment_attempt.amount)
-                        .set_amount_capturable(None)
+                        .set_amount_capturable(get_amount_capturable(&payment_intent))
                         .set_amount_received(payment_intent.amount_captured)
                         .set_connector(payment_attempt.connector)
                         .set_client_secret(payment_intent.client_secret.map(masking::Secret::new))
@@ -305,6 +355,7 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_attempts(attempts_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -350,7 +401,7 @@ where
             merchant_id: Some(payment_attempt.merchant_id),
             status: payment_intent.status.foreign_into(),
             amount: payment_attempt.amount,
-            amount_capturable: None,
+            amount_capturable: get_amount_capturable(&payment_intent),
             amount_received: payment_intent.amount_captured,
             client_secret: payment_intent.client_secret.map(masking::Secret::new),
             created: Some(payment_intent.created_at),
@@ -358,6 +409,7 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            attempts: attempts_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -386,6 +438,12 @@ where
     })
 }
 
+/// Amount left to be captured, for payments which can still be captured
+fn get_amount_capturable(payment_intent: &storage::PaymentIntent) -> Option<i64> {
+    (payment_intent.status == enums::IntentStatus::RequiresCapture)
+        .then(|| payment_intent.amount - payment_intent.amount_captured.unwrap_or(0))
+}
+
 impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -415,6 +473,13 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
 
         let order_details = parsed_metadata.and_then(|data| data.order_details);
 
+        let three_ds_authentication = helpers::get_three_ds_authentication(
+            &payment_data.payment_attempt,
+        )?
+        .filter(|authentication| {
+            authentication.status == types::ThreeDsAuthenticationStatus::Succeeded
+        });
+
         Ok(Self {
             payment_method_data: payment_data
                 .payment_method_data
@@ -423,14 +488,25 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             mandate_id: payment_data.mandate_id.clone(),
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
             setup_mandate_details: payment_data.setup_mandate.clone(),
+            customer_id: payment_data.payment_intent.customer_id.clone(),
+            connector_customer: None,
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
-            capture_method: payment_data.payment_attempt.capture_method,
+            // Scheduled payments are only authorized with the connector, the capture is triggered
+            // by the scheduler at `capture_on`
+            capture_method: payment_data
+                .payment_attempt
+                .capture_method
+                .map(|capture_method| match capture_method {
+                    enums::CaptureMethod::Scheduled => enums::CaptureMethod::Manual,
+                    capture_method => capture_method,
+                }),
             amount: payment_data.amount.into(),
             currency: payment_data.currency,
             browser_info,
             email: payment_data.email,
             order_details,
+            three_ds_authentication,
         })
     }
 }
@@ -456,14 +532,19 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
     type Error = errors::ApiErrorResponse;
 
     fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        let capture = payment_data
+            .capture
+            .ok_or(errors::ApiErrorResponse::InternalServerError)?;
         Ok(Self {
-            amount_to_capture: payment_data.payment_attempt.amount_to_capture,
+            amount_to_capture: Some(capture.amount),
             currency: payment_data.currency,
             connector_transaction_id: payment_data
                 .payment_attempt
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            capture_id: capture.capture_id,
+            final_capture: capture.final_capture,
         })
     }
 }
@@ -484,6 +565,72 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCancelData {
     }
 }
 
+impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsIncrementalAuthorizationData {
+    type Error = errors::ApiErrorResponse;
+
+    fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        let total_amount: i64 = payment_data.amount.into();
+        Ok(Self {
+            total_amount,
+            additional_amount: total_amount - payment_data.payment_attempt.amount,
+            currency: payment_data.currency,
+            connector_transaction_id: payment_data
+                .payment_attempt
+                .connector_transaction_id
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "connector_transaction_id",
+                })?,
+        })
+    }
+}
+
+impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsPreAuthenticateData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_method_data: payment_data
+                .payment_method_data
+                .get_required_value("payment_method_data")?,
+            amount: payment_data.amount.into(),
+            currency: payment_data.currency,
+        })
+    }
+}
+
+impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthenticateData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        let authentication = helpers::get_three_ds_authentication(&payment_data.payment_attempt)?
+            .get_required_value("three_ds_authentication")?;
+        let request = payment_data
+            .three_ds_authenticate_request
+            .get_required_value("three_ds_authenticate_request")?;
+        let browser_info: Option<types::BrowserInformation> = payment_data
+            .payment_attempt
+            .browser_info
+            .map(|b| b.parse_value("BrowserInformation"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "browser_info",
+            })?;
+
+        Ok(Self {
+            payment_method_data: payment_data
+                .payment_method_data
+                .get_required_value("payment_method_data")?,
+            amount: payment_data.amount.into(),
+            currency: payment_data.currency,
+            browser_info,
+            three_ds_server_transaction_id: authentication.three_ds_server_transaction_id,
+            message_version: authentication.message_version,
+            three_ds_method_completed: request.three_ds_method_completed,
+            challenge_response: request.challenge_response,
+        })
+    }
+}
+
 impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSessionData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -531,6 +678,9 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::VerifyRequestData {
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
             mandate_id: payment_data.mandate_id.clone(),
             setup_mandate_details: payment_data.setup_mandate,
+            customer_id: payment_data.payment_intent.customer_id,
+            email: payment_data.email,
+            connector_customer: None,
         })
     }
 }
@@ -0,0 +1,654 @@
+use std::fmt::Debug;
+
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, access_token},
+        utils as core_utils,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, enums as api_enums, payouts},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+// ********************************************** PAYOUT CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+    validate_payout_amount(req.amount)?;
+
+    let confirm = req.confirm.unwrap_or(false);
+    utils::when(confirm && req.payout_method_data.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_method_data"
+        }))
+    })?;
+
+    let routing_input = payments::routing::RoutingInput {
+        currency: Some(req.currency),
+        amount: Some(req.amount),
+        ..Default::default()
+    };
+    let connector =
+        get_connector_data(state, &merchant_account, req.connector, &routing_input).await?;
+
+    let payout_new = storage::PayoutsNew {
+        payout_id: payout_id.clone(),
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id,
+        connector: Some(connector.connector_name.to_string()),
+        connector_payout_id: None,
+        payout_type: req.payout_type.foreign_into(),
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        status: enums::PayoutStatus::RequiresFulfillment,
+        description: req.description,
+        metadata: req.metadata,
+        ..storage::PayoutsNew::default()
+    };
+
+    let payout = db.insert_payout(payout_new).await.map_err(|error| {
+        error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+    })?;
+
+    let payout = if confirm {
+        fulfill_payout(
+            state,
+            &merchant_account,
+            &connector,
+            payout,
+            req.payout_method_data,
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT UPDATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_update_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: &str,
+    req: payouts::PayoutUpdateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let payout = find_payout(state, &merchant_account, payout_id).await?;
+
+    utils::when(
+        payout.status != enums::PayoutStatus::RequiresFulfillment,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot update this payout because it has status {}",
+                    payout.status
+                ),
+            }))
+        },
+    )?;
+
+    if let Some(amount) = req.amount {
+        validate_payout_amount(amount)?;
+    }
+
+    let confirm = req.confirm.unwrap_or(false);
+    utils::when(confirm && req.payout_method_data.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_method_data"
+        }))
+    })?;
+
+    let payout_update = storage::PayoutsUpdate::Update {
+        amount: req.amount,
+        currency: req.currency.map(ForeignInto::foreign_into),
+        payout_type: req.payout_type.map(ForeignInto::foreign_into),
+        customer_id: req.customer_id,
+        description: req.description,
+        metadata: req.metadata,
+    };
+
+    let payout = db
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update payout with payout_id: {payout_id}"))?;
+
+    let payout = if confirm {
+        let connector = get_connector_data_for_payout(state, &merchant_account, &payout).await?;
+        fulfill_payout(
+            state,
+            &merchant_account,
+            &connector,
+            payout,
+            req.payout_method_data,
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    validate_payout_cancellation(payout.status)?;
+
+    // The payout was never sent to the connector, it can be cancelled locally
+    let payout_update = if payout.status == enums::PayoutStatus::RequiresFulfillment {
+        storage::PayoutsUpdate::StatusUpdate {
+            connector: None,
+            connector_payout_id: None,
+            status: enums::PayoutStatus::Cancelled,
+        }
+    } else {
+        let connector = get_connector_data_for_payout(state, &merchant_account, &payout).await?;
+        let router_data = call_connector_payout::<api::PoCancel>(
+            state,
+            &merchant_account,
+            &connector,
+            &payout,
+            None,
+        )
+        .await?;
+        let response = router_data
+            .response
+            .map_err(|err| get_connector_error(&connector, err))?;
+        storage::PayoutsUpdate::StatusUpdate {
+            connector: None,
+            connector_payout_id: Some(response.connector_payout_id),
+            status: response.status,
+        }
+    };
+
+    let payout = db
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update payout with payout_id: {payout_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+/// Only payouts which have not been paid out by the connector can be cancelled
+fn validate_payout_cancellation(status: enums::PayoutStatus) -> RouterResult<()> {
+    utils::when(
+        !matches!(
+            status,
+            enums::PayoutStatus::RequiresFulfillment | enums::PayoutStatus::Pending
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("You cannot cancel this payout because it has status {status}"),
+            }))
+        },
+    )
+}
+
+// ********************************************** PAYOUT REVERSE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_reverse_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    validate_payout_reversal(payout.status)?;
+
+    let connector = get_connector_data_for_payout(state, &merchant_account, &payout).await?;
+    let router_data = call_connector_payout::<api::PoReverse>(
+        state,
+        &merchant_account,
+        &connector,
+        &payout,
+        None,
+    )
+    .await?;
+    let response = router_data
+        .response
+        .map_err(|err| get_connector_error(&connector, err))?;
+
+    // The payout keeps its reference at the connector, which the reversal webhooks refer to
+    let payout_update = storage::PayoutsUpdate::StatusUpdate {
+        connector: None,
+        connector_payout_id: None,
+        status: response.status,
+    };
+    let payout = db
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update payout with payout_id: {payout_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+/// Only payouts which have been paid out by the connector can be reversed, payouts yet to be paid
+/// out are to be cancelled instead
+fn validate_payout_reversal(status: enums::PayoutStatus) -> RouterResult<()> {
+    utils::when(status != enums::PayoutStatus::Success, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("You cannot reverse this payout because it has status {status}"),
+        }))
+    })
+}
+
+// ********************************************** PAYOUT ACCOUNTS **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_accounts_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutAccountsListRequest,
+) -> RouterResponse<payouts::PayoutAccountsListResponse> {
+    let payouts = state
+        .store
+        .find_fulfilled_payouts_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            &req.customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payouts of the customer")?;
+
+    let data = get_payout_accounts(payouts);
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutAccountsListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// The distinct accounts the payouts were fulfilled to, given the payouts latest first
+fn get_payout_accounts(payouts: Vec<storage::Payouts>) -> Vec<payouts::PayoutAccount> {
+    let mut accounts: Vec<payouts::PayoutAccount> = Vec::new();
+    for payout in payouts {
+        let payout_type: api_enums::PayoutType = payout.payout_type.foreign_into();
+        let payout_account = match payout.payout_account {
+            Some(payout_account) => payout_account,
+            None => continue,
+        };
+        let is_listed = accounts.iter().any(|account| {
+            account.payout_type == payout_type && account.payout_account == payout_account
+        });
+        if !is_listed {
+            accounts.push(payouts::PayoutAccount {
+                payout_type,
+                payout_account,
+                last_payout_id: payout.payout_id,
+                last_used_at: payout.created_at,
+            });
+        }
+    }
+    accounts
+}
+
+// ********************************************** PAYOUT LIST **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn payouts_list_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutListRequest,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    let db = &*state.store;
+    let payouts = db
+        .filter_payouts_by_merchant_id(
+            &merchant_account.merchant_id,
+            req.customer_id.as_deref(),
+            req.limit,
+            req.offset,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+
+    let data: Vec<payouts::PayoutResponse> =
+        payouts.into_iter().map(ForeignInto::foreign_into).collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+// ********************************************** HELPERS **********************************************
+
+async fn find_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<storage::Payouts> {
+    state
+        .store
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))
+}
+
+fn validate_payout_amount(amount: i64) -> RouterResult<()> {
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than or equal to zero"))
+    })
+}
+
+/// Picks the connector passed in the request, falling back to the merchant's routing algorithm
+async fn get_connector_data(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: Option<api_enums::Connector>,
+    routing_input: &payments::routing::RoutingInput,
+) -> RouterResult<api::ConnectorData> {
+    let connector_name = match connector {
+        Some(connector) => connector.to_string(),
+        None => {
+            payments::routing::get_connector_name_from_routing_algorithm(
+                state,
+                merchant_account,
+                routing_input,
+            )
+            .await?
+        }
+    };
+
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+    .attach_printable("Failed to get the connector")
+}
+
+async fn get_connector_data_for_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payouts,
+) -> RouterResult<api::ConnectorData> {
+    let connector: api_enums::Connector = payout
+        .connector
+        .clone()
+        .parse_enum("Connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector found for payout")?;
+    get_connector_data(
+        state,
+        merchant_account,
+        Some(connector),
+        &payments::routing::RoutingInput::default(),
+    )
+    .await
+}
+
+fn get_connector_error(
+    connector: &api::ConnectorData,
+    err: types::ErrorResponse,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::ExternalConnectorError {
+        code: err.code,
+        message: err.message,
+        connector: connector.connector_name.to_string(),
+        status_code: err.status_code,
+    })
+}
+
+async fn fulfill_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payout: storage::Payouts,
+    payout_method_data: Option<api::PayoutMethodData>,
+) -> RouterResult<storage::Payouts> {
+    let router_data = call_connector_payout::<api::PoFulfill>(
+        state,
+        merchant_account,
+        connector,
+        &payout,
+        payout_method_data,
+    )
+    .await?;
+
+    let payout_update = match router_data.response {
+        Err(err) => storage::PayoutsUpdate::ErrorUpdate {
+            status: enums::PayoutStatus::Failed,
+            error_message: Some(err.message),
+            error_code: Some(err.code),
+        },
+        Ok(response) => storage::PayoutsUpdate::FulfillUpdate {
+            connector: Some(connector.connector_name.to_string()),
+            connector_payout_id: Some(response.connector_payout_id),
+            status: response.status,
+            // Accounts the connector refused to pay out to are not listed as payout accounts
+            payout_account: router_data
+                .request
+                .payout_method_data
+                .as_ref()
+                .filter(|_| response.status != enums::PayoutStatus::Failed)
+                .map(mask_payout_account),
+        },
+    };
+
+    let payout_id = payout.payout_id.clone();
+    state
+        .store
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed while updating payout: payout_id: {payout_id}"))
+}
+
+/// The card number or IBAN of the payout destination, with all but its last four characters masked
+fn mask_payout_account(payout_method_data: &api::PayoutMethodData) -> String {
+    let account = match payout_method_data {
+        api::PayoutMethodData::Card(card) => card.card_number.peek(),
+        api::PayoutMethodData::Bank(bank) => bank.iban.peek(),
+    };
+    let account: Vec<char> = account.chars().filter(|c| !c.is_whitespace()).collect();
+    let masked_length = account.len().saturating_sub(4);
+    account
+        .iter()
+        .enumerate()
+        .map(|(index, c)| if index < masked_length { '*' } else { *c })
+        .collect()
+}
+
+async fn call_connector_payout<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payout: &storage::Payouts,
+    payout_method_data: Option<api::PayoutMethodData>,
+) -> RouterResult<types::PayoutsRouterData<F>>
+where
+    F: Clone + Debug + Send + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let mut router_data = core_utils::construct_payout_router_data(
+        state,
+        &connector.connector_name.to_string(),
+        merchant_account,
+        payout,
+        payout_method_data,
+    )
+    .await?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, connector, merchant_account, &router_data).await?;
+
+    logger::debug!(payout_router_data=?router_data);
+
+    access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    if add_access_token_result.connector_supports_access_token && router_data.access_token.is_none()
+    {
+        return Ok(router_data);
+    }
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector.connector.get_connector_integration();
+    services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payout_cancellation() {
+        assert!(validate_payout_cancellation(enums::PayoutStatus::RequiresFulfillment).is_ok());
+        assert!(validate_payout_cancellation(enums::PayoutStatus::Pending).is_ok());
+        for status in [
+            enums::PayoutStatus::Success,
+            enums::PayoutStatus::Failed,
+            enums::PayoutStatus::Cancelled,
+            enums::PayoutStatus::Reversed,
+        ] {
+            assert!(matches!(
+                validate_payout_cancellation(status)
+                    .map_err(|error| error.current_context().clone()),
+                Err(errors::ApiErrorResponse::PreconditionFailed { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_payout_reversal() {
+        assert!(validate_payout_reversal(enums::PayoutStatus::Success).is_ok());
+        for status in [
+            enums::PayoutStatus::RequiresFulfillment,
+            enums::PayoutStatus::Pending,
+            enums::PayoutStatus::Failed,
+            enums::PayoutStatus::Cancelled,
+            enums::PayoutStatus::Reversed,
+        ] {
+            assert!(matches!(
+                validate_payout_reversal(status).map_err(|error| error.current_context().clone()),
+                Err(errors::ApiErrorResponse::PreconditionFailed { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_mask_payout_account() {
+        let card = api::PayoutMethodData::Card(api::PayoutCard {
+            card_number: "4111111111111111".to_string().into(),
+            card_exp_month: "03".to_string().into(),
+            card_exp_year: "2030".to_string().into(),
+            card_holder_name: "John Doe".to_string().into(),
+        });
+        assert_eq!(mask_payout_account(&card), "************1111");
+
+        let bank = api::PayoutMethodData::Bank(api::PayoutBank {
+            iban: "NL46 TEST 0136 1699 72".to_string().into(),
+            bic: None,
+            bank_name: None,
+            bank_country_code: None,
+            account_holder_name: "John Doe".to_string().into(),
+        });
+        assert_eq!(mask_payout_account(&bank), "**************6972");
+    }
+
+    #[test]
+    fn test_get_payout_accounts() {
+        let payout = |payout_id: &str, payout_account: Option<&str>| storage::Payouts {
+            id: 1,
+            payout_id: payout_id.to_string(),
+            merchant_id: "merchant_1".to_string(),
+            customer_id: Some("customer_1".to_string()),
+            connector: Some("adyen".to_string()),
+            connector_payout_id: None,
+            payout_type: enums::PayoutType::Card,
+            amount: 1000,
+            currency: enums::Currency::EUR,
+            status: enums::PayoutStatus::Success,
+            description: None,
+            metadata: None,
+            error_message: None,
+            error_code: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            payout_account: payout_account.map(ToString::to_string),
+        };
+        let accounts = get_payout_accounts(vec![
+            payout("payout_3", Some("************1111")),
+            payout("payout_2", None),
+            payout("payout_1", Some("************1111")),
+            payout("payout_0", Some("************4242")),
+        ]);
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].payout_account, "************1111");
+        assert_eq!(accounts[0].last_payout_id, "payout_3");
+        assert_eq!(accounts[1].payout_account, "************4242");
+        assert_eq!(accounts[1].last_payout_id, "payout_0");
+    }
+}
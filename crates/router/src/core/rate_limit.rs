@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use common_utils::crypto::{GenerateDigest, Sha512};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{
+    api_keys,
+    errors::{self, RouterResult},
+};
+use crate::{
+    configs::settings::{RateLimitSettings, TokenBucket},
+    logger,
+    routes::AppState,
+    utils::StringExt,
+};
+
+/// Key of the config overriding the rate limits of the merchant
+pub fn get_rate_limit_config_key(merchant_id: &str) -> String {
+    format!("rate_limit_{merchant_id}")
+}
+
+/// Rate limits of a merchant overriding the limits in the settings, the route limits being merged
+/// with the route limits in the settings
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct MerchantRateLimits {
+    pub merchant: Option<TokenBucket>,
+    pub api_key: Option<TokenBucket>,
+    #[serde(default)]
+    pub routes: HashMap<String, TokenBucket>,
+}
+
+impl MerchantRateLimits {
+    fn into_buckets(
+        self,
+        settings: &RateLimitSettings,
+        merchant_id: &str,
+        api_key_hash: &str,
+        route: Option<&str>,
+    ) -> Vec<(String, TokenBucket)> {
+        // The merchant ID is used as the hash tag of the keys, so that the buckets of a request
+        // are on the same Redis cluster node and can be consumed atomically
+        let mut buckets = vec![
+            (
+                format!("rate_limit_{{{merchant_id}}}_merchant"),
+                self.merchant.unwrap_or(settings.merchant),
+            ),
+            (
+                format!("rate_limit_{{{merchant_id}}}_api_key_{api_key_hash}"),
+                self.api_key.unwrap_or(settings.api_key),
+            ),
+        ];
+        if let Some(route) = route {
+            if let Some(bucket) = self
+                .routes
+                .get(route)
+                .or_else(|| settings.routes.get(route))
+            {
+                buckets.push((
+                    format!("rate_limit_{{{merchant_id}}}_route_{route}"),
+                    *bucket,
+                ));
+            }
+        }
+        buckets
+    }
+}
+
+async fn get_merchant_rate_limits(state: &AppState, merchant_id: &str) -> MerchantRateLimits {
+    let key = get_rate_limit_config_key(merchant_id);
+    match state.store.find_config_by_key_cached(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("MerchantRateLimits")
+            .unwrap_or_else(|error| {
+                logger::error!(?error, "Invalid rate limits configured for the merchant");
+                MerchantRateLimits::default()
+            }),
+        // The limits in the settings apply unless overridden for the merchant
+        Err(_) => MerchantRateLimits::default(),
+    }
+}
+
+/// Whether the API key is one of the keys which are not merchant secret keys, and by which
+/// requests are not rate limited
+fn is_non_merchant_api_key(state: &AppState, api_key: &str) -> bool {
+    api_key == state.conf.secrets.admin_api_key
+        || api_key.starts_with("pk_")
+        || api_key.starts_with("epk")
+}
+
+/// Consume a token from the buckets of the merchant, the API key and the route of the request,
+/// failing with the time after which the request can be retried if any of them is empty.
+/// Only requests whose API key resolves to a merchant secret key are rate limited, `None` being
+/// returned for other requests, which are left to be authenticated by their route.
+#[instrument(skip_all)]
+pub async fn check_rate_limits(
+    state: &AppState,
+    api_key: &str,
+    route: Option<&str>,
+) -> RouterResult<Option<api_keys::VerifiedApiKey>> {
+    if is_non_merchant_api_key(state, api_key) {
+        return Ok(None);
+    }
+    let verified_api_key =
+        match api_keys::resolve_api_key(&*state.store, &state.conf.api_keys, api_key).await {
+            Ok(verified_api_key) => verified_api_key,
+            Err(error) => {
+                logger::debug!(
+                    ?error,
+                    "API key not resolved to a merchant for rate limiting"
+                );
+                return Ok(None);
+            }
+        };
+    let merchant_id = match &verified_api_key {
+        api_keys::VerifiedApiKey::Hashed(api_key) => api_key.merchant_id.as_str(),
+        api_keys::VerifiedApiKey::Legacy(merchant_account) => merchant_account.merchant_id.as_str(),
+    };
+    let api_key_hash = Sha512
+        .generate_digest(api_key.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the API key for rate limiting")?;
+
+    let buckets = get_merchant_rate_limits(state, merchant_id)
+        .await
+        .into_buckets(&state.conf.rate_limit, merchant_id, &api_key_hash, route);
+
+    match state.store.consume_rate_limit_tokens(buckets).await {
+        Ok(None) => Ok(Some(verified_api_key)),
+        Ok(Some(retry_after_millis)) => {
+            let retry_after_secs = ((retry_after_millis + 999) / 1000).max(1);
+            Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_secs
+            }))
+        }
+        // Requests are not rejected when the limits cannot be enforced
+        Err(error) => {
+            logger::error!(?error, "Failed to consume rate limit tokens");
+            Ok(Some(verified_api_key))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_merchant_rate_limits_override_settings() {
+        let settings = RateLimitSettings {
+            enabled: true,
+            merchant: TokenBucket {
+                capacity: 200,
+                refill_rate: 100.0,
+            },
+            api_key: TokenBucket {
+                capacity: 100,
+                refill_rate: 50.0,
+            },
+            routes: HashMap::from([(
+                "POST /payments".to_string(),
+                TokenBucket {
+                    capacity: 50,
+                    refill_rate: 25.0,
+                },
+            )]),
+        };
+        let config = r#"{"merchant":{"capacity":10,"refill_rate":1.0}}"#.to_string();
+        let merchant_rate_limits: MerchantRateLimits =
+            config.parse_struct("MerchantRateLimits").unwrap();
+
+        let buckets = merchant_rate_limits.into_buckets(
+            &settings,
+            "merchant_1",
+            "hash",
+            Some("POST /payments"),
+        );
+        assert_eq!(
+            buckets,
+            vec![
+                (
+                    "rate_limit_{merchant_1}_merchant".to_string(),
+                    TokenBucket {
+                        capacity: 10,
+                        refill_rate: 1.0,
+                    },
+                ),
+                (
+                    "rate_limit_{merchant_1}_api_key_hash".to_string(),
+                    settings.api_key,
+                ),
+                (
+                    "rate_limit_{merchant_1}_route_POST /payments".to_string(),
+                    TokenBucket {
+                        capacity: 50,
+                        refill_rate: 25.0,
+                    },
+                ),
+            ]
+        );
+
+        let buckets = MerchantRateLimits::default().into_buckets(
+            &settings,
+            "merchant_1",
+            "hash",
+            Some("GET /payments/{payment_id}"),
+        );
+        assert_eq!(buckets.len(), 2);
+    }
+}
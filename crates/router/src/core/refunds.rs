@@ -8,7 +8,7 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token},
-        utils as core_utils,
+        utils as core_utils, webhooks,
     },
     db, logger,
     routes::AppState,
@@ -18,7 +18,7 @@ use crate::{
         self,
         api::{self, refunds},
         storage::{self, enums, ProcessTrackerExt},
-        transformers::{Foreign, ForeignInto},
+        transformers::{Foreign, ForeignInto, ForeignTryInto},
     },
     utils::{self, OptionExt},
 };
@@ -188,6 +188,7 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    trigger_refund_outgoing_webhook(state, merchant_account, &response).await;
     Ok(response)
 }
 
@@ -353,9 +354,41 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    if response.refund_status != refund.refund_status {
+        trigger_refund_outgoing_webhook(state, merchant_account, &response).await;
+    }
     Ok(response)
 }
 
+/// Notify the merchant once the refund reaches a final status
+#[instrument(skip_all)]
+pub async fn trigger_refund_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    refund: &storage::Refund,
+) {
+    let event_type: enums::EventType = match refund.refund_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+
+    let result = webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        &refund.connector,
+        event_type,
+        enums::EventClass::Refunds,
+        Some(refund.payment_id.clone()),
+        refund.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund.clone().foreign_into()),
+    )
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to trigger outgoing webhook for the refund");
+    }
+}
+
 // ********************************************** REFUND UPDATE **********************************************
 
 pub async fn refund_update_core(
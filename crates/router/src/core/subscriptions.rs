@@ -0,0 +1,763 @@
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::{
+    errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    payments,
+};
+use crate::{
+    configs::settings,
+    consts,
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const SUBSCRIPTION_RUNNER: &str = "SUBSCRIPTION_WORKFLOW";
+const SUBSCRIPTION_TASK: &str = "SUBSCRIPTION_CHARGE";
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SubscriptionTrackingData {
+    pub merchant_id: String,
+    pub subscription_id: String,
+}
+
+/// The outcome of charging a subscription for its current billing cycle
+#[derive(Debug)]
+pub enum ChargeOutcome {
+    Charged {
+        payment_id: String,
+    },
+    Failed {
+        payment_id: Option<String>,
+    },
+    /// The payment made for the billing cycle is still being processed, the billing cycle is
+    /// charged again only once the payment has failed
+    Pending {
+        payment_id: String,
+    },
+    /// The mandate of the subscription can no longer be charged
+    MandateInactive,
+}
+
+fn get_subscription_process_tracker_id(merchant_id: &str, subscription_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        SUBSCRIPTION_RUNNER,
+        SUBSCRIPTION_TASK,
+        subscription_id,
+        merchant_id,
+    )
+}
+
+/// Time at which a billing cycle of the subscription is due, counting the intervals from the
+/// billing anchor rather than from the previous charge, so that the charges do not drift. Months
+/// and years are added on the calendar, with the day clamped to the length of shorter months.
+pub fn get_charge_time(
+    billing_anchor: PrimitiveDateTime,
+    interval: storage_enums::SubscriptionInterval,
+    interval_count: i32,
+    billing_cycle: i32,
+) -> Option<PrimitiveDateTime> {
+    let intervals = interval_count.checked_mul(billing_cycle)?;
+    match interval {
+        storage_enums::SubscriptionInterval::Day => {
+            billing_anchor.checked_add(time::Duration::days(intervals.into()))
+        }
+        storage_enums::SubscriptionInterval::Week => {
+            billing_anchor.checked_add(time::Duration::weeks(intervals.into()))
+        }
+        storage_enums::SubscriptionInterval::Month => add_months(billing_anchor, intervals),
+        storage_enums::SubscriptionInterval::Year => {
+            add_months(billing_anchor, intervals.checked_mul(12)?)
+        }
+    }
+}
+
+fn add_months(date_time: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let month_index = date_time
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date_time.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date_time
+        .day()
+        .min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+    Some(PrimitiveDateTime::new(date, date_time.time()))
+}
+
+/// The first billing cycle of the subscription which is due at or after `now`, along with the time
+/// at which it is due
+fn get_next_billing_cycle(
+    subscription: &storage::Subscription,
+    now: PrimitiveDateTime,
+) -> Option<(i32, PrimitiveDateTime)> {
+    let mut billing_cycle = subscription.billing_cycle;
+    loop {
+        let charge_time = get_charge_time(
+            subscription.billing_anchor,
+            subscription.billing_interval,
+            subscription.interval_count,
+            billing_cycle,
+        )?;
+        if charge_time >= now {
+            return Some((billing_cycle, charge_time));
+        }
+        billing_cycle = billing_cycle.checked_add(1)?;
+    }
+}
+
+/// Time at which a failed charge of a subscription is retried, backing off exponentially from the
+/// configured base interval. Returns `None` once the maximum number of retries is made.
+pub fn get_dunning_retry_schedule_time(
+    subscriptions: &settings::SubscriptionSettings,
+    retry_count: i32,
+) -> Option<PrimitiveDateTime> {
+    let retry_count = u32::try_from(retry_count).ok()?;
+    (retry_count < subscriptions.dunning_max_retries).then(|| {
+        let delay_secs = subscriptions
+            .dunning_retry_base_interval_secs
+            .saturating_mul(2_i64.saturating_pow(retry_count));
+        common_utils::date_time::now().saturating_add(time::Duration::seconds(delay_secs))
+    })
+}
+
+/// Time at which the payment of a subscription which is still being processed is checked again.
+/// The check does not count as a retry of the charge of the subscription.
+pub fn get_pending_charge_schedule_time(
+    subscriptions: &settings::SubscriptionSettings,
+) -> PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        subscriptions.pending_charge_check_interval_secs,
+    ))
+}
+
+// Payments which are still being processed are not retried, so that the customer is not charged
+// twice for the same billing cycle
+fn is_charge_successful(status: api_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::Processing
+            | api_enums::IntentStatus::RequiresCapture
+    )
+}
+
+fn get_subscription_response(subscription: storage::Subscription) -> api::SubscriptionResponse {
+    let next_charge_at = matches!(
+        subscription.status,
+        storage_enums::SubscriptionStatus::Active | storage_enums::SubscriptionStatus::PastDue
+    )
+    .then_some(subscription.next_charge_at);
+    api::SubscriptionResponse {
+        subscription_id: subscription.subscription_id,
+        merchant_id: subscription.merchant_id,
+        customer_id: subscription.customer_id,
+        mandate_id: subscription.mandate_id,
+        status: subscription.status.foreign_into(),
+        amount: subscription.amount,
+        currency: subscription.currency.foreign_into(),
+        interval: subscription.billing_interval.foreign_into(),
+        interval_count: subscription.interval_count,
+        next_charge_at,
+        last_payment_id: subscription.last_payment_id,
+        description: subscription.description,
+        metadata: subscription.metadata,
+        created_at: subscription.created_at,
+    }
+}
+
+fn validate_subscription_status(
+    subscription: &storage::Subscription,
+    allowed_statuses: &[storage_enums::SubscriptionStatus],
+    action: &str,
+) -> RouterResult<()> {
+    utils::when(!allowed_statuses.contains(&subscription.status), || {
+        Err(report!(
+            errors::ApiErrorResponse::SubscriptionStatusValidationFailed {
+                reason: format!(
+                    "This subscription cannot be {action} because it has status {}",
+                    subscription.status
+                ),
+            }
+        ))
+    })
+}
+
+async fn find_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    db.find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound)
+        })
+}
+
+async fn update_subscription(
+    db: &dyn StorageInterface,
+    subscription: storage::Subscription,
+    subscription_update: storage::SubscriptionUpdate,
+) -> RouterResult<storage::Subscription> {
+    let subscription_id = subscription.subscription_id.clone();
+    db.update_subscription(subscription, subscription_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update subscription with subscription_id: {subscription_id}")
+        })
+}
+
+/// Schedule the charge of the subscription at its next charge time
+async fn add_subscription_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = SubscriptionTrackingData {
+        merchant_id: subscription.merchant_id.clone(),
+        subscription_id: subscription.subscription_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        get_subscription_process_tracker_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+        ),
+        SUBSCRIPTION_TASK,
+        SUBSCRIPTION_RUNNER,
+        tracking_data,
+        subscription.next_charge_at,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Schedule the charge of the next billing cycle of the subscription, resetting the retries made
+/// for the previous billing cycle
+pub async fn reschedule_subscription_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> errors::CustomResult<(), errors::StorageError> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_subscription_process_tracker_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+        )],
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(subscription.next_charge_at),
+            tracking_data: None,
+            business_status: Some("Pending".to_string()),
+            status: Some(storage_enums::ProcessTrackerStatus::Pending),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Stop charging the subscription until it is resumed
+async fn finish_subscription_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    business_status: &str,
+) -> RouterResult<()> {
+    db.process_tracker_update_process_status_by_ids(
+        vec![get_subscription_process_tracker_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+        )],
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: storage_enums::ProcessTrackerStatus::Finish,
+            business_status: Some(business_status.to_string()),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while stopping the charges of the subscription")?;
+    Ok(())
+}
+
+#[instrument(skip(state))]
+pub async fn create_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::SubscriptionCreateRequest,
+) -> RouterResponse<api::SubscriptionResponse> {
+    let db = &*state.store;
+    let now = common_utils::date_time::now();
+    let interval_count = req.interval_count.unwrap_or(1);
+    if req.amount <= 0 {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })?
+    }
+    if interval_count <= 0 {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "interval_count",
+        })?
+    }
+    if req.start_at.map_or(false, |start_at| start_at < now) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_at must not be in the past".to_string(),
+        })?
+    }
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+    utils::when(
+        mandate.mandate_status != storage_enums::MandateStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "subscriptions can only be charged against active mandates".to_string(),
+            }))
+        },
+    )?;
+    utils::when(
+        mandate.mandate_type != storage_enums::MandateType::MultiUse,
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "subscriptions can only be charged against multi use mandates".to_string(),
+            }))
+        },
+    )?;
+    let currency: storage_enums::Currency = req.currency.foreign_into();
+    utils::when(
+        mandate
+            .mandate_currency
+            .map_or(false, |mandate_currency| mandate_currency != currency),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "currency of the subscription must match the currency of the mandate"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    let billing_anchor = req.start_at.unwrap_or(now);
+    let subscription_new = storage::SubscriptionNew {
+        subscription_id: utils::generate_id(consts::ID_LENGTH, "sub"),
+        merchant_id: merchant_account.merchant_id,
+        customer_id: mandate.customer_id,
+        mandate_id: mandate.mandate_id,
+        status: storage_enums::SubscriptionStatus::Active,
+        amount: req.amount,
+        currency,
+        billing_interval: req.interval.foreign_into(),
+        interval_count,
+        billing_anchor,
+        billing_cycle: 0,
+        next_charge_at: billing_anchor,
+        description: req.description,
+        metadata: req.metadata,
+        created_at: None,
+        modified_at: None,
+    };
+    let subscription = db
+        .insert_subscription(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert subscription")?;
+
+    add_subscription_task(db, &subscription)
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to schedule the charges of the subscription")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<api::SubscriptionResponse> {
+    let subscription = find_subscription(
+        &*state.store,
+        &merchant_account.merchant_id,
+        &subscription_id,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn pause_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<api::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription =
+        find_subscription(db, &merchant_account.merchant_id, &subscription_id).await?;
+    validate_subscription_status(
+        &subscription,
+        &[
+            storage_enums::SubscriptionStatus::Active,
+            storage_enums::SubscriptionStatus::PastDue,
+        ],
+        "paused",
+    )?;
+
+    finish_subscription_task(db, &subscription, "SUBSCRIPTION_PAUSED").await?;
+    let subscription = update_subscription(
+        db,
+        subscription,
+        storage::SubscriptionUpdate::StatusUpdate {
+            status: storage_enums::SubscriptionStatus::Paused,
+        },
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+/// Resume charging a paused subscription from the first billing cycle which is not yet due, so
+/// that the customer is not charged for the billing cycles which fell due while it was paused
+#[instrument(skip(state))]
+pub async fn resume_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<api::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription =
+        find_subscription(db, &merchant_account.merchant_id, &subscription_id).await?;
+    validate_subscription_status(
+        &subscription,
+        &[storage_enums::SubscriptionStatus::Paused],
+        "resumed",
+    )?;
+
+    let (billing_cycle, next_charge_at) =
+        get_next_billing_cycle(&subscription, common_utils::date_time::now())
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Failed to compute the next charge time of the subscription")?;
+    let subscription = update_subscription(
+        db,
+        subscription,
+        storage::SubscriptionUpdate::ScheduleUpdate {
+            status: storage_enums::SubscriptionStatus::Active,
+            billing_cycle,
+            next_charge_at,
+            last_payment_id: None,
+        },
+    )
+    .await?;
+    reschedule_subscription_task(db, &subscription)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to schedule the charges of the subscription")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_subscription(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    subscription_id: String,
+) -> RouterResponse<api::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription =
+        find_subscription(db, &merchant_account.merchant_id, &subscription_id).await?;
+    validate_subscription_status(
+        &subscription,
+        &[
+            storage_enums::SubscriptionStatus::Active,
+            storage_enums::SubscriptionStatus::PastDue,
+            storage_enums::SubscriptionStatus::Paused,
+        ],
+        "cancelled",
+    )?;
+
+    finish_subscription_task(db, &subscription, "SUBSCRIPTION_CANCELLED").await?;
+    let subscription = update_subscription(
+        db,
+        subscription,
+        storage::SubscriptionUpdate::StatusUpdate {
+            status: storage_enums::SubscriptionStatus::Cancelled,
+        },
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(
+        get_subscription_response(subscription),
+    ))
+}
+
+/// Charge the subscription for its current billing cycle with an off-session payment against its
+/// mandate. The payment made for the billing cycle is recorded before the connector is called, and
+/// is checked on the next charge of the billing cycle so that the customer is charged again only
+/// once the payment has failed.
+#[instrument(skip_all)]
+pub async fn charge_subscription(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    subscription: &storage::Subscription,
+) -> RouterResult<ChargeOutcome> {
+    let db = &*state.store;
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &subscription.mandate_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MandateNotFound)?;
+    if mandate.mandate_status != storage_enums::MandateStatus::Active {
+        return Ok(ChargeOutcome::MandateInactive);
+    }
+
+    let pending_payment = match subscription.pending_payment_id.as_ref() {
+        Some(payment_id) => match db
+            .find_payment_intent_by_payment_id_merchant_id(
+                payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_intent) => Some(payment_intent),
+            // The payment was not created, the billing cycle is charged with the same payment ID
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while finding the pending payment of the subscription")?,
+        },
+        None => None,
+    };
+
+    let req = api::PaymentsRequest {
+        amount: Some(subscription.amount.into()),
+        currency: Some(subscription.currency.foreign_into()),
+        customer_id: Some(subscription.customer_id.clone()),
+        mandate_id: Some(subscription.mandate_id.clone()),
+        off_session: Some(true),
+        confirm: Some(true),
+        description: subscription.description.clone(),
+        ..Default::default()
+    };
+    let (payment_id, result) = match pending_payment {
+        Some(payment_intent) => {
+            match get_pending_charge_action(payment_intent.status.foreign_into()) {
+                PendingChargeAction::Charged => {
+                    return Ok(ChargeOutcome::Charged {
+                        payment_id: payment_intent.payment_id,
+                    })
+                }
+                PendingChargeAction::Await => {
+                    return Ok(ChargeOutcome::Pending {
+                        payment_id: payment_intent.payment_id,
+                    })
+                }
+                // The payment did not reach the connector, it is confirmed instead of being made again
+                PendingChargeAction::Confirm => {
+                    let payment_id = payment_intent.payment_id;
+                    let result =
+                        payments::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+                            state,
+                            merchant_account.clone(),
+                            payments::PaymentConfirm,
+                            api::PaymentsRequest {
+                                payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                                    payment_id.clone(),
+                                )),
+                                ..req
+                            },
+                            services::AuthFlow::Merchant,
+                            payments::CallConnectorAction::Trigger,
+                        )
+                        .await;
+                    (payment_id, result)
+                }
+                PendingChargeAction::Charge => {
+                    let payment_id = add_pending_payment(db, subscription).await?;
+                    let result = create_charge(state, merchant_account, &payment_id, req).await;
+                    (payment_id, result)
+                }
+            }
+        }
+        None => {
+            let payment_id = match subscription.pending_payment_id.clone() {
+                Some(payment_id) => payment_id,
+                None => add_pending_payment(db, subscription).await?,
+            };
+            let result = create_charge(state, merchant_account, &payment_id, req).await;
+            (payment_id, result)
+        }
+    };
+
+    match result {
+        Ok(services::ApplicationResponse::Json(payment))
+            if is_charge_successful(payment.status) =>
+        {
+            Ok(ChargeOutcome::Charged { payment_id })
+        }
+        Ok(_) => Ok(ChargeOutcome::Failed {
+            payment_id: Some(payment_id),
+        }),
+        // The outcome of the payment is checked on the next charge of the billing cycle
+        Err(error) => {
+            logger::error!(?error, "Charge of the subscription failed");
+            Ok(ChargeOutcome::Failed {
+                payment_id: Some(payment_id),
+            })
+        }
+    }
+}
+
+/// What is done with the payment made for the billing cycle on the next charge of the billing cycle
+#[derive(Debug, PartialEq, Eq)]
+enum PendingChargeAction {
+    /// The billing cycle is charged by the payment
+    Charged,
+    /// The payment can still succeed, the billing cycle is not charged again until it completes
+    Await,
+    /// The payment was created but not confirmed with the connector
+    Confirm,
+    /// The payment failed, the billing cycle is charged with a new payment
+    Charge,
+}
+
+fn get_pending_charge_action(status: api_enums::IntentStatus) -> PendingChargeAction {
+    match status {
+        status if is_charge_successful(status) => PendingChargeAction::Charged,
+        api_enums::IntentStatus::RequiresPaymentMethod
+        | api_enums::IntentStatus::RequiresConfirmation => PendingChargeAction::Confirm,
+        api_enums::IntentStatus::Failed | api_enums::IntentStatus::Cancelled => {
+            PendingChargeAction::Charge
+        }
+        _ => PendingChargeAction::Await,
+    }
+}
+
+/// Record a new payment for the billing cycle before it is made
+async fn add_pending_payment(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<String> {
+    let payment_id = utils::generate_id(consts::ID_LENGTH, "pay");
+    update_subscription(
+        db,
+        subscription.clone(),
+        storage::SubscriptionUpdate::ChargeUpdate {
+            pending_payment_id: payment_id.clone(),
+        },
+    )
+    .await?;
+    Ok(payment_id)
+}
+
+async fn create_charge(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+    req: api::PaymentsRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    payments::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account.clone(),
+        payments::PaymentCreate,
+        api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.to_string())),
+            ..req
+        },
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_charge_time() {
+        let anchor = datetime!(2023-01-31 10:30);
+        let month = storage_enums::SubscriptionInterval::Month;
+        assert_eq!(
+            get_charge_time(anchor, month, 1, 1),
+            Some(datetime!(2023-02-28 10:30))
+        );
+        // The charges are counted from the anchor, so the day is not clamped for good
+        assert_eq!(
+            get_charge_time(anchor, month, 1, 2),
+            Some(datetime!(2023-03-31 10:30))
+        );
+        assert_eq!(
+            get_charge_time(anchor, month, 3, 4),
+            Some(datetime!(2024-01-31 10:30))
+        );
+        assert_eq!(
+            get_charge_time(
+                datetime!(2024-02-29 00:00),
+                storage_enums::SubscriptionInterval::Year,
+                1,
+                1
+            ),
+            Some(datetime!(2025-02-28 00:00))
+        );
+        assert_eq!(
+            get_charge_time(anchor, storage_enums::SubscriptionInterval::Week, 2, 3),
+            Some(datetime!(2023-03-14 10:30))
+        );
+    }
+
+    #[test]
+    fn test_pending_charge_action() {
+        assert_eq!(
+            get_pending_charge_action(api_enums::IntentStatus::Processing),
+            PendingChargeAction::Charged
+        );
+        assert_eq!(
+            get_pending_charge_action(api_enums::IntentStatus::RequiresCustomerAction),
+            PendingChargeAction::Await
+        );
+        assert_eq!(
+            get_pending_charge_action(api_enums::IntentStatus::RequiresConfirmation),
+            PendingChargeAction::Confirm
+        );
+        assert_eq!(
+            get_pending_charge_action(api_enums::IntentStatus::Failed),
+            PendingChargeAction::Charge
+        );
+    }
+
+    #[test]
+    fn test_charge_successful() {
+        assert!(is_charge_successful(api_enums::IntentStatus::Succeeded));
+        assert!(is_charge_successful(api_enums::IntentStatus::Processing));
+        assert!(!is_charge_successful(api_enums::IntentStatus::Failed));
+        assert!(!is_charge_successful(
+            api_enums::IntentStatus::RequiresCustomerAction
+        ));
+    }
+}
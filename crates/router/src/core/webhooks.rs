@@ -6,14 +6,16 @@ use masking::ExposeInterface;
 use router_env::{instrument, tracing};
 
 use crate::{
+    configs::settings,
     consts,
     core::{
         errors::{self, CustomResult, RouterResponse},
-        payments,
+        payments, refunds,
     },
     db::StorageInterface,
     logger,
     routes::AppState,
+    scheduler::utils as pt_utils,
     services,
     types::{
         api,
@@ -24,6 +26,9 @@ use crate::{
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_MS: u64 = 5000;
+const OUTGOING_WEBHOOK_RESPONSE_BODY_EXCERPT_LENGTH: usize = 1024;
+const OUTGOING_WEBHOOK_RETRY_RUNNER: &str = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
 
 #[instrument(skip_all)]
 async fn payments_incoming_webhook_flow(
@@ -31,6 +36,7 @@ async fn payments_incoming_webhook_flow(
     merchant_account: storage::MerchantAccount,
     webhook_details: api::IncomingWebhookDetails,
     source_verified: bool,
+    connector_name: &str,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let consume_or_trigger_flow = if source_verified {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
@@ -38,6 +44,15 @@ async fn payments_incoming_webhook_flow(
         payments::CallConnectorAction::Trigger
     };
 
+    // Connectors can notify the same status of a payment several times, the merchant is only
+    // notified when the status of the payment changes
+    let previous_status = get_payment_status_by_connector_transaction_id(
+        &state,
+        &merchant_account,
+        &webhook_details.object_reference_id,
+    )
+    .await;
+
     let payments_response = payments::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
         &state,
         merchant_account.clone(),
@@ -65,6 +80,16 @@ async fn payments_incoming_webhook_flow(
                 .get_required_value("payment_id")
                 .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
 
+            let status: enums::IntentStatus = payments_response.status.foreign_into();
+            if previous_status == Some(status) {
+                logger::info!(
+                    %payment_id,
+                    %status,
+                    "Payment status is unchanged by the webhook, not notifying the merchant"
+                );
+                return Ok(());
+            }
+
             let event_type: enums::EventType = payments_response
                 .status
                 .foreign_try_into()
@@ -74,6 +99,7 @@ async fn payments_incoming_webhook_flow(
             create_event_and_trigger_outgoing_webhook(
                 state,
                 merchant_account,
+                connector_name,
                 event_type,
                 enums::EventClass::Payments,
                 None,
@@ -90,11 +116,351 @@ async fn payments_incoming_webhook_flow(
     Ok(())
 }
 
+async fn get_payment_status_by_connector_transaction_id(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector_transaction_id: &str,
+) -> Option<enums::IntentStatus> {
+    let db = &*state.store;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .ok()?;
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_attempt.payment_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .ok()
+    .map(|payment_intent| payment_intent.status)
+}
+
+#[instrument(skip_all)]
+async fn refunds_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector_name: &str,
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let refund = db
+        .find_refund_by_merchant_id_connector_refund_id_connector(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            connector_name,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to find refund with connector refund id {}",
+                webhook_details.object_reference_id
+            )
+        })?;
+
+    // The refund status can only be trusted from a verified webhook, otherwise it is synced with
+    // the connector, which also notifies the merchant of the status change
+    if !source_verified {
+        refunds::refund_retrieve_core(&state, merchant_account, refund.refund_id)
+            .await
+            .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+        return Ok(());
+    }
+
+    let refund_status: enums::RefundStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+    if refund.refund_status == refund_status {
+        return Ok(());
+    }
+    // Webhooks can be delivered out of order, a refund which reached a final status is not
+    // changed by the webhooks of its earlier statuses
+    if is_refund_status_final(&refund.refund_status) {
+        logger::warn!(
+            refund_id = %refund.refund_id,
+            current_status = %refund.refund_status,
+            webhook_status = %refund_status,
+            "Ignoring refund webhook for a refund in a final status"
+        );
+        return Ok(());
+    }
+
+    let refund_update = storage::RefundUpdate::StatusUpdate {
+        connector_refund_id: None,
+        sent_to_gateway: true,
+        refund_status,
+    };
+    let updated_refund = db
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .change_context(errors::WebhooksFlowError::RefundsCoreFailed)
+        .attach_printable("Failed to update refund from incoming webhook")?;
+    refunds::trigger_refund_outgoing_webhook(&state, &merchant_account, &updated_refund).await;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn payouts_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Connectors settle bank payouts asynchronously and have no API to sync them, the status of
+    // the payout can only be trusted from a verified webhook
+    if !source_verified {
+        logger::error!("Incoming webhook source verification failed for payout webhook");
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let payout = db
+        .find_payout_by_merchant_id_connector_payout_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to find payout with connector payout id {}",
+                webhook_details.object_reference_id
+            )
+        })?;
+
+    let payout_status: enums::PayoutStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?;
+    if payout.status == payout_status {
+        return Ok(());
+    }
+    if !is_payout_status_transition_allowed(&payout.status, &payout_status) {
+        logger::warn!(
+            payout_id = %payout.payout_id,
+            current_status = %payout.status,
+            webhook_status = %payout_status,
+            "Ignoring payout webhook which does not follow the current status of the payout"
+        );
+        return Ok(());
+    }
+
+    let payout_update = storage::PayoutsUpdate::StatusUpdate {
+        connector: None,
+        connector_payout_id: None,
+        status: payout_status,
+    };
+    db.update_payout(payout, payout_update)
+        .await
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)
+        .attach_printable("Failed to update payout from incoming webhook")?;
+
+    Ok(())
+}
+
+/// Pending payouts are settled by the connector, while successful payouts can only be returned by
+/// the bank of the recipient
+fn is_payout_status_transition_allowed(
+    current_status: &enums::PayoutStatus,
+    payout_status: &enums::PayoutStatus,
+) -> bool {
+    match current_status {
+        enums::PayoutStatus::Pending => true,
+        enums::PayoutStatus::Success => matches!(payout_status, enums::PayoutStatus::Reversed),
+        _ => false,
+    }
+}
+
+fn is_refund_status_final(refund_status: &enums::RefundStatus) -> bool {
+    matches!(
+        refund_status,
+        enums::RefundStatus::Success
+            | enums::RefundStatus::Failure
+            | enums::RefundStatus::TransactionFailure
+    )
+}
+
+fn validate_dispute_stage(
+    prev_dispute_stage: &enums::DisputeStage,
+    dispute_stage: &enums::DisputeStage,
+) -> bool {
+    match prev_dispute_stage {
+        enums::DisputeStage::PreDispute => true,
+        enums::DisputeStage::Dispute => !matches!(dispute_stage, enums::DisputeStage::PreDispute),
+        enums::DisputeStage::PreArbitration => {
+            matches!(dispute_stage, enums::DisputeStage::PreArbitration)
+        }
+    }
+}
+
+fn validate_dispute_stage_and_dispute_status(
+    prev_dispute_stage: &enums::DisputeStage,
+    prev_dispute_status: &enums::DisputeStatus,
+    dispute_stage: &enums::DisputeStage,
+    dispute_status: &enums::DisputeStatus,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let dispute_stage_validation = validate_dispute_stage(prev_dispute_stage, dispute_stage);
+    // A dispute in a final status can only be moved forward by the connector opening a new stage
+    let dispute_status_validation = if dispute_stage == prev_dispute_stage {
+        matches!(
+            prev_dispute_status,
+            enums::DisputeStatus::DisputeOpened | enums::DisputeStatus::DisputeChallenged
+        ) || prev_dispute_status == dispute_status
+    } else {
+        true
+    };
+    common_utils::fp_utils::when(
+        !(dispute_stage_validation && dispute_status_validation),
+        || Err(errors::WebhooksFlowError::DisputeWebhookValidationFailed).into_report(),
+    )
+}
+
+#[instrument(skip_all)]
+async fn get_or_update_dispute_object(
+    state: AppState,
+    option_dispute: Option<storage::Dispute>,
+    dispute_details: api::DisputePayload,
+    merchant_id: &str,
+    payment_attempt: &storage::PaymentAttempt,
+    event_type: api::IncomingWebhookEvent,
+    connector_name: &str,
+) -> CustomResult<storage::Dispute, errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let dispute_status: enums::DisputeStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::DisputeWebhookValidationFailed)?;
+    match option_dispute {
+        None => {
+            let dispute_id = generate_id(consts::ID_LENGTH, "dp");
+            let new_dispute = storage::DisputeNew {
+                dispute_id,
+                amount: dispute_details.amount,
+                currency: dispute_details.currency,
+                dispute_stage: dispute_details.dispute_stage,
+                dispute_status,
+                payment_id: payment_attempt.payment_id.to_owned(),
+                attempt_id: payment_attempt.attempt_id.to_owned(),
+                merchant_id: merchant_id.to_owned(),
+                connector_status: dispute_details.connector_status,
+                connector_dispute_id: dispute_details.connector_dispute_id,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                dispute_created_at: dispute_details.created_at,
+                updated_at: dispute_details.updated_at,
+                connector: connector_name.to_string(),
+            };
+            db.insert_dispute(new_dispute)
+                .await
+                .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)
+        }
+        Some(dispute) => {
+            logger::info!("Dispute Already exists, Updating the dispute details");
+            validate_dispute_stage_and_dispute_status(
+                &dispute.dispute_stage,
+                &dispute.dispute_status,
+                &dispute_details.dispute_stage,
+                &dispute_status,
+            )?;
+            let update_dispute = storage::DisputeUpdate::Update {
+                dispute_stage: dispute_details.dispute_stage,
+                dispute_status,
+                connector_status: dispute_details.connector_status,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                updated_at: dispute_details.updated_at,
+            };
+            db.update_dispute(dispute, update_dispute)
+                .await
+                .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn create_event_and_trigger_outgoing_webhook(
+async fn disputes_incoming_webhook_flow(
     state: AppState,
     merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &(dyn api::Connector + Sync),
+    request_body: &[u8],
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    if !source_verified {
+        logger::error!("Incoming webhook source verification failed for dispute webhook");
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let dispute_details = connector
+        .get_dispute_details(request_body)
+        .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+    let option_dispute = db
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+            &dispute_details.connector_dispute_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+    let dispute_object = get_or_update_dispute_object(
+        state.clone(),
+        option_dispute,
+        dispute_details,
+        &merchant_account.merchant_id,
+        &payment_attempt,
+        event_type,
+        connector.id(),
+    )
+    .await?;
+    let disputes_response = Box::new(dispute_object.clone().foreign_into());
+    let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
+
+    create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        connector.id(),
+        event_type,
+        enums::EventClass::Disputes,
+        Some(dispute_object.payment_id),
+        dispute_object.dispute_id,
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+    )
+    .await
+}
+
+/// Record the event and notify the merchant of it, if the event is enabled in the webhook config
+/// of the merchant for the connector
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn create_event_and_trigger_outgoing_webhook(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    connector_name: &str,
     event_type: enums::EventType,
     event_class: enums::EventClass,
     intent_reference_id: Option<String>,
@@ -102,6 +468,18 @@ async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
+    let is_event_enabled = utils::lookup_webhook_event(
+        &*state.store,
+        connector_name,
+        &merchant_account.merchant_id,
+        &event_type.foreign_into(),
+    )
+    .await;
+    if !is_event_enabled {
+        logger::info!(%event_type, "Outgoing webhook event is not enabled for the merchant");
+        return Ok(());
+    }
+
     let new_event = storage::EventNew {
         event_id: generate_id(consts::ID_LENGTH, "evt"),
         event_type,
@@ -125,15 +503,33 @@ async fn create_event_and_trigger_outgoing_webhook(
 
         let outgoing_webhook = api::OutgoingWebhook {
             merchant_id: merchant_account.merchant_id.clone(),
-            event_id: event.event_id,
+            event_id: event.event_id.clone(),
             event_type: event.event_type.foreign_into(),
             content,
             timestamp: event.created_at,
         };
 
+        let webhook = Encode::<api::OutgoingWebhook>::encode_to_value(&outgoing_webhook)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+
         arbiter.spawn(async move {
+            let event_id = event.event_id.clone();
             let result =
-                trigger_webhook_to_merchant(merchant_account, outgoing_webhook, state.store).await;
+                trigger_webhook_to_merchant(&state, &merchant_account, event, &webhook).await;
+            let result = match result {
+                Err(error) if is_webhook_delivery_failure(&error) => {
+                    logger::error!(?error, "Scheduling outgoing webhook for retry");
+                    add_outgoing_webhook_retry_task(
+                        &*state.store,
+                        &state.conf.webhooks,
+                        &merchant_account.merchant_id,
+                        &event_id,
+                        webhook,
+                    )
+                    .await
+                }
+                result => result,
+            };
 
             if let Err(e) = result {
                 logger::error!(?e);
@@ -144,13 +540,18 @@ async fn create_event_and_trigger_outgoing_webhook(
     Ok(())
 }
 
-async fn trigger_webhook_to_merchant(
-    merchant_account: storage::MerchantAccount,
-    webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
+/// Send the webhook of the event to the merchant, recording the outcome of the delivery attempt
+/// against the event
+#[instrument(skip_all)]
+pub async fn trigger_webhook_to_merchant(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event: storage::Event,
+    webhook: &serde_json::Value,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = merchant_account
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -165,31 +566,206 @@ async fn trigger_webhook_to_merchant(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let response = reqwest::Client::new()
+    // The signature is computed over the exact bytes sent to the merchant
+    let payload = webhook.to_string();
+    let mut request = reqwest::Client::new()
         .post(&webhook_url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&webhook)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+
+    if let Some(secret) = get_webhook_signing_secret(merchant_account, &webhook_details) {
+        let timestamp = time::OffsetDateTime::now_utc().unix_timestamp().to_string();
+        let signature =
+            api::sign_outgoing_webhook(secret.as_bytes(), &timestamp, payload.as_bytes())
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+        request = request
+            .header(api::OUTGOING_WEBHOOK_SIGNATURE_HEADER, signature)
+            .header(api::OUTGOING_WEBHOOK_TIMESTAMP_HEADER, timestamp);
+    }
+
+    let attempted_at = common_utils::date_time::now();
+    let request_started_at = std::time::Instant::now();
+    let response = request
+        .body(payload)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
         .send()
         .await;
+    let latency_ms = request_started_at.elapsed().as_millis();
 
-    match response {
+    let (delivery_attempt, result) = match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
-            Err(e)
+            let delivery_attempt = storage::WebhookDeliveryAttempt {
+                status_code: e.status().map(|status| status.as_u16()),
+                latency_ms,
+                response_body: None,
+                error_message: Some(e.to_string()),
+                attempted_at,
+            };
+            let result = Err(e)
                 .into_report()
-                .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
+                .change_context(errors::WebhooksFlowError::CallToMerchantFailed);
+            (delivery_attempt, result)
         }
         Ok(res) => {
-            if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
-            }
+            let status = res.status();
+            let response_body = res.text().await.ok().map(|body| {
+                body.chars()
+                    .take(OUTGOING_WEBHOOK_RESPONSE_BODY_EXCERPT_LENGTH)
+                    .collect()
+            });
+            let delivery_attempt = storage::WebhookDeliveryAttempt {
+                status_code: Some(status.as_u16()),
+                latency_ms,
+                response_body,
+                error_message: None,
+                attempted_at,
+            };
+            let result = if status.is_success() {
+                Ok(())
+            } else {
+                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()
+            };
+            (delivery_attempt, result)
         }
+    };
+
+    record_webhook_delivery_attempt(&*state.store, event, delivery_attempt, result.is_ok()).await?;
+
+    result
+}
+
+/// Outgoing webhooks are signed with the webhook secret of the merchant, falling back to the
+/// payment response hash key of the merchant
+fn get_webhook_signing_secret(
+    merchant_account: &storage::MerchantAccount,
+    webhook_details: &api::WebhookDetails,
+) -> Option<String> {
+    if let Some(webhook_secret) = webhook_details.webhook_secret.clone() {
+        return Some(webhook_secret.expose());
     }
 
+    let payment_response_hash_key = merchant_account.payment_response_hash_key.clone();
+    match payment_response_hash_key {
+        Some(_) => logger::info!(
+            merchant_id = %merchant_account.merchant_id,
+            "No webhook secret configured, signing outgoing webhook with the payment response hash key"
+        ),
+        None => logger::warn!(
+            merchant_id = %merchant_account.merchant_id,
+            "Sending unsigned outgoing webhook as no signing secret is configured for the merchant"
+        ),
+    }
+    payment_response_hash_key
+}
+
+async fn record_webhook_delivery_attempt(
+    db: &dyn StorageInterface,
+    event: storage::Event,
+    delivery_attempt: storage::WebhookDeliveryAttempt,
+    is_webhook_notified: bool,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let mut delivery_attempts: Vec<storage::WebhookDeliveryAttempt> = event
+        .delivery_attempts
+        .clone()
+        .map(|delivery_attempts| delivery_attempts.parse_value("WebhookDeliveryAttempts"))
+        .transpose()
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?
+        .unwrap_or_default();
+    delivery_attempts.push(delivery_attempt);
+
+    let delivery_attempts =
+        Encode::<Vec<storage::WebhookDeliveryAttempt>>::encode_to_value(&delivery_attempts)
+            .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    db.update_event(
+        event,
+        storage::EventUpdate::DeliveryAttemptUpdate {
+            is_webhook_notified,
+            delivery_attempts,
+        },
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    Ok(())
+}
+
+/// Only the webhooks which could not be delivered to the merchant are retried, and not the ones
+/// which failed due to missing webhook details of the merchant
+pub fn is_webhook_delivery_failure(error: &error_stack::Report<errors::WebhooksFlowError>) -> bool {
+    matches!(
+        error.current_context(),
+        errors::WebhooksFlowError::CallToMerchantFailed
+            | errors::WebhooksFlowError::NotReceivedByMerchant
+    )
+}
+
+/// Time at which the next retry of an outgoing webhook is to be made, backing off exponentially
+/// from the configured base interval. Returns `None` once the maximum number of retries is made.
+pub fn get_outgoing_webhook_retry_schedule_time(
+    webhooks: &settings::WebhooksSettings,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let retry_count = u32::try_from(retry_count).ok()?;
+    (retry_count < webhooks.outgoing_max_retries).then(|| {
+        let delay_secs = webhooks
+            .outgoing_retry_base_interval_secs
+            .saturating_mul(2_i64.saturating_pow(retry_count));
+        common_utils::date_time::now().saturating_add(time::Duration::seconds(delay_secs))
+    })
+}
+
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    webhooks: &settings::WebhooksSettings,
+    merchant_id: &str,
+    event_id: &str,
+    webhook: serde_json::Value,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let schedule_time = match get_outgoing_webhook_retry_schedule_time(webhooks, 0) {
+        Some(schedule_time) => schedule_time,
+        None => return Ok(()),
+    };
+
+    let tracking_data = storage::OutgoingWebhookTrackingData {
+        merchant_id: merchant_id.to_string(),
+        event_id: event_id.to_string(),
+        webhook,
+    };
+    let tracking_data =
+        Encode::<storage::OutgoingWebhookTrackingData>::encode_to_value(&tracking_data)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)?;
+
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            OUTGOING_WEBHOOK_RETRY_RUNNER,
+            OUTGOING_WEBHOOK_RETRY_TASK,
+            event_id,
+            merchant_id,
+        ),
+        name: Some(String::from(OUTGOING_WEBHOOK_RETRY_TASK)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(OUTGOING_WEBHOOK_RETRY_RUNNER)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed while inserting task in process_tracker: event_id: {event_id}")
+        })?;
+
     Ok(())
 }
 
@@ -273,10 +849,44 @@ pub async fn webhooks_core(
                 merchant_account,
                 webhook_details,
                 source_verified,
+                connector_name,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Incoming webhook flow for payments failed")?,
+            api::WebhookFlow::Refund => refunds_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                connector_name,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for refunds failed")?,
+            api::WebhookFlow::Dispute => disputes_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                *connector,
+                &decoded_body,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for disputes failed")?,
+            api::WebhookFlow::Payout => payouts_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for payouts failed")?,
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .into_report()
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
@@ -290,3 +900,164 @@ pub async fn webhooks_core(
 
     Ok(response)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payout_status_transition() {
+        // Pending payouts are settled by the webhooks of the connector
+        for payout_status in [
+            enums::PayoutStatus::Success,
+            enums::PayoutStatus::Failed,
+            enums::PayoutStatus::Cancelled,
+        ] {
+            assert!(is_payout_status_transition_allowed(
+                &enums::PayoutStatus::Pending,
+                &payout_status
+            ));
+        }
+
+        assert!(is_payout_status_transition_allowed(
+            &enums::PayoutStatus::Success,
+            &enums::PayoutStatus::Reversed
+        ));
+        assert!(!is_payout_status_transition_allowed(
+            &enums::PayoutStatus::Success,
+            &enums::PayoutStatus::Failed
+        ));
+        assert!(!is_payout_status_transition_allowed(
+            &enums::PayoutStatus::Cancelled,
+            &enums::PayoutStatus::Success
+        ));
+        assert!(!is_payout_status_transition_allowed(
+            &enums::PayoutStatus::RequiresFulfillment,
+            &enums::PayoutStatus::Success
+        ));
+    }
+
+    #[test]
+    fn test_payout_status_from_webhook_event() {
+        let payout_status: Result<enums::PayoutStatus, _> =
+            api::IncomingWebhookEvent::PayoutReversed.foreign_try_into();
+        assert_eq!(payout_status.ok(), Some(enums::PayoutStatus::Reversed));
+        let payout_status: Result<enums::PayoutStatus, _> =
+            api::IncomingWebhookEvent::PaymentIntentSuccess.foreign_try_into();
+        assert!(payout_status.is_err());
+    }
+
+    #[test]
+    fn test_dispute_stage_transition() {
+        let stages = [
+            enums::DisputeStage::PreDispute,
+            enums::DisputeStage::Dispute,
+            enums::DisputeStage::PreArbitration,
+        ];
+        for dispute_stage in &stages {
+            assert!(validate_dispute_stage(
+                &enums::DisputeStage::PreDispute,
+                dispute_stage
+            ));
+        }
+
+        assert!(validate_dispute_stage(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStage::PreArbitration
+        ));
+        assert!(!validate_dispute_stage(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStage::PreDispute
+        ));
+        assert!(!validate_dispute_stage(
+            &enums::DisputeStage::PreArbitration,
+            &enums::DisputeStage::Dispute
+        ));
+        assert!(!validate_dispute_stage(
+            &enums::DisputeStage::PreArbitration,
+            &enums::DisputeStage::PreDispute
+        ));
+    }
+
+    #[test]
+    fn test_dispute_status_transition() {
+        // Open disputes can move to any status within the same stage
+        assert!(validate_dispute_stage_and_dispute_status(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeOpened,
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeWon,
+        )
+        .is_ok());
+        assert!(validate_dispute_stage_and_dispute_status(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeChallenged,
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeLost,
+        )
+        .is_ok());
+
+        // Final disputes only accept the same status again, or a new stage
+        assert!(validate_dispute_stage_and_dispute_status(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeLost,
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeLost,
+        )
+        .is_ok());
+        assert!(validate_dispute_stage_and_dispute_status(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeLost,
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeWon,
+        )
+        .is_err());
+        assert!(validate_dispute_stage_and_dispute_status(
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeLost,
+            &enums::DisputeStage::PreArbitration,
+            &enums::DisputeStatus::DisputeOpened,
+        )
+        .is_ok());
+
+        // Disputes cannot move back to an earlier stage
+        assert!(validate_dispute_stage_and_dispute_status(
+            &enums::DisputeStage::PreArbitration,
+            &enums::DisputeStatus::DisputeOpened,
+            &enums::DisputeStage::Dispute,
+            &enums::DisputeStatus::DisputeOpened,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_refund_status_final() {
+        for refund_status in [
+            enums::RefundStatus::Success,
+            enums::RefundStatus::Failure,
+            enums::RefundStatus::TransactionFailure,
+        ] {
+            assert!(is_refund_status_final(&refund_status));
+        }
+        // Refunds in these statuses are updated by the webhooks of the connector
+        for refund_status in [
+            enums::RefundStatus::Pending,
+            enums::RefundStatus::ManualReview,
+        ] {
+            assert!(!is_refund_status_final(&refund_status));
+        }
+    }
+
+    #[test]
+    fn test_refund_status_from_webhook_event() {
+        let refund_status: Result<enums::RefundStatus, _> =
+            api::IncomingWebhookEvent::RefundSuccess.foreign_try_into();
+        assert_eq!(refund_status.ok(), Some(enums::RefundStatus::Success));
+        let refund_status: Result<enums::RefundStatus, _> =
+            api::IncomingWebhookEvent::RefundFailure.foreign_try_into();
+        assert_eq!(refund_status.ok(), Some(enums::RefundStatus::Failure));
+        let refund_status: Result<enums::RefundStatus, _> =
+            api::IncomingWebhookEvent::DisputeOpened.foreign_try_into();
+        assert!(refund_status.is_err());
+    }
+}
@@ -4,7 +4,27 @@ use crate::{
 };
 
 fn default_webhook_config() -> api::MerchantWebhookConfig {
-    std::collections::HashSet::from([api::IncomingWebhookEvent::PaymentIntentSuccess])
+    std::collections::HashSet::from([
+        api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::IncomingWebhookEvent::PaymentIntentFailure,
+        api::IncomingWebhookEvent::PaymentIntentProcessing,
+        api::IncomingWebhookEvent::PaymentIntentCancelled,
+        api::IncomingWebhookEvent::PaymentIntentCaptured,
+        api::IncomingWebhookEvent::RefundSuccess,
+        api::IncomingWebhookEvent::RefundFailure,
+        api::IncomingWebhookEvent::DisputeOpened,
+        api::IncomingWebhookEvent::DisputeExpired,
+        api::IncomingWebhookEvent::DisputeAccepted,
+        api::IncomingWebhookEvent::DisputeCancelled,
+        api::IncomingWebhookEvent::DisputeChallenged,
+        api::IncomingWebhookEvent::DisputeWon,
+        api::IncomingWebhookEvent::DisputeLost,
+        api::IncomingWebhookEvent::MandateRevoked,
+        api::IncomingWebhookEvent::PayoutSuccess,
+        api::IncomingWebhookEvent::PayoutFailure,
+        api::IncomingWebhookEvent::PayoutCancelled,
+        api::IncomingWebhookEvent::PayoutReversed,
+    ])
 }
 
 pub async fn lookup_webhook_event(
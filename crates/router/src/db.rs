@@ -1,11 +1,17 @@
 pub mod address;
 pub mod api_keys;
 pub mod cache;
+pub mod capture;
 pub mod configs;
+pub mod connector_health;
 pub mod connector_response;
 pub mod customers;
+pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
+pub mod export;
+pub mod file;
+pub mod idempotency_key;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -13,10 +19,13 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod queue;
+pub mod rate_limit;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod subscription;
 
 use std::sync::Arc;
 
@@ -38,11 +47,17 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + capture::CaptureInterface
     + configs::ConfigInterface
+    + connector_health::ConnectorHealthInterface
     + connector_response::ConnectorResponseInterface
     + customers::CustomerInterface
+    + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + export::ExportInterface
+    + file::FileMetadataInterface
+    + idempotency_key::IdempotencyKeyInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -51,10 +66,13 @@ pub trait StorageInterface:
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payouts::PayoutsInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
+    + rate_limit::RateLimitInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + subscription::SubscriptionInterface
     + 'static
 {
     async fn close(&mut self) {}
@@ -1,12 +1,26 @@
-use error_stack::IntoReport;
+use error_stack::{IntoReport, ResultExt};
+use time::PrimitiveDateTime;
 
 use super::{MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    services::logger,
+    types::{api::enums as api_enums, storage},
 };
 
+/// An API key which has been verified, cached by the digest of the plaintext API key so that the
+/// API key need not be looked up and verified on each request
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CachedApiKey {
+    pub key_id: String,
+    pub merchant_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub scopes: Option<Vec<api_enums::ApiKeyScope>>,
+}
+
 #[async_trait::async_trait]
 pub trait ApiKeyInterface {
     async fn insert_api_key(
@@ -33,6 +47,74 @@ pub trait ApiKeyInterface {
         limit: Option<i64>,
         offset: Option<i64>,
     ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
+
+    async fn find_api_keys_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
+
+    async fn find_cached_api_key(
+        &self,
+        digest: &str,
+    ) -> CustomResult<Option<CachedApiKey>, errors::StorageError>;
+
+    async fn cache_api_key(
+        &self,
+        digest: &str,
+        api_key: &CachedApiKey,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn redact_cached_api_key(&self, key_id: &str) -> CustomResult<(), errors::StorageError>;
+
+    /// Acquire the lock on the migration of the legacy API key of the merchant, returning `false`
+    /// if the API key is being migrated by another request
+    async fn acquire_legacy_api_key_migration_lock(
+        &self,
+        merchant_id: &str,
+        lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    /// Acquire the lock on updating the last used time of the API key, held for `lock_ttl`
+    /// seconds so that the last used time is updated at most once in that interval. Returns
+    /// `false` if the last used time was updated within the interval.
+    async fn acquire_api_key_last_used_update_lock(
+        &self,
+        key_id: &str,
+        lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+fn get_api_key_cache_key(digest: &str) -> String {
+    format!("api_key_{digest}")
+}
+
+// The digest of the plaintext API key cannot be computed from the key ID, so it is cached along
+// with the API key, for the cached API key to be redacted when the API key is updated or revoked
+fn get_api_key_digest_cache_key(key_id: &str) -> String {
+    format!("api_key_digest_{key_id}")
+}
+
+impl Store {
+    async fn redact_api_key_cache(&self, key_id: &str) -> CustomResult<(), errors::StorageError> {
+        let digest_key = get_api_key_digest_cache_key(key_id);
+        let digest: Option<String> = self
+            .redis_conn
+            .get_key(&digest_key)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+        if let Some(digest) = digest {
+            self.redis_conn
+                .delete_key(&get_api_key_cache_key(&digest))
+                .await
+                .change_context(errors::StorageError::KVError)?;
+            self.redis_conn
+                .delete_key(&digest_key)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -54,19 +136,33 @@ impl ApiKeyInterface for Store {
         key_id: String,
         api_key: storage::ApiKeyUpdate,
     ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        // The last used time of the API key is not cached
+        let is_cached_update = !matches!(api_key, storage::ApiKeyUpdate::LastUsedUpdate { .. });
         let conn = pg_connection(&self.master_pool).await;
-        storage::ApiKey::update_by_key_id(&conn, key_id, api_key)
+        let api_key = storage::ApiKey::update_by_key_id(&conn, key_id, api_key)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+        // The API key is already updated, the cached API key expires on its own if the cache
+        // cannot be redacted
+        if is_cached_update {
+            if let Err(error) = self.redact_api_key_cache(&api_key.key_id).await {
+                logger::error!(api_key_cache_redaction_error=?error);
+            }
+        }
+        Ok(api_key)
     }
 
     async fn revoke_api_key(&self, key_id: &str) -> CustomResult<bool, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        storage::ApiKey::revoke_by_key_id(&conn, key_id)
+        let revoked = storage::ApiKey::revoke_by_key_id(&conn, key_id)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+        if let Err(error) = self.redact_api_key_cache(key_id).await {
+            logger::error!(api_key_cache_redaction_error=?error);
+        }
+        Ok(revoked)
     }
 
     async fn find_api_key_optional(
@@ -92,6 +188,82 @@ impl ApiKeyInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn find_api_keys_by_prefix(
+        &self,
+        prefix: &str,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ApiKey::find_by_prefix(&conn, prefix)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_cached_api_key(
+        &self,
+        digest: &str,
+    ) -> CustomResult<Option<CachedApiKey>, errors::StorageError> {
+        match self
+            .redis_conn
+            .get_and_deserialize_key(&get_api_key_cache_key(digest), "CachedApiKey")
+            .await
+        {
+            Ok(api_key) => Ok(Some(api_key)),
+            Err(error) => match error.current_context() {
+                errors::RedisError::NotFound => Ok(None),
+                _ => Err(error.change_context(errors::StorageError::KVError)),
+            },
+        }
+    }
+
+    async fn cache_api_key(
+        &self,
+        digest: &str,
+        api_key: &CachedApiKey,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.redis_conn
+            .set_key_with_expiry(&get_api_key_digest_cache_key(&api_key.key_id), digest, ttl)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+        self.redis_conn
+            .serialize_and_set_key_with_expiry(&get_api_key_cache_key(digest), api_key, ttl)
+            .await
+            .change_context(errors::StorageError::KVError)
+    }
+
+    async fn redact_cached_api_key(&self, key_id: &str) -> CustomResult<(), errors::StorageError> {
+        self.redact_api_key_cache(key_id).await
+    }
+
+    async fn acquire_legacy_api_key_migration_lock(
+        &self,
+        merchant_id: &str,
+        lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let key = format!("legacy_api_key_migration_lock_{merchant_id}");
+        self.redis_conn
+            .set_key_if_not_exists_with_expiry(&key, "true", lock_ttl)
+            .await
+            .map(|reply| matches!(reply, redis_interface::SetnxReply::KeySet))
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when acquiring the legacy API key migration lock")
+    }
+
+    async fn acquire_api_key_last_used_update_lock(
+        &self,
+        key_id: &str,
+        lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let key = format!("api_key_last_used_update_lock_{key_id}");
+        self.redis_conn
+            .set_key_if_not_exists_with_expiry(&key, "true", lock_ttl)
+            .await
+            .map(|reply| matches!(reply, redis_interface::SetnxReply::KeySet))
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when acquiring the API key last used update lock")
+    }
 }
 
 #[async_trait::async_trait]
@@ -135,4 +307,50 @@ impl ApiKeyInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_api_keys_by_prefix(
+        &self,
+        _prefix: &str,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        // The mock store has no hashed API keys, so merchants authenticate with their legacy keys
+        Ok(Vec::new())
+    }
+
+    async fn find_cached_api_key(
+        &self,
+        _digest: &str,
+    ) -> CustomResult<Option<CachedApiKey>, errors::StorageError> {
+        Ok(None)
+    }
+
+    async fn cache_api_key(
+        &self,
+        _digest: &str,
+        _api_key: &CachedApiKey,
+        _ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn redact_cached_api_key(&self, _key_id: &str) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn acquire_legacy_api_key_migration_lock(
+        &self,
+        _merchant_id: &str,
+        _lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn acquire_api_key_last_used_update_lock(
+        &self,
+        _key_id: &str,
+        _lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,303 @@
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    configs::settings::ConnectorHealthSettings,
+    core::errors::{self, CustomResult},
+    types::api::enums as api_enums,
+};
+
+/// Records a call to a connector in the bucket of the current time, which is `KEYS[2]`, and opens
+/// the circuit breaker of the connector, which is `KEYS[1]`, if the calls recorded in the buckets
+/// of the window, which are `KEYS[2..]`, exceed the thresholds. The buckets are cleared when the
+/// circuit breaker opens, so that the connector is tracked afresh once the cooldown elapses.
+/// `ARGV` holds whether the call succeeded, its latency in milliseconds, the TTL of the buckets,
+/// the minimum number of calls, the maximum failure rate, the maximum average latency and the
+/// cooldown, in that order. Returns 1 if the circuit breaker was opened by the call.
+const RECORD_CALL_SCRIPT: &str = r#"
+redis.call('HINCRBY', KEYS[2], 'calls', 1)
+if ARGV[1] == '0' then
+    redis.call('HINCRBY', KEYS[2], 'failures', 1)
+end
+redis.call('HINCRBY', KEYS[2], 'latency_ms', ARGV[2])
+redis.call('EXPIRE', KEYS[2], ARGV[3])
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    return 0
+end
+local calls, failures, latency = 0, 0, 0
+for i = 2, #KEYS do
+    local bucket = redis.call('HMGET', KEYS[i], 'calls', 'failures', 'latency_ms')
+    calls = calls + (tonumber(bucket[1]) or 0)
+    failures = failures + (tonumber(bucket[2]) or 0)
+    latency = latency + (tonumber(bucket[3]) or 0)
+end
+if calls < tonumber(ARGV[4]) then
+    return 0
+end
+if failures / calls > tonumber(ARGV[5]) or latency / calls > tonumber(ARGV[6]) then
+    redis.call('SET', KEYS[1], '1', 'EX', ARGV[7])
+    for i = 2, #KEYS do
+        redis.call('DEL', KEYS[i])
+    end
+    return 1
+end
+return 0
+"#;
+
+/// Sums the calls recorded in the buckets of the window, which are `KEYS[3..]`, and returns them
+/// along with whether the circuit breaker, which is `KEYS[1]`, is open and the health override,
+/// which is `KEYS[2]`, as strings.
+const GET_HEALTH_SCRIPT: &str = r#"
+local calls, failures, latency = 0, 0, 0
+for i = 3, #KEYS do
+    local bucket = redis.call('HMGET', KEYS[i], 'calls', 'failures', 'latency_ms')
+    calls = calls + (tonumber(bucket[1]) or 0)
+    failures = failures + (tonumber(bucket[2]) or 0)
+    latency = latency + (tonumber(bucket[3]) or 0)
+end
+return {
+    tostring(calls),
+    tostring(failures),
+    tostring(latency),
+    tostring(redis.call('EXISTS', KEYS[1])),
+    redis.call('GET', KEYS[2]) or ''
+}
+"#;
+
+/// The calls made to a connector within the rolling window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectorCallStats {
+    pub calls: u64,
+    pub failed_calls: u64,
+    pub total_latency_ms: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConnectorHealth {
+    pub stats: ConnectorCallStats,
+    pub circuit_open: bool,
+    pub health_override: Option<api_enums::ConnectorHealthStatus>,
+}
+
+#[async_trait::async_trait]
+pub trait ConnectorHealthInterface {
+    /// Record a call to the connector, returning whether the circuit breaker of the connector was
+    /// opened by the call
+    async fn record_connector_call(
+        &self,
+        connector: &str,
+        succeeded: bool,
+        latency_ms: u64,
+        settings: &ConnectorHealthSettings,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn get_connector_health(
+        &self,
+        connector: &str,
+        settings: &ConnectorHealthSettings,
+    ) -> CustomResult<ConnectorHealth, errors::StorageError>;
+
+    async fn set_connector_health_override(
+        &self,
+        connector: &str,
+        status: api_enums::ConnectorHealthStatus,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn delete_connector_health_override(
+        &self,
+        connector: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+// The connector is used as the hash tag of the keys, so that the keys of a connector are on the
+// same Redis cluster node and can be used together in a script
+fn get_circuit_key(connector: &str) -> String {
+    format!("connector_health_{{{connector}}}_circuit")
+}
+
+fn get_override_key(connector: &str) -> String {
+    format!("connector_health_{{{connector}}}_override")
+}
+
+/// Keys of the buckets making up the window ending at `now`, starting with the bucket of `now`
+fn get_window_bucket_keys(
+    connector: &str,
+    settings: &ConnectorHealthSettings,
+    now: u64,
+) -> Vec<String> {
+    let current_bucket = now / settings.bucket_secs;
+    let bucket_count = (settings.window_secs + settings.bucket_secs - 1) / settings.bucket_secs;
+    (0..bucket_count.min(current_bucket + 1))
+        .map(|offset| {
+            format!(
+                "connector_health_{{{connector}}}_{}",
+                current_bucket - offset
+            )
+        })
+        .collect()
+}
+
+fn get_now() -> u64 {
+    u64::try_from(date_time::now_unix_timestamp()).unwrap_or_default()
+}
+
+#[async_trait::async_trait]
+impl ConnectorHealthInterface for Store {
+    async fn record_connector_call(
+        &self,
+        connector: &str,
+        succeeded: bool,
+        latency_ms: u64,
+        settings: &ConnectorHealthSettings,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut keys = vec![get_circuit_key(connector)];
+        keys.extend(get_window_bucket_keys(connector, settings, get_now()));
+        let args = vec![
+            u8::from(succeeded).to_string(),
+            latency_ms.to_string(),
+            (settings.window_secs + settings.bucket_secs).to_string(),
+            settings.min_calls.to_string(),
+            settings.max_failure_rate.to_string(),
+            settings.max_average_latency_ms.to_string(),
+            settings.cooldown_secs.to_string(),
+        ];
+        let circuit_opened: u8 = self
+            .redis_conn
+            .evaluate_script(RECORD_CALL_SCRIPT, keys, args)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to record the call to the connector")?;
+        Ok(circuit_opened == 1)
+    }
+
+    async fn get_connector_health(
+        &self,
+        connector: &str,
+        settings: &ConnectorHealthSettings,
+    ) -> CustomResult<ConnectorHealth, errors::StorageError> {
+        let mut keys = vec![get_circuit_key(connector), get_override_key(connector)];
+        keys.extend(get_window_bucket_keys(connector, settings, get_now()));
+        let health: Vec<String> = self
+            .redis_conn
+            .evaluate_script(GET_HEALTH_SCRIPT, keys, vec![])
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get the health of the connector")?;
+
+        let parse = |index: usize| {
+            health
+                .get(index)
+                .and_then(|value| value.parse::<u64>().ok())
+                .ok_or(errors::StorageError::DeserializationFailed)
+                .into_report()
+                .attach_printable("Unexpected connector health reply")
+        };
+        let health_override: Option<api_enums::ConnectorHealthStatus> = health
+            .get(4)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse())
+            .transpose()
+            .into_report()
+            .change_context(errors::StorageError::DeserializationFailed)
+            .attach_printable("Invalid connector health override")?;
+
+        Ok(ConnectorHealth {
+            stats: ConnectorCallStats {
+                calls: parse(0)?,
+                failed_calls: parse(1)?,
+                total_latency_ms: parse(2)?,
+            },
+            circuit_open: parse(3)? == 1,
+            health_override,
+        })
+    }
+
+    async fn set_connector_health_override(
+        &self,
+        connector: &str,
+        status: api_enums::ConnectorHealthStatus,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.redis_conn
+            .set_key_with_expiry(&get_override_key(connector), status.to_string(), ttl)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to override the health of the connector")
+    }
+
+    async fn delete_connector_health_override(
+        &self,
+        connector: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.redis_conn
+            .delete_key(&get_override_key(connector))
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to delete the health override of the connector")
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorHealthInterface for MockDb {
+    async fn record_connector_call(
+        &self,
+        _connector: &str,
+        _succeeded: bool,
+        _latency_ms: u64,
+        _settings: &ConnectorHealthSettings,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_connector_health(
+        &self,
+        _connector: &str,
+        _settings: &ConnectorHealthSettings,
+    ) -> CustomResult<ConnectorHealth, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn set_connector_health_override(
+        &self,
+        _connector: &str,
+        _status: api_enums::ConnectorHealthStatus,
+        _ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_connector_health_override(
+        &self,
+        _connector: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_bucket_keys() {
+        let settings = ConnectorHealthSettings {
+            window_secs: 25,
+            bucket_secs: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            get_window_bucket_keys("stripe", &settings, 1005),
+            vec![
+                "connector_health_{stripe}_100",
+                "connector_health_{stripe}_99",
+                "connector_health_{stripe}_98",
+            ]
+        );
+    }
+}
@@ -13,6 +13,17 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,6 +35,29 @@ impl EventInterface for Store {
         let conn = pg_connection(&self.master_pool).await;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, event)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -35,4 +69,21 @@ impl EventInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        _event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_event(
+        &self,
+        _this: storage::Event,
+        _event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,156 @@
+use error_stack::IntoReport;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+/// Paged reads of the records of a merchant created within a time range, for exporting the
+/// records. The records are read from the replica database regardless of the storage scheme of
+/// the merchant, since the records written to Redis are drained to the database.
+#[async_trait::async_trait]
+pub trait ExportInterface {
+    /// The payment intents created in `[start_time, end_time)` with an `id` greater than
+    /// `after_id`, in the order of their `id`
+    async fn get_payment_intents_for_export(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::StorageError>;
+
+    /// The payment attempts created in `[start_time, end_time)` with an `id` greater than
+    /// `after_id`, in the order of their `id`
+    async fn get_payment_attempts_for_export(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::StorageError>;
+
+    /// The refunds created in `[start_time, end_time)` with an `id` greater than `after_id`, in
+    /// the order of their `id`
+    async fn get_refunds_for_export(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ExportInterface for Store {
+    async fn get_payment_intents_for_export(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await;
+        storage::PaymentIntent::filter_by_merchant_id_created_at_range(
+            &conn,
+            merchant_id,
+            start_time,
+            end_time,
+            after_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn get_payment_attempts_for_export(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await;
+        storage::PaymentAttempt::filter_by_merchant_id_created_at_range(
+            &conn,
+            merchant_id,
+            start_time,
+            end_time,
+            after_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn get_refunds_for_export(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await;
+        storage::Refund::filter_by_merchant_id_created_at_range(
+            &conn,
+            merchant_id,
+            start_time,
+            end_time,
+            after_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ExportInterface for MockDb {
+    async fn get_payment_intents_for_export(
+        &self,
+        _merchant_id: &str,
+        _start_time: PrimitiveDateTime,
+        _end_time: PrimitiveDateTime,
+        _after_id: i32,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_payment_attempts_for_export(
+        &self,
+        _merchant_id: &str,
+        _start_time: PrimitiveDateTime,
+        _end_time: PrimitiveDateTime,
+        _after_id: i32,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_refunds_for_export(
+        &self,
+        _merchant_id: &str,
+        _start_time: PrimitiveDateTime,
+        _end_time: PrimitiveDateTime,
+        _after_id: i32,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
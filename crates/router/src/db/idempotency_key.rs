@@ -0,0 +1,236 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    logger,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IdempotencyKeyInterface {
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+        ttl: i64,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+
+    async fn update_idempotency_key(
+        &self,
+        this: storage::IdempotencyKey,
+        idempotency_key: storage::IdempotencyKeyUpdate,
+        ttl: i64,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn delete_idempotency_keys_by_merchant_id_created_before(
+        &self,
+        merchant_id: &str,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_oldest_idempotency_key_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::IdempotencyKey>, errors::StorageError>;
+}
+
+fn get_idempotency_key_redis_key(merchant_id: &str, idempotency_key: &str) -> String {
+    format!("idempotency_{merchant_id}_{idempotency_key}")
+}
+
+impl Store {
+    /// Idempotency keys are cached in Redis on a best effort basis, the database being the source
+    /// of truth whenever Redis is unavailable
+    async fn cache_idempotency_key(&self, idempotency_key: &storage::IdempotencyKey, ttl: i64) {
+        let key = get_idempotency_key_redis_key(
+            &idempotency_key.merchant_id,
+            &idempotency_key.idempotency_key,
+        );
+        if let Err(error) = self
+            .redis_conn
+            .serialize_and_set_key_with_expiry(&key, idempotency_key, ttl)
+            .await
+        {
+            logger::error!(?error, "Failed to cache idempotency key in redis");
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for Store {
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+        ttl: i64,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        let idempotency_key = idempotency_key
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        self.cache_idempotency_key(&idempotency_key, ttl).await;
+        Ok(idempotency_key)
+    }
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let key = get_idempotency_key_redis_key(merchant_id, idempotency_key);
+        match self
+            .redis_conn
+            .get_and_deserialize_key(&key, "IdempotencyKey")
+            .await
+        {
+            Ok(idempotency_key) => Ok(idempotency_key),
+            Err(_) => {
+                let conn = pg_connection(&self.master_pool).await;
+                storage::IdempotencyKey::find_by_merchant_id_idempotency_key(
+                    &conn,
+                    merchant_id,
+                    idempotency_key,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            }
+        }
+    }
+
+    async fn update_idempotency_key(
+        &self,
+        this: storage::IdempotencyKey,
+        idempotency_key: storage::IdempotencyKeyUpdate,
+        ttl: i64,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        let idempotency_key = this
+            .update(&conn, idempotency_key)
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+        self.cache_idempotency_key(&idempotency_key, ttl).await;
+        Ok(idempotency_key)
+    }
+
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let key = get_idempotency_key_redis_key(merchant_id, idempotency_key);
+        if let Err(error) = self.redis_conn.delete_key(&key).await {
+            logger::error!(?error, "Failed to delete idempotency key from redis");
+        }
+
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IdempotencyKey::delete_by_merchant_id_idempotency_key(
+            &conn,
+            merchant_id,
+            idempotency_key,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_idempotency_keys_by_merchant_id_created_before(
+        &self,
+        merchant_id: &str,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // The keys cached in redis expire on their own
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IdempotencyKey::delete_by_merchant_id_created_before(
+            &conn,
+            merchant_id,
+            created_before,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_oldest_idempotency_key_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::IdempotencyKey>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IdempotencyKey::find_oldest_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for MockDb {
+    async fn insert_idempotency_key(
+        &self,
+        _idempotency_key: storage::IdempotencyKeyNew,
+        _ttl: i64,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        _merchant_id: &str,
+        _idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_idempotency_key(
+        &self,
+        _this: storage::IdempotencyKey,
+        _idempotency_key: storage::IdempotencyKeyUpdate,
+        _ttl: i64,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        _merchant_id: &str,
+        _idempotency_key: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_idempotency_keys_by_merchant_id_created_before(
+        &self,
+        _merchant_id: &str,
+        _created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_oldest_idempotency_key_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Option<storage::IdempotencyKey>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -28,6 +28,14 @@ pub trait MandateInterface {
         mandate: storage::MandateUpdate,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
+    /// Update the usage of the mandate, provided that it has not been updated since `mandate` was
+    /// read. Returns `None` if it has been.
+    async fn update_mandate_usage(
+        &self,
+        mandate: &storage::Mandate,
+        mandate_update: storage::MandateUpdate,
+    ) -> CustomResult<Option<storage::Mandate>, errors::StorageError>;
+
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
@@ -73,6 +81,18 @@ impl MandateInterface for Store {
             .into_report()
     }
 
+    async fn update_mandate_usage(
+        &self,
+        mandate: &storage::Mandate,
+        mandate_update: storage::MandateUpdate,
+    ) -> CustomResult<Option<storage::Mandate>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Mandate::update_usage_if_unchanged(&conn, mandate, mandate_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
@@ -116,6 +136,15 @@ impl MandateInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_mandate_usage(
+        &self,
+        _mandate: &storage::Mandate,
+        _mandate_update: storage::MandateUpdate,
+    ) -> CustomResult<Option<storage::Mandate>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn insert_mandate(
         &self,
         _mandate: storage::MandateNew,
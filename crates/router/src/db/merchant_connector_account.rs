@@ -10,6 +10,15 @@ use crate::{
     types::{self, storage},
 };
 
+/// Deletes the lock in `KEYS[1]` only if it is still held with the token in `ARGV[1]`, so that a
+/// request whose lock has expired does not release the lock since acquired by another request
+const RELEASE_LOCK_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
 #[async_trait::async_trait]
 pub trait ConnectorAccessToken {
     async fn get_access_token(
@@ -23,6 +32,25 @@ pub trait ConnectorAccessToken {
         merchant_id: &str,
         connector_name: &str,
         access_token: types::AccessToken,
+        ttl: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Acquire the lock allowing a single request to refresh the access token, which is released
+    /// after `lock_ttl` seconds if the request fails to release it
+    async fn acquire_access_token_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        lock_token: &str,
+        lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    /// Release the lock, only if it is still held with the token it was acquired with
+    async fn release_access_token_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        lock_token: &str,
     ) -> CustomResult<(), errors::StorageError>;
 }
 
@@ -33,9 +61,6 @@ impl ConnectorAccessToken for Store {
         merchant_id: &str,
         connector_name: &str,
     ) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
-        //TODO: Handle race condition
-        // This function should acquire a global lock on some resource, if access token is already
-        // being refreshed by other request then wait till it finishes and use the same access token
         let key = format!("access_token_{merchant_id}_{connector_name}");
         let maybe_token = self
             .redis_conn
@@ -58,13 +83,14 @@ impl ConnectorAccessToken for Store {
         merchant_id: &str,
         connector_name: &str,
         access_token: types::AccessToken,
+        ttl: i64,
     ) -> CustomResult<(), errors::StorageError> {
         let key = format!("access_token_{merchant_id}_{connector_name}");
         let serialized_access_token =
             Encode::<types::AccessToken>::encode_to_string_of_json(&access_token)
                 .change_context(errors::StorageError::SerializationFailed)?;
         self.redis_conn
-            .set_key_with_expiry(&key, serialized_access_token, access_token.expires)
+            .set_key_with_expiry(&key, serialized_access_token, ttl)
             .await
             .map_err(|error| {
                 logger::error!(access_token_kv_error=?error);
@@ -72,6 +98,37 @@ impl ConnectorAccessToken for Store {
             })
             .into_report()
     }
+
+    async fn acquire_access_token_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        lock_token: &str,
+        lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let key = format!("access_token_lock_{merchant_id}_{connector_name}");
+        self.redis_conn
+            .set_key_if_not_exists_with_expiry(&key, lock_token, lock_ttl)
+            .await
+            .map(|reply| matches!(reply, redis_interface::SetnxReply::KeySet))
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when acquiring the access token lock")
+    }
+
+    async fn release_access_token_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        lock_token: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = format!("access_token_lock_{merchant_id}_{connector_name}");
+        self.redis_conn
+            .evaluate_script::<i64>(RELEASE_LOCK_SCRIPT, vec![key], vec![lock_token.to_owned()])
+            .await
+            .map(|_| ())
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when releasing the access token lock")
+    }
 }
 
 #[async_trait::async_trait]
@@ -89,6 +146,26 @@ impl ConnectorAccessToken for MockDb {
         _merchant_id: &str,
         _connector_name: &str,
         _access_token: types::AccessToken,
+        _ttl: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn acquire_access_token_lock(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _lock_token: &str,
+        _lock_ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Ok(true)
+    }
+
+    async fn release_access_token_lock(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _lock_token: &str,
     ) -> CustomResult<(), errors::StorageError> {
         Ok(())
     }
@@ -26,6 +26,13 @@ pub trait PaymentAttemptInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError>;
 
+    async fn find_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         connector_transaction_id: &str,
@@ -109,6 +116,19 @@ mod storage {
                 .into_report()
         }
 
+        async fn find_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            PaymentAttempt::filter_by_payment_id_merchant_id(&conn, payment_id, merchant_id, None)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
         async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
             &self,
             connector_transaction_id: &str,
@@ -244,6 +264,7 @@ impl PaymentAttemptInterface for MockDb {
             payment_token: None,
             error_code: payment_attempt.error_code,
             connector_metadata: None,
+            three_ds_authentication: None,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -279,6 +300,25 @@ impl PaymentAttemptInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_payment_attempts_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .rev()
+            .filter(|payment_attempt| {
+                payment_attempt.payment_id == payment_id
+                    && payment_attempt.merchant_id == merchant_id
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         _connector_transaction_id: &str,
@@ -383,6 +423,7 @@ mod storage {
                         payment_token: payment_attempt.payment_token.clone(),
                         error_code: payment_attempt.error_code.clone(),
                         connector_metadata: payment_attempt.connector_metadata.clone(),
+                        three_ds_authentication: payment_attempt.three_ds_authentication.clone(),
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -550,6 +591,37 @@ mod storage {
             }
         }
 
+        async fn find_payment_attempts_by_payment_id_merchant_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    PaymentAttempt::filter_by_payment_id_merchant_id(
+                        &conn,
+                        payment_id,
+                        merchant_id,
+                        None,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                // [#439]: Payments are not retried in KV mode, so there can only be a single attempt
+                enums::MerchantStorageScheme::RedisKv => self
+                    .find_payment_attempt_by_payment_id_merchant_id(
+                        payment_id,
+                        merchant_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .map(|payment_attempt| vec![payment_attempt]),
+            }
+        }
+
         async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
             &self,
             connector_transaction_id: &str,
@@ -35,6 +35,14 @@ pub trait PaymentIntentInterface {
         pc: &api::PaymentListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn count_payment_intents_by_constraints(
+        &self,
+        merchant_id: &str,
+        pc: &api::PaymentListConstraints,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -229,6 +237,26 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
             }
         }
+
+        #[cfg(feature = "olap")]
+        async fn count_payment_intents_by_constraints(
+            &self,
+            merchant_id: &str,
+            pc: &api::PaymentListConstraints,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<i64, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.replica_pool).await;
+                    PaymentIntent::count_by_constraints(&conn, merchant_id, pc)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+
+                enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
+            }
+        }
     }
 }
 
@@ -296,6 +324,20 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        #[cfg(feature = "olap")]
+        async fn count_payment_intents_by_constraints(
+            &self,
+            merchant_id: &str,
+            pc: &api::PaymentListConstraints,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = pg_connection(&self.replica_pool).await;
+            PaymentIntent::count_by_constraints(&conn, merchant_id, pc)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -312,6 +354,17 @@ impl PaymentIntentInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "olap")]
+    async fn count_payment_intents_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _pc: &api::PaymentListConstraints,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     #[allow(clippy::panic)]
     async fn insert_payment_intent(
         &self,
@@ -0,0 +1,85 @@
+use error_stack::ResultExt;
+
+use super::{MockDb, Store};
+use crate::{
+    configs::settings::TokenBucket,
+    core::errors::{self, CustomResult},
+};
+
+/// Takes a token from each of the buckets in `KEYS`, only if all of them have a token available,
+/// and returns the number of milliseconds after which the emptiest bucket has a token again
+/// otherwise. The capacity and refill rate of the buckets are passed in `ARGV` as pairs, in the
+/// order of the keys. The time of the Redis server is used, so that the buckets are refilled
+/// alike irrespective of the clock of the router instance handling the request.
+const CONSUME_TOKENS_SCRIPT: &str = r#"
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local tokens = {}
+local retry_after = 0
+for i, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[2 * i - 1])
+    local refill_rate = tonumber(ARGV[2 * i])
+    local bucket = redis.call('HMGET', key, 'tokens', 'updated_at')
+    local available = tonumber(bucket[1]) or capacity
+    local updated_at = tonumber(bucket[2]) or now
+    available = math.min(capacity, available + math.max(0, now - updated_at) * refill_rate / 1000)
+    tokens[i] = available
+    if available < 1 then
+        retry_after = math.max(retry_after, math.ceil((1 - available) * 1000 / refill_rate))
+    end
+end
+if retry_after == 0 then
+    for i, key in ipairs(KEYS) do
+        local capacity = tonumber(ARGV[2 * i - 1])
+        local refill_rate = tonumber(ARGV[2 * i])
+        redis.call('HSET', key, 'tokens', tokens[i] - 1, 'updated_at', now)
+        redis.call('PEXPIRE', key, math.ceil(capacity * 1000 / refill_rate))
+    end
+end
+return retry_after
+"#;
+
+#[async_trait::async_trait]
+pub trait RateLimitInterface {
+    /// Take a token from each of the buckets if all of them have a token available, returning the
+    /// number of milliseconds to wait for the tokens otherwise
+    async fn consume_rate_limit_tokens(
+        &self,
+        buckets: Vec<(String, TokenBucket)>,
+    ) -> CustomResult<Option<u64>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for Store {
+    async fn consume_rate_limit_tokens(
+        &self,
+        buckets: Vec<(String, TokenBucket)>,
+    ) -> CustomResult<Option<u64>, errors::StorageError> {
+        let (keys, args) = buckets.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut keys, mut args), (key, bucket)| {
+                keys.push(key);
+                args.push(bucket.capacity.to_string());
+                args.push(bucket.refill_rate.to_string());
+                (keys, args)
+            },
+        );
+        let retry_after: u64 = self
+            .redis_conn
+            .evaluate_script(CONSUME_TOKENS_SCRIPT, keys, args)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+        Ok((retry_after > 0).then_some(retry_after))
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitInterface for MockDb {
+    async fn consume_rate_limit_tokens(
+        &self,
+        _buckets: Vec<(String, TokenBucket)>,
+    ) -> CustomResult<Option<u64>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
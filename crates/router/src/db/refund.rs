@@ -36,6 +36,14 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage_types::Refund, errors::StorageError>;
 
+    async fn find_refund_by_merchant_id_connector_refund_id_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_id: &str,
+        connector: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Refund, errors::StorageError>;
+
     async fn update_refund(
         &self,
         this: storage_types::Refund,
@@ -123,6 +131,25 @@ mod storage {
             .into_report()
         }
 
+        async fn find_refund_by_merchant_id_connector_refund_id_connector(
+            &self,
+            merchant_id: &str,
+            connector_refund_id: &str,
+            connector: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Refund::find_by_merchant_id_connector_refund_id_connector(
+                &conn,
+                merchant_id,
+                connector_refund_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
         async fn update_refund(
             &self,
             this: storage_types::Refund,
@@ -399,6 +426,27 @@ mod storage {
             }
         }
 
+        async fn find_refund_by_merchant_id_connector_refund_id_connector(
+            &self,
+            merchant_id: &str,
+            connector_refund_id: &str,
+            connector: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+            // The connector refund id is not part of the reverse lookups of the refund, so the
+            // refund is looked up in the database in KV mode as well
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Refund::find_by_merchant_id_connector_refund_id_connector(
+                &conn,
+                merchant_id,
+                connector_refund_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
         async fn update_refund(
             &self,
             this: storage_types::Refund,
@@ -634,6 +682,28 @@ impl RefundInterface for MockDb {
             .collect::<Vec<_>>())
     }
 
+    async fn find_refund_by_merchant_id_connector_refund_id_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_id: &str,
+        connector: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        refunds
+            .iter()
+            .find(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund.connector_refund_id.as_deref() == Some(connector_refund_id)
+                    && refund.connector == connector
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
     async fn update_refund(
         &self,
         _this: storage_types::Refund,
@@ -50,6 +50,8 @@ pub mod headers {
     pub const X_API_VERSION: &str = "X-ApiVersion";
     pub const DATE: &str = "Date";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
 }
 
 pub mod pii {
@@ -72,7 +74,7 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit);
+    let mut server_app = get_application_builder(request_body_limit, state.clone());
 
     #[cfg(any(feature = "olap", feature = "oltp"))]
     {
@@ -82,8 +84,10 @@ pub fn mk_app(
             .service(routes::Configs::server(state.clone()))
             .service(routes::Refunds::server(state.clone()))
             .service(routes::Payouts::server(state.clone()))
+            .service(routes::Disputes::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
-            .service(routes::Mandates::server(state.clone()));
+            .service(routes::Mandates::server(state.clone()))
+            .service(routes::Subscriptions::server(state.clone()));
     }
 
     #[cfg(feature = "oltp")]
@@ -91,6 +95,7 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
+            .service(routes::Files::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()));
     }
 
@@ -98,13 +103,20 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::Routing::server(state.clone()))
+            .service(routes::ConnectorHealth::server(state.clone()))
+            .service(routes::Exports::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
     {
         server_app = server_app.service(routes::StripeApis::server(state.clone()));
     }
+    #[cfg(feature = "mock_three_ds")]
+    {
+        server_app = server_app.service(routes::MockThreeDs::server(state.clone()));
+    }
     server_app = server_app.service(routes::Health::server(state));
     server_app
 }
@@ -133,6 +145,7 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<(Server
 
 pub fn get_application_builder(
     request_body_limit: usize,
+    state: AppState,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -150,6 +163,11 @@ pub fn get_application_builder(
 
     actix_web::App::new()
         .app_data(json_cfg)
+        .wrap(middleware::IdempotencyFingerprint::new(
+            &state.conf.idempotency.fingerprint_secret,
+            request_body_limit,
+        ))
+        .wrap(middleware::RateLimit::new(state))
         .wrap(middleware::RequestId)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
         .wrap(ErrorHandlers::new().handler(
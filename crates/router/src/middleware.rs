@@ -59,3 +59,302 @@ where
         })
     }
 }
+
+/// Middleware rate limiting the API requests of merchants, responding with `429 Too Many Requests`
+/// and a `Retry-After` header once the limits of the merchant are exhausted.
+pub(crate) struct RateLimit {
+    state: crate::routes::AppState,
+}
+
+impl RateLimit {
+    pub(crate) fn new(state: crate::routes::AppState) -> Self {
+        Self { state }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimit
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimitMiddleware {
+            service: std::rc::Rc::new(service),
+            state: self.state.clone(),
+        }))
+    }
+}
+
+pub(crate) struct RateLimitMiddleware<S> {
+    service: std::rc::Rc<S>,
+    state: crate::routes::AppState,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let state = self.state.clone();
+
+        Box::pin(async move {
+            let api_key = match crate::services::authentication::get_api_key(req.headers()) {
+                Ok(api_key) if state.conf.rate_limit.enabled => api_key.to_owned(),
+                // Requests authenticated otherwise are not rate limited
+                _ => return Ok(service.call(req).await?.map_into_left_body()),
+            };
+            let route = req
+                .match_pattern()
+                .map(|pattern| format!("{} {}", req.method(), pattern));
+
+            match crate::core::rate_limit::check_rate_limits(&state, &api_key, route.as_deref())
+                .await
+            {
+                // The API key resolved by the rate limiter is not verified again on
+                // authentication
+                Ok(Some(verified_api_key)) => {
+                    req.extensions_mut().insert(verified_api_key);
+                    Ok(service.call(req).await?.map_into_left_body())
+                }
+                // Requests with keys other than merchant secret keys are authenticated by the
+                // route alone
+                Ok(None) => Ok(service.call(req).await?.map_into_left_body()),
+                Err(error) => {
+                    let error = error.current_context();
+                    let mut response = get_rate_limit_error_response(req.path(), error);
+                    if let crate::core::errors::ApiErrorResponse::RateLimitExceeded {
+                        retry_after_secs,
+                    } = error
+                    {
+                        response.headers_mut().insert(
+                            http::header::RETRY_AFTER,
+                            http::HeaderValue::from(*retry_after_secs),
+                        );
+                    }
+                    Ok(req.into_response(response).map_into_right_body())
+                }
+            }
+        })
+    }
+}
+
+#[cfg_attr(not(feature = "stripe"), allow(unused_variables))]
+fn get_rate_limit_error_response(
+    path: &str,
+    error: &crate::core::errors::ApiErrorResponse,
+) -> actix_web::HttpResponse {
+    use actix_web::ResponseError;
+
+    // The errors of the Stripe compatible APIs are returned in the format of Stripe
+    #[cfg(feature = "stripe")]
+    if path.starts_with("/vs/v1") {
+        return crate::compatibility::stripe::errors::StripeErrorCode::from(error.clone())
+            .error_response();
+    }
+
+    error.error_response()
+}
+
+/// Middleware fingerprinting the body of requests sent with an idempotency key, before the body is
+/// consumed by the extractors of the route. The body is put back for the route to extract it.
+///
+/// The body is read up to `max_body_size`, except for multipart bodies such as file uploads, which
+/// are left to be streamed by the route and are not part of the fingerprint.
+pub(crate) struct IdempotencyFingerprint {
+    secret: std::rc::Rc<str>,
+    max_body_size: usize,
+}
+
+impl IdempotencyFingerprint {
+    pub(crate) fn new(secret: &str, max_body_size: usize) -> Self {
+        Self {
+            secret: secret.into(),
+            max_body_size,
+        }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for IdempotencyFingerprint
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = IdempotencyFingerprintMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(IdempotencyFingerprintMiddleware {
+            service: std::rc::Rc::new(service),
+            secret: self.secret.clone(),
+            max_body_size: self.max_body_size,
+        }))
+    }
+}
+
+pub(crate) struct IdempotencyFingerprintMiddleware<S> {
+    service: std::rc::Rc<S>,
+    secret: std::rc::Rc<str>,
+    max_body_size: usize,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for IdempotencyFingerprintMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let secret = self.secret.clone();
+        let max_body_size = self.max_body_size;
+
+        Box::pin(async move {
+            if crate::core::idempotency::get_idempotency_key(req.request()).is_some() {
+                let body = if is_multipart_request(&req) {
+                    actix_web::web::Bytes::new()
+                } else {
+                    let body = read_request_body(&mut req, max_body_size).await?;
+                    let payload: std::pin::Pin<
+                        Box<
+                            dyn futures::Stream<
+                                Item = Result<
+                                    actix_web::web::Bytes,
+                                    actix_web::error::PayloadError,
+                                >,
+                            >,
+                        >,
+                    > = Box::pin(futures::stream::once({
+                        let body = body.clone();
+                        async move { Ok(body) }
+                    }));
+                    req.set_payload(payload.into());
+                    body
+                };
+                let fingerprint = crate::core::idempotency::generate_request_fingerprint(
+                    &secret,
+                    req.method().as_str(),
+                    req.path(),
+                    &body,
+                )
+                .map_err(|error| actix_web::Error::from(error.current_context().clone()))?;
+                req.extensions_mut()
+                    .insert(crate::core::idempotency::RequestFingerprint(fingerprint));
+            }
+            service.call(req).await
+        })
+    }
+}
+
+fn is_multipart_request(req: &actix_web::dev::ServiceRequest) -> bool {
+    use actix_web::HttpMessage;
+
+    matches!(
+        req.mime_type(),
+        Ok(Some(content_type)) if content_type.type_() == mime::MULTIPART
+    )
+}
+
+/// Read the body of the request, failing with a payload too large error once the body exceeds
+/// `max_body_size`
+async fn read_request_body(
+    req: &mut actix_web::dev::ServiceRequest,
+    max_body_size: usize,
+) -> Result<actix_web::web::Bytes, actix_web::Error> {
+    use futures::StreamExt;
+
+    let mut payload = req.take_payload();
+    let mut body = actix_web::web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > max_body_size {
+            return Err(actix_web::error::PayloadError::Overflow.into());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[actix_rt::test]
+    async fn test_read_request_body_within_limit() {
+        let mut req = TestRequest::post()
+            .set_payload(r#"{"amount":6540}"#)
+            .to_srv_request();
+        let body = read_request_body(&mut req, 16).await.unwrap();
+        assert_eq!(body, r#"{"amount":6540}"#);
+
+        let mut req = TestRequest::post()
+            .set_payload(r#"{"amount":6540,"currency":"USD"}"#)
+            .to_srv_request();
+        let error = read_request_body(&mut req, 16).await.unwrap_err();
+        assert_eq!(
+            error.as_response_error().status_code(),
+            actix_web::http::StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+
+    #[test]
+    fn test_multipart_requests_are_detected() {
+        let req = TestRequest::post()
+            .insert_header((
+                actix_web::http::header::CONTENT_TYPE,
+                "multipart/form-data; boundary=boundary",
+            ))
+            .to_srv_request();
+        assert!(is_multipart_request(&req));
+
+        let req = TestRequest::post()
+            .insert_header((actix_web::http::header::CONTENT_TYPE, "application/json"))
+            .to_srv_request();
+        assert!(!is_multipart_request(&req));
+    }
+}
@@ -54,6 +54,13 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Payouts", description = "Create and manage payouts to sellers"),
+        (name = "Disputes", description = "Manage disputes raised against payments"),
+        (name = "Files", description = "Upload and manage files, such as dispute evidence"),
+        (name = "Routing", description = "Evaluate the routing algorithm of the merchant"),
+        (name = "Connector Health", description = "Monitor and override the health of connectors"),
+        (name = "Exports", description = "Export payments, payment attempts and refunds for reconciliation"),
+        (name = "Subscriptions", description = "Charge customers on a recurring schedule against their mandates"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
@@ -85,6 +92,9 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_connector_session,
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
+        crate::routes::payments::payments_incremental_authorization,
+        crate::routes::payments::payments_pre_authenticate,
+        crate::routes::payments::payments_authenticate,
         crate::routes::payments::payments_list,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
@@ -101,6 +111,31 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::api_keys::api_key_update,
         crate::routes::api_keys::api_key_revoke,
         crate::routes::api_keys::api_key_list,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::payouts::payouts_reverse,
+        crate::routes::payouts::payouts_accounts,
+        crate::routes::payouts::payouts_list,
+        crate::routes::disputes::retrieve_dispute,
+        crate::routes::disputes::retrieve_disputes_list,
+        crate::routes::disputes::accept_dispute,
+        crate::routes::disputes::submit_dispute_evidence,
+        crate::routes::files::files_create,
+        crate::routes::files::files_delete,
+        crate::routes::files::files_retrieve,
+        crate::routes::routing::routing_dry_run,
+        crate::routes::connector_health::connector_health_retrieve,
+        crate::routes::connector_health::connector_health_override,
+        crate::routes::connector_health::connector_health_override_delete,
+        crate::routes::exports::export_create,
+        crate::routes::exports::export_retrieve,
+        crate::routes::subscriptions::subscriptions_create,
+        crate::routes::subscriptions::subscriptions_retrieve,
+        crate::routes::subscriptions::subscriptions_pause,
+        crate::routes::subscriptions::subscriptions_resume,
+        crate::routes::subscriptions::subscriptions_cancel,
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -130,6 +165,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
+        api_models::enums::AttemptStatus,
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
@@ -139,6 +175,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::SupportedWallets,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
+        api_models::enums::DisputeStage,
+        api_models::enums::DisputeStatus,
+        api_models::enums::FilePurpose,
         api_models::admin::PaymentConnectorCreate,
         api_models::admin::PaymentMethods,
         api_models::payments::AddressDetails,
@@ -158,11 +199,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::MandateType,
         api_models::payments::AcceptanceType,
         api_models::payments::MandateAmountData,
+        api_models::payments::MandateFrequency,
+        api_models::enums::MandateFrequencyPeriod,
         api_models::payments::OnlineMandate,
         api_models::payments::Card,
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsResponse,
+        api_models::payments::PaymentAttemptResponse,
         api_models::payment_methods::PaymentExperience,
         api_models::payments::PaymentsStartRequest,
         api_models::payments::PaymentRetrieveBody,
@@ -179,10 +223,44 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GpayTokenParameters,
         api_models::payments::GpayTransactionInfo,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsIncrementalAuthorizationRequest,
+        api_models::payments::PaymentsPreAuthenticateRequest,
+        api_models::payments::PaymentsAuthenticateRequest,
+        api_models::payments::ThreeDsData,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutUpdateRequest,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::PayoutCard,
+        api_models::payouts::PayoutBank,
+        api_models::payouts::PayoutResponse,
+        api_models::payouts::PayoutListRequest,
+        api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutAccountsListRequest,
+        api_models::payouts::PayoutAccount,
+        api_models::payouts::PayoutAccountsListResponse,
+        api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeListConstraints,
+        api_models::disputes::SubmitEvidenceRequest,
+        api_models::files::CreateFileRequest,
+        api_models::files::CreateFileResponse,
+        api_models::admin::RoutingDryRunResponse,
+        api_models::admin::ConnectorHealthResponse,
+        api_models::admin::ConnectorHealthOverrideRequest,
+        api_models::enums::ConnectorHealthStatus,
+        api_models::exports::ExportRequest,
+        api_models::exports::ExportResponse,
+        api_models::enums::ExportEntity,
+        api_models::enums::ExportFormat,
+        api_models::enums::ExportStatus,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInterval,
+        api_models::enums::ApiKeyScope,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
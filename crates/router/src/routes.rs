@@ -2,20 +2,31 @@ pub mod admin;
 pub mod api_keys;
 pub mod app;
 pub mod configs;
+pub mod connector_health;
 pub mod customers;
+pub mod disputes;
 pub mod ephemeral_key;
+pub mod exports;
+pub mod files;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
+#[cfg(feature = "mock_three_ds")]
+pub mod mock_three_ds;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod routing;
+pub mod subscriptions;
 pub mod webhooks;
 
+#[cfg(feature = "mock_three_ds")]
+pub use self::app::MockThreeDs;
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    ApiKeys, AppState, Configs, ConnectorHealth, Customers, Disputes, EphemeralKey, Exports, Files,
+    Health, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts,
+    Refunds, Routing, Subscriptions, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -1,12 +1,17 @@
 use actix_web::{web, Scope};
 
 use super::health::*;
+#[cfg(feature = "mock_three_ds")]
+use super::mock_three_ds;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{admin::*, api_keys::*, connector_health::*, exports::*, routing::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
+use super::{
+    configs::*, customers::*, disputes::*, mandates::*, payments::*, payouts::*, refunds::*,
+    subscriptions::*,
+};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{ephemeral_key::*, files::*, payment_methods::*, webhooks::*};
 use crate::{
     configs::settings::Settings,
     db::{MockDb, StorageImpl, StorageInterface},
@@ -71,6 +76,24 @@ impl Health {
     }
 }
 
+#[cfg(feature = "mock_three_ds")]
+pub struct MockThreeDs;
+
+#[cfg(feature = "mock_three_ds")]
+impl MockThreeDs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/mock_three_ds")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/preauth").route(web::post().to(mock_three_ds::pre_authenticate)),
+            )
+            .service(web::resource("/auth").route(web::post().to(mock_three_ds::authenticate)))
+            .service(web::resource("/results").route(web::post().to(mock_three_ds::get_results)))
+            .service(web::resource("/method").route(web::post().to(mock_three_ds::three_ds_method)))
+            .service(web::resource("/challenge").route(web::post().to(mock_three_ds::challenge)))
+    }
+}
+
 pub struct Payments;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -104,6 +127,18 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/incremental_authorization")
+                        .route(web::post().to(payments_incremental_authorization)),
+                )
+                .service(
+                    web::resource("/{payment_id}/3ds/pre_authenticate")
+                        .route(web::post().to(payments_pre_authenticate)),
+                )
+                .service(
+                    web::resource("/{payment_id}/3ds/authenticate")
+                        .route(web::post().to(payments_authenticate)),
+                )
                 .service(
                     web::resource("/start/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -185,22 +220,68 @@ impl Payouts {
 
         #[cfg(feature = "olap")]
         {
-            route =
-                route.service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
+            route = route.service(web::resource("/list").route(web::get().to(payouts_list)));
         }
         #[cfg(feature = "oltp")]
         {
             route = route
                 .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
-                .service(web::resource("/update").route(web::post().to(payouts_update)))
-                .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(web::resource("/accounts").route(web::get().to(payouts_accounts)))
+                .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
+                .service(
+                    web::resource("/{payout_id}/reverse").route(web::post().to(payouts_reverse)),
+                )
+                .service(
+                    web::resource("/{payout_id}")
+                        .route(web::get().to(payouts_retrieve))
+                        .route(web::post().to(payouts_update)),
+                );
         }
         route
     }
 }
 
+pub struct Disputes;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Disputes {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/disputes").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route =
+                route.service(web::resource("/list").route(web::get().to(retrieve_disputes_list)));
+        }
+        #[cfg(feature = "oltp")]
+        {
+            route = route
+                .service(
+                    web::resource("/accept/{dispute_id}").route(web::post().to(accept_dispute)),
+                )
+                .service(web::resource("/evidence").route(web::post().to(submit_dispute_evidence)))
+                .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)));
+        }
+        route
+    }
+}
+
+pub struct Files;
+
+#[cfg(feature = "oltp")]
+impl Files {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/files")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(files_create)))
+            .service(
+                web::resource("/{file_id}")
+                    .route(web::delete().to(files_delete))
+                    .route(web::get().to(files_retrieve)),
+            )
+    }
+}
+
 pub struct PaymentMethods;
 
 #[cfg(feature = "oltp")]
@@ -240,6 +321,45 @@ impl MerchantAccount {
     }
 }
 
+pub struct Routing;
+
+#[cfg(feature = "olap")]
+impl Routing {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/routing")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/dry_run").route(web::post().to(routing_dry_run)))
+    }
+}
+
+pub struct ConnectorHealth;
+
+#[cfg(feature = "olap")]
+impl ConnectorHealth {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_health")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(connector_health_retrieve))
+                    .route(web::post().to(connector_health_override))
+                    .route(web::delete().to(connector_health_override_delete)),
+            )
+    }
+}
+
+pub struct Exports;
+
+#[cfg(feature = "olap")]
+impl Exports {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/exports")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(export_create)))
+            .service(web::resource("/{export_id}").route(web::get().to(export_retrieve)))
+    }
+}
+
 pub struct MerchantConnectorAccount;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -306,6 +426,32 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(subscriptions_create)))
+            .service(
+                web::resource("/{subscription_id}").route(web::get().to(subscriptions_retrieve)),
+            )
+            .service(
+                web::resource("/{subscription_id}/pause")
+                    .route(web::post().to(subscriptions_pause)),
+            )
+            .service(
+                web::resource("/{subscription_id}/resume")
+                    .route(web::post().to(subscriptions_resume)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscriptions_cancel)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(feature = "oltp")]
@@ -327,6 +473,7 @@ impl Configs {
     pub fn server(config: AppState) -> Scope {
         web::scope("/configs")
             .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::post().to(config_key_create)))
             .service(
                 web::resource("/{key}")
                     .route(web::get().to(config_key_retrieve))
@@ -8,6 +8,24 @@ use crate::{
     types::api as api_types,
 };
 
+#[instrument(skip_all, fields(flow = ?Flow::ConfigKeyCreate))]
+pub async fn config_key_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::Config>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, _, payload| configs::set_config(&*state.store, payload),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::ConfigKeyFetch))]
 pub async fn config_key_retrieve(
     state: web::Data<AppState>,
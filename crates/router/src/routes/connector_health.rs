@@ -0,0 +1,109 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::connector_health,
+    services::{api, authentication as auth},
+    types::api::admin,
+};
+
+// Connector Health - Retrieve
+
+///
+/// Retrieve the health of a connector, as tracked by its circuit breaker over a rolling window of calls
+#[utoipa::path(
+    get,
+    path = "/connector_health/{connector}",
+    params (("connector" = String, Path, description = "The name of the connector")),
+    responses(
+        (status = 200, description = "Connector health retrieved", body = ConnectorHealthResponse),
+        (status = 400, description = "Invalid connector")
+    ),
+    tag = "Connector Health",
+    operation_id = "Retrieve the Health of a Connector"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorHealthRetrieve))]
+pub async fn connector_health_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let connector = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        connector,
+        |state, _, connector| connector_health::retrieve_connector_health(state, connector),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+// Connector Health - Override
+
+///
+/// Override the health of a connector, so that payments are routed to it or away from it regardless of its circuit breaker
+#[utoipa::path(
+    post,
+    path = "/connector_health/{connector}",
+    params (("connector" = String, Path, description = "The name of the connector")),
+    request_body = ConnectorHealthOverrideRequest,
+    responses(
+        (status = 200, description = "Connector health overridden", body = ConnectorHealthResponse),
+        (status = 400, description = "Invalid connector")
+    ),
+    tag = "Connector Health",
+    operation_id = "Override the Health of a Connector"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorHealthOverride))]
+pub async fn connector_health_override(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::ConnectorHealthOverrideRequest>,
+) -> HttpResponse {
+    let connector = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (connector, json_payload.into_inner()),
+        |state, _, (connector, req)| {
+            connector_health::override_connector_health(state, connector, req)
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+// Connector Health - Delete Override
+
+///
+/// Remove the override of the health of a connector, so that its health is tracked by its circuit breaker again
+#[utoipa::path(
+    delete,
+    path = "/connector_health/{connector}",
+    params (("connector" = String, Path, description = "The name of the connector")),
+    responses(
+        (status = 200, description = "Connector health override removed", body = ConnectorHealthResponse),
+        (status = 400, description = "Invalid connector")
+    ),
+    tag = "Connector Health",
+    operation_id = "Remove the Health Override of a Connector"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorHealthOverrideDelete))]
+pub async fn connector_health_override_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let connector = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        connector,
+        |state, _, connector| connector_health::delete_connector_health_override(state, connector),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
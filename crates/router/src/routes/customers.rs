@@ -34,7 +34,7 @@ pub async fn customers_create(
         &req,
         json_payload.into_inner(),
         |state, merchant_account, req| create_customer(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersCreate),
     )
     .await
 }
@@ -65,11 +65,17 @@ pub async fn customers_retrieve(
     })
     .into_inner();
 
-    let auth =
-        match auth::is_ephemeral_auth(req.headers(), &*state.store, &payload.customer_id).await {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let auth = match auth::is_ephemeral_auth(
+        req.headers(),
+        &*state.store,
+        &payload.customer_id,
+        Flow::CustomersRetrieve,
+    )
+    .await
+    {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     api::server_wrap(
         state.get_ref(),
@@ -111,7 +117,7 @@ pub async fn customers_update(
         &req,
         json_payload.into_inner(),
         |state, merchant_account, req| update_customer(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersUpdate),
     )
     .await
 }
@@ -146,7 +152,7 @@ pub async fn customers_delete(
         &req,
         payload,
         delete_customer,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersDelete),
     )
     .await
 }
@@ -168,7 +174,7 @@ pub async fn get_customer_mandates(
         |state, merchant_account, req| {
             crate::core::mandate::get_customer_mandates(state, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::CustomersGetMandates),
     )
     .await
 }
@@ -0,0 +1,155 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::disputes,
+    services::{api, authentication as auth},
+    types::api::disputes as dispute_types,
+};
+
+// Disputes - Retrieve
+
+///
+/// To retrieve a dispute raised against a payment
+#[utoipa::path(
+    get,
+    path = "/disputes/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was retrieved successfully", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve a Dispute"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+// #[get("/{dispute_id}")]
+pub async fn retrieve_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        disputes::retrieve_dispute,
+        &auth::ApiKeyAuth(Flow::DisputesRetrieve),
+    )
+    .await
+}
+
+// Disputes - List
+
+///
+/// To list the disputes of the merchant, optionally filtered by the given constraints
+#[utoipa::path(
+    get,
+    path = "/disputes/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of Dispute Objects to include in the response"),
+        ("dispute_status" = Option<DisputeStatus>, Query, description = "The status of dispute"),
+        ("dispute_stage" = Option<DisputeStage>, Query, description = "The stage of dispute"),
+        ("reason" = Option<String>, Query, description = "The reason for dispute"),
+        ("connector" = Option<String>, Query, description = "The connector linked to dispute"),
+        ("received_time" = Option<PrimitiveDateTime>, Query, description = "The time at which dispute is received"),
+        ("received_time.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the dispute received time"),
+        ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
+        ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
+        ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+    ),
+    responses(
+        (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "List Disputes"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+#[cfg(feature = "olap")]
+// #[get("/list")]
+pub async fn retrieve_disputes_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<dispute_types::DisputeListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        disputes::retrieve_disputes_list,
+        &auth::ApiKeyAuth(Flow::DisputesList),
+    )
+    .await
+}
+
+// Disputes - Accept
+
+///
+/// To accept an open dispute, conceding it to the customer
+#[utoipa::path(
+    post,
+    path = "/disputes/accept/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was accepted successfully", body = DisputeResponse),
+        (status = 400, description = "The dispute cannot be accepted"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Accept a Dispute"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesAccept))]
+// #[post("/accept/{dispute_id}")]
+pub async fn accept_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        disputes::accept_dispute,
+        &auth::ApiKeyAuth(Flow::DisputesAccept),
+    )
+    .await
+}
+
+// Disputes - Submit Evidence
+
+///
+/// To submit evidence for an open dispute, challenging it at the connector
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence",
+    request_body=SubmitEvidenceRequest,
+    responses(
+        (status = 200, description = "The dispute evidence submitted successfully", body = DisputeResponse),
+        (status = 400, description = "The dispute evidence cannot be submitted"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Submit Dispute Evidence"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+// #[post("/evidence")]
+pub async fn submit_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_types::SubmitEvidenceRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        disputes::submit_evidence,
+        &auth::ApiKeyAuth(Flow::DisputesEvidenceSubmit),
+    )
+    .await
+}
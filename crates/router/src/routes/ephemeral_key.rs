@@ -22,7 +22,7 @@ pub async fn ephemeral_key_create(
         |state, merchant_account, req| {
             helpers::make_ephemeral_key(state, req.customer_id, merchant_account.merchant_id)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::EphemeralKeyCreate),
     )
     .await
 }
@@ -39,7 +39,7 @@ pub async fn ephemeral_key_delete(
         &req,
         payload,
         |state, _, req| helpers::delete_ephemeral_key(&*state.store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::EphemeralKeyDelete),
     )
     .await
 }
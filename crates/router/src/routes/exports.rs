@@ -0,0 +1,78 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::exports,
+    services::{api, authentication as auth},
+    types::api::exports as export_types,
+};
+
+// Exports - Create
+
+///
+/// To export the payments, payment attempts or refunds of the merchant created within a time
+/// range, for reconciliation. The records are written to a file asynchronously, which can be
+/// downloaded once the export is completed.
+#[utoipa::path(
+    post,
+    path = "/exports",
+    request_body = ExportRequest,
+    responses(
+        (status = 200, description = "Export requested", body = ExportResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Exports",
+    operation_id = "Create an Export"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ExportCreate))]
+// #[post("")]
+pub async fn export_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<export_types::ExportRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        exports::create_export,
+        &auth::ApiKeyAuth(Flow::ExportCreate),
+    )
+    .await
+}
+
+// Exports - Retrieve
+
+///
+/// To retrieve the status of an export, along with the link from which the exported file can be
+/// downloaded once the export is completed
+#[utoipa::path(
+    get,
+    path = "/exports/{export_id}",
+    params(
+        ("export_id" = String, Path, description = "The identifier for export")
+    ),
+    responses(
+        (status = 200, description = "Export retrieved", body = ExportResponse),
+        (status = 404, description = "Export does not exist in our records")
+    ),
+    tag = "Exports",
+    operation_id = "Retrieve an Export"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ExportRetrieve))]
+// #[get("/{export_id}")]
+pub async fn export_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        exports::retrieve_export,
+        &auth::ApiKeyAuth(Flow::ExportRetrieve),
+    )
+    .await
+}
@@ -0,0 +1,120 @@
+pub mod transformers;
+
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::files::*,
+    services::{api, authentication as auth},
+};
+
+// Files - Create
+
+///
+/// To create a file, which can be used as evidence for a dispute
+#[utoipa::path(
+    post,
+    path = "/files",
+    request_body(content = CreateFileRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "File created", body = CreateFileResponse),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Create a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateFile))]
+// #[post("")]
+pub async fn files_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    // The multipart body is read once the request is authenticated, so that unauthenticated
+    // requests cannot have the body buffered in memory
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        transformers::CreateFileRequestPayload(payload),
+        |state, merchant_account, payload| async move {
+            let create_file_request = transformers::get_create_file_request(
+                payload.0,
+                state.conf.file_upload.max_file_size,
+            )
+            .await?;
+            files_create_core(state, merchant_account, create_file_request).await
+        },
+        &auth::ApiKeyAuth(Flow::CreateFile),
+    )
+    .await
+}
+
+// Files - Delete
+
+///
+/// To delete a file
+#[utoipa::path(
+    delete,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File deleted"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Delete a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteFile))]
+// #[delete("/{file_id}")]
+pub async fn files_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        files_delete_core,
+        &auth::ApiKeyAuth(Flow::DeleteFile),
+    )
+    .await
+}
+
+// Files - Retrieve
+
+///
+/// To retrieve the contents of a file
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File body"),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Retrieve a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RetrieveFile))]
+// #[get("/{file_id}")]
+pub async fn files_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        files_retrieve_core,
+        &auth::ApiKeyAuth(Flow::RetrieveFile),
+    )
+    .await
+}
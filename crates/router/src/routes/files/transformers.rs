@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use actix_multipart::Multipart;
+use error_stack::{IntoReport, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::api,
+    utils::OptionExt,
+};
+
+/// Maximum size in bytes of the multipart fields other than the file
+const MAX_TEXT_FIELD_SIZE: usize = 1024;
+
+/// Multipart body of a file creation request, which is read only once the request is
+/// authenticated
+pub struct CreateFileRequestPayload(pub Multipart);
+
+impl std::fmt::Debug for CreateFileRequestPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateFileRequestPayload")
+            .finish_non_exhaustive()
+    }
+}
+
+pub async fn get_create_file_request(
+    mut payload: Multipart,
+    max_file_size: usize,
+) -> RouterResult<api::CreateFileRequest> {
+    let mut option_purpose: Option<api::FilePurpose> = None;
+    let mut dispute_id: Option<String> = None;
+    let mut file_name: Option<String> = None;
+    let mut provided_file_name: Option<String> = None;
+    let mut file_content: Option<Vec<u8>> = None;
+    let mut file_type: Option<mime::Mime> = None;
+
+    while let Some(mut field) = payload.try_next().await.into_report().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Unable to read the multipart body of the request".to_owned(),
+        },
+    )? {
+        let field_name = field
+            .content_disposition()
+            .get_name()
+            .map(ToOwned::to_owned);
+        match field_name.as_deref() {
+            Some("purpose") => {
+                option_purpose =
+                    Some(get_field_as_string(&mut field).await.and_then(|purpose| {
+                        api::FilePurpose::from_str(&purpose)
+                            .into_report()
+                            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                                field_name: "purpose",
+                            })
+                    })?);
+            }
+            Some("dispute_id") => {
+                dispute_id = Some(get_field_as_string(&mut field).await?);
+            }
+            Some("file") => {
+                file_name = field.content_disposition().get_filename().map(String::from);
+                file_type = field.content_type().cloned();
+                file_content = Some(read_field(&mut field, max_file_size).await?);
+            }
+            Some("file_name") => {
+                provided_file_name = Some(get_field_as_string(&mut field).await?);
+            }
+            _ => (),
+        }
+    }
+    let purpose = option_purpose.get_required_value("purpose")?;
+    let file = file_content.get_required_value("file")?;
+    let file_size = i32::try_from(file.len()).into_report().change_context(
+        errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file_size exceeds the maximum supported file size".to_owned(),
+        },
+    )?;
+    let file_type = file_type.get_required_value("file_type")?;
+    Ok(api::CreateFileRequest {
+        file,
+        // An explicitly provided file name takes precedence over the one in the file field
+        file_name: provided_file_name.or(file_name),
+        file_size,
+        file_type,
+        purpose,
+        dispute_id,
+    })
+}
+
+async fn read_field(field: &mut actix_multipart::Field, max_size: usize) -> RouterResult<Vec<u8>> {
+    let mut data = Vec::new();
+    while let Some(chunk) = field.next().await {
+        let chunk =
+            chunk
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Unable to read the multipart field from the request".to_owned(),
+                })?;
+        // The field is read no further once it exceeds the maximum size, so that its size is
+        // bounded in memory
+        if data.len() + chunk.len() > max_size {
+            return Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!(
+                    "file_size exceeds the maximum supported file size of {max_size} bytes"
+                ),
+            })
+            .into_report();
+        }
+        data.extend_from_slice(&chunk);
+    }
+    Ok(data)
+}
+
+async fn get_field_as_string(field: &mut actix_multipart::Field) -> RouterResult<String> {
+    String::from_utf8(read_field(field, MAX_TEXT_FIELD_SIZE).await?)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The multipart field is not a valid UTF-8 string".to_owned(),
+        })
+}
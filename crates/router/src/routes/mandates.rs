@@ -40,7 +40,7 @@ pub async fn get_mandate(
         &req,
         mandate_id,
         mandate::get_mandate,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::MandatesRetrieve),
     )
     .await
 }
@@ -76,10 +76,8 @@ pub async fn revoke_mandate(
         state.get_ref(),
         &req,
         mandate_id,
-        |state, merchant_account, req| {
-            mandate::revoke_mandate(&*state.store, merchant_account, req)
-        },
-        &auth::ApiKeyAuth,
+        mandate::revoke_mandate,
+        &auth::ApiKeyAuth(Flow::MandatesRevoke),
     )
     .await
 }
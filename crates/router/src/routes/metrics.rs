@@ -13,3 +13,15 @@ pub(crate) static HEALTH_METRIC: Lazy<Counter<u64>> =
 
 pub(crate) static KV_MISS: Lazy<Counter<u64>> =
     Lazy::new(|| GLOBAL_METER.u64_counter("KV_MISS").init());
+
+pub(crate) static ACCESS_TOKEN_CACHE_HIT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("ACCESS_TOKEN_CACHE_HIT").init());
+
+pub(crate) static ACCESS_TOKEN_CACHE_MISS: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("ACCESS_TOKEN_CACHE_MISS").init());
+
+pub(crate) static ACCESS_TOKEN_REFRESHED: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("ACCESS_TOKEN_REFRESHED").init());
+
+pub(crate) static CONNECTOR_CIRCUIT_OPENED: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("CONNECTOR_CIRCUIT_OPENED").init());
@@ -0,0 +1,83 @@
+use actix_web::{web, HttpResponse, Responder};
+use router_env::{instrument, tracing};
+
+use super::app::AppState;
+use crate::{
+    connector::mock_three_ds::transformers::{
+        MockThreeDsAuthenticationRequest, MockThreeDsPreAuthenticationRequest,
+        MockThreeDsResultsRequest,
+    },
+    core::mock_three_ds,
+    services::api,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ThreeDsMethodForm {
+    #[serde(rename = "threeDSMethodData")]
+    pub three_ds_method_data: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ChallengeForm {
+    pub creq: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ChallengeResponse {
+    pub cres: String,
+}
+
+fn to_response<T: serde::Serialize>(
+    response: crate::core::errors::RouterResult<T>,
+) -> HttpResponse {
+    match response {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(error) => api::log_and_return_error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn pre_authenticate(
+    state: web::Data<AppState>,
+    json_payload: web::Json<MockThreeDsPreAuthenticationRequest>,
+) -> impl Responder {
+    to_response(mock_three_ds::pre_authenticate(
+        &state.conf.server.base_url,
+        json_payload.into_inner(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn authenticate(
+    state: web::Data<AppState>,
+    json_payload: web::Json<MockThreeDsAuthenticationRequest>,
+) -> impl Responder {
+    to_response(mock_three_ds::authenticate(
+        &state.conf.server.base_url,
+        json_payload.into_inner(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn get_results(
+    state: web::Data<AppState>,
+    json_payload: web::Json<MockThreeDsResultsRequest>,
+) -> impl Responder {
+    to_response(mock_three_ds::get_results(
+        &state.conf.server.base_url,
+        json_payload.into_inner(),
+    ))
+}
+
+/// The 3DS method loaded by the browser of the customer, which has nothing to collect here
+#[instrument(skip_all)]
+pub async fn three_ds_method(_form: web::Form<ThreeDsMethodForm>) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("text/html")
+        .body("<html><body></body></html>")
+}
+
+#[instrument(skip_all)]
+pub async fn challenge(form: web::Form<ChallengeForm>) -> impl Responder {
+    to_response(mock_three_ds::challenge(&form.creq).map(|cres| ChallengeResponse { cres }))
+}
@@ -33,10 +33,6 @@ pub async fn payments_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     api::server_wrap(
         state.get_ref(),
         &req,
@@ -50,7 +46,7 @@ pub async fn payments_create(
                 api::AuthFlow::Merchant,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsCreate),
     )
     .await
 }
@@ -137,10 +133,11 @@ pub async fn payments_retrieve(
         param: None,
         connector: None,
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Flow::PaymentsRetrieve) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     api::server_wrap(
         state.get_ref(),
@@ -197,10 +194,11 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Flow::PaymentsUpdate) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     api::server_wrap(
         state.get_ref(),
@@ -256,11 +254,14 @@ pub async fn payments_confirm(
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Flow::PaymentsConfirm,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
 
     api::server_wrap(
         state.get_ref(),
@@ -325,7 +326,7 @@ pub async fn payments_capture(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsCapture),
     )
     .await
 }
@@ -472,7 +473,187 @@ pub async fn payments_cancel(
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsCancel),
+    )
+    .await
+}
+
+// Payments - Incremental Authorization
+
+///
+/// To increase the amount authorized for a payment which is yet to be captured. The new total amount has to be greater than the amount authorized so far.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/incremental_authorization",
+    request_body=PaymentsIncrementalAuthorizationRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment authorization incremented", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Increment the authorization of a Payment"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsIncrementalAuthorization))]
+pub async fn payments_incremental_authorization(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsIncrementalAuthorizationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<
+                api_types::IncrementalAuthorization,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                merchant_account,
+                payments::PaymentIncrementalAuthorization,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &auth::ApiKeyAuth(Flow::PaymentsIncrementalAuthorization),
+    )
+    .await
+}
+
+// Payments - 3DS Pre Authenticate
+
+///
+/// To start the 3DS authentication of the customer of a payment with the card to be charged. The next action of the payment gives the 3DS method to be performed by the SDK, or the challenge to be answered by the customer.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/3ds/pre_authenticate",
+    request_body=PaymentsPreAuthenticateRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "3DS authentication started", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Start the 3DS authentication of a Payment"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsPreAuthenticate))]
+pub async fn payments_pre_authenticate(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsPreAuthenticateRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Flow::PaymentsPreAuthenticate,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<
+                api_types::PreAuthenticate,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                merchant_account,
+                payments::PaymentPreAuthenticate,
+                req,
+                auth_flow,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &*auth_type,
+    )
+    .await
+}
+
+// Payments - 3DS Authenticate
+
+///
+/// To authenticate the customer of a payment with 3DS, once the 3DS method given in the next action is performed or the challenge is answered. The payment can be confirmed once the customer is authenticated.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/3ds/authenticate",
+    request_body=PaymentsAuthenticateRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Customer authenticated or challenged", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Authenticate the customer of a Payment with 3DS"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsAuthenticate))]
+pub async fn payments_authenticate(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsAuthenticateRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Flow::PaymentsAuthenticate,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<
+                api_types::Authenticate,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                merchant_account,
+                payments::PaymentAuthenticate,
+                req,
+                auth_flow,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &*auth_type,
     )
     .await
 }
@@ -518,7 +699,7 @@ pub async fn payments_list(
         |state, merchant_account, req| {
             payments::list_payments(&*state.store, merchant_account, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::PaymentsList),
     )
     .await
 }
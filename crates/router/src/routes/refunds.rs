@@ -35,7 +35,7 @@ pub async fn refunds_create(
         &req,
         json_payload.into_inner(),
         refund_create_core,
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsCreate),
     )
     .await
 }
@@ -73,7 +73,7 @@ pub async fn refunds_retrieve(
         |state, merchant_account, refund_id| {
             refund_response_wrapper(state, merchant_account, refund_id, refund_retrieve_core)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsRetrieve),
     )
     .await
 }
@@ -112,7 +112,7 @@ pub async fn refunds_update(
         |state, merchant_account, req| {
             refund_update_core(&*state.store, merchant_account, &refund_id, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsUpdate),
     )
     .await
 }
@@ -153,7 +153,7 @@ pub async fn refunds_list(
         &req,
         payload.into_inner(),
         |state, merchant_account, req| refund_list(&*state.store, merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Flow::RefundsList),
     )
     .await
 }
@@ -0,0 +1,41 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payments::routing,
+    services::{api, authentication as auth},
+    types::api::payments,
+};
+
+// Routing - Dry Run
+
+///
+/// To find out which connector a sample payment would be routed to by the merchant's routing algorithm, without creating the payment
+#[utoipa::path(
+    post,
+    path = "/routing/dry_run",
+    request_body=PaymentsRequest,
+    responses(
+        (status = 200, description = "The routing algorithm was evaluated successfully", body = RoutingDryRunResponse),
+        (status = 400, description = "A routing algorithm is not configured for the merchant")
+    ),
+    tag = "Routing",
+    operation_id = "Dry run the Routing Algorithm"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingDryRun))]
+// #[post("/dry_run")]
+pub async fn routing_dry_run(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payments::PaymentsRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        routing::routing_dry_run,
+        &auth::ApiKeyAuth(Flow::RoutingDryRun),
+    )
+    .await
+}
@@ -0,0 +1,179 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::subscriptions,
+    services::{api, authentication as auth},
+    types::api::subscriptions as subscription_types,
+};
+
+// Subscriptions - Create
+
+///
+/// To charge a customer a fixed amount on a recurring schedule against one of their active
+/// mandates. The first charge is made at `start_at`, or right away if it is not given.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCreate))]
+// #[post("")]
+pub async fn subscriptions_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        subscriptions::create_subscription,
+        &auth::ApiKeyAuth(Flow::SubscriptionsCreate),
+    )
+    .await
+}
+
+// Subscriptions - Retrieve
+
+///
+/// To retrieve a subscription, along with the time of its next charge
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsRetrieve))]
+// #[get("/{subscription_id}")]
+pub async fn subscriptions_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        subscriptions::retrieve_subscription,
+        &auth::ApiKeyAuth(Flow::SubscriptionsRetrieve),
+    )
+    .await
+}
+
+// Subscriptions - Pause
+
+///
+/// To stop charging a subscription until it is resumed
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/pause",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription paused", body = SubscriptionResponse),
+        (status = 400, description = "Subscription cannot be paused")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Pause a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsPause))]
+// #[post("/{subscription_id}/pause")]
+pub async fn subscriptions_pause(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        subscriptions::pause_subscription,
+        &auth::ApiKeyAuth(Flow::SubscriptionsPause),
+    )
+    .await
+}
+
+// Subscriptions - Resume
+
+///
+/// To resume charging a paused subscription. The billing cycles which fell due while the
+/// subscription was paused are not charged.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/resume",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription resumed", body = SubscriptionResponse),
+        (status = 400, description = "Subscription cannot be resumed")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Resume a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsResume))]
+// #[post("/{subscription_id}/resume")]
+pub async fn subscriptions_resume(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        subscriptions::resume_subscription,
+        &auth::ApiKeyAuth(Flow::SubscriptionsResume),
+    )
+    .await
+}
+
+// Subscriptions - Cancel
+
+///
+/// To stop charging a subscription for good
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 400, description = "Subscription cannot be cancelled")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCancel))]
+// #[post("/{subscription_id}/cancel")]
+pub async fn subscriptions_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        subscriptions::cancel_subscription,
+        &auth::ApiKeyAuth(Flow::SubscriptionsCancel),
+    )
+    .await
+}
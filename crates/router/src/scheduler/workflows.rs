@@ -4,8 +4,14 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod capture_sync;
+pub mod export;
+pub mod idempotency_key_cleanup;
+pub mod outgoing_webhook_retry;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod scheduled_capture;
+pub mod subscription;
 
 macro_rules! runners {
     ($($body:tt),*) => {
@@ -44,7 +50,13 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    RefundWorkflowRouter,
+    OutgoingWebhookRetryWorkflow,
+    ScheduledCaptureWorkflow,
+    ExportWorkflow,
+    SubscriptionWorkflow,
+    CaptureSyncWorkflow,
+    IdempotencyKeyCleanupWorkflow
 }
 
 #[async_trait]
@@ -0,0 +1,91 @@
+use super::{payment_sync, CaptureSyncWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for CaptureSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: payment_flows::CaptureSyncTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("CaptureSyncTrackingData")?;
+
+        let capture = db
+            .find_capture_by_merchant_id_capture_id(
+                &tracking_data.merchant_id,
+                &tracking_data.capture_id,
+            )
+            .await?;
+        // The capture could have been charged or failed through a webhook of the connector or a
+        // sync of the payment meanwhile
+        if capture.status != enums::CaptureStatus::Pending {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await;
+        }
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let request = api::PaymentsRetrieveRequest {
+            force_sync: true,
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            resource_id: api::PaymentIdType::PaymentAttemptId(tracking_data.attempt_id.clone()),
+            param: None,
+            connector: None,
+        };
+        payment_flows::payments_operation_core::<api::PSync, _, _, _>(
+            state,
+            merchant_account,
+            operations::PaymentStatus,
+            request,
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await?;
+
+        let capture = db
+            .find_capture_by_merchant_id_capture_id(
+                &tracking_data.merchant_id,
+                &tracking_data.capture_id,
+            )
+            .await?;
+        match capture.status {
+            enums::CaptureStatus::Pending => {
+                payment_sync::retry_sync_task(db, capture.connector, capture.merchant_id, process)
+                    .await
+            }
+            _ => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,49 @@
+use router_env::logger;
+
+use super::{ExportWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::exports,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: exports::ExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ExportTrackingData")?;
+
+        match exports::write_export(state, tracking_data).await {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+            Err(error) => {
+                logger::error!(?error, "Export of the records failed");
+                Err(errors::ProcessTrackerError::FlowExecutionError { flow: "Export" })?
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
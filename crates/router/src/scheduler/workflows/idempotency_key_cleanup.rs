@@ -0,0 +1,52 @@
+use super::{IdempotencyKeyCleanupWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::idempotency,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for IdempotencyKeyCleanupWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: idempotency::IdempotencyKeyCleanupTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("IdempotencyKeyCleanupTrackingData")?;
+
+        let next_expiry = idempotency::delete_expired_idempotency_keys(
+            db,
+            &tracking_data.merchant_id,
+            state.conf.idempotency.key_ttl_secs,
+        )
+        .await?;
+
+        match next_expiry {
+            // The task is run again once the oldest of the remaining keys expires
+            Some(schedule_time) => process.retry(db, schedule_time).await,
+            None => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
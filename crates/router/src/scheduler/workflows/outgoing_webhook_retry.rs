@@ -0,0 +1,119 @@
+use router_env::logger;
+
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    configs::settings,
+    core::webhooks,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let event = db.find_event_by_event_id(&tracking_data.event_id).await?;
+
+        let result = webhooks::trigger_webhook_to_merchant(
+            state,
+            &merchant_account,
+            event,
+            &tracking_data.webhook,
+        )
+        .await;
+
+        match result {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Err(error) if webhooks::is_webhook_delivery_failure(&error) => {
+                logger::error!(?error, "Outgoing webhook retry failed");
+                retry_webhook_delivery_task(db, &state.conf.webhooks, process).await?
+            }
+            Err(error) => {
+                logger::error!(?error);
+                Err(errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "OutgoingWebhookRetry",
+                })?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+pub async fn retry_webhook_delivery_task(
+    db: &dyn StorageInterface,
+    webhooks_settings: &settings::WebhooksSettings,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    // The current execution of the task is a retry in itself
+    let schedule_time =
+        webhooks::get_outgoing_webhook_retry_schedule_time(webhooks_settings, pt.retry_count + 1);
+
+    match schedule_time {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_retry_backoff() {
+        let webhooks_settings = settings::WebhooksSettings {
+            outgoing_enabled: true,
+            outgoing_max_retries: 3,
+            outgoing_retry_base_interval_secs: 60,
+        };
+        let now = common_utils::date_time::now();
+        let retry_delays = (0..3)
+            .map(|retry_count| {
+                webhooks::get_outgoing_webhook_retry_schedule_time(&webhooks_settings, retry_count)
+                    .unwrap()
+                    - now
+            })
+            .collect::<Vec<_>>();
+
+        for (retry_delay, expected_delay_secs) in retry_delays.into_iter().zip([60, 120, 240]) {
+            assert!(retry_delay >= time::Duration::seconds(expected_delay_secs));
+            assert!(retry_delay < time::Duration::seconds(expected_delay_secs + 5));
+        }
+        assert!(
+            webhooks::get_outgoing_webhook_retry_schedule_time(&webhooks_settings, 3).is_none()
+        );
+    }
+}
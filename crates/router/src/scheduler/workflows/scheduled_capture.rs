@@ -0,0 +1,160 @@
+use router_env::logger;
+
+use super::{payment_sync, ProcessTrackerWorkflow, ScheduledCaptureWorkflow};
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsCaptureRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsCaptureRequest")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                tracking_data
+                    .merchant_id
+                    .as_ref()
+                    .get_required_value("merchant_id")?,
+            )
+            .await?;
+        let payment_id = tracking_data
+            .payment_id
+            .as_ref()
+            .get_required_value("payment_id")?;
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id(
+                payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        match payment_intent.status {
+            enums::IntentStatus::RequiresCapture => {}
+            // The authorization of the payment is still in progress, the capture is attempted
+            // again once the payment has had time to be authorized
+            status if is_pending_authorization(status) => {
+                return retry_scheduled_capture_task(db, payment_attempt, process).await;
+            }
+            // Voided, failed or already captured payments have nothing left to capture
+            status => {
+                return process
+                    .finish_with_status(db, format!("PAYMENT_{status}").to_uppercase())
+                    .await;
+            }
+        }
+
+        let result = payment_flows::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+            state,
+            merchant_account,
+            operations::PaymentCapture,
+            tracking_data,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await;
+
+        match result {
+            Ok(_) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(?error, "Scheduled capture of the payment failed");
+                retry_scheduled_capture_task(db, payment_attempt, process).await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+fn is_pending_authorization(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresCustomerAction
+            | enums::IntentStatus::RequiresConfirmation
+            | enums::IntentStatus::RequiresPaymentMethod
+    )
+}
+
+/// The capture is retried on the schedule the connector has configured for syncing payments
+pub async fn retry_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: storage::PaymentAttempt,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let connector = payment_attempt
+        .connector
+        .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+    let schedule_time = payment_sync::get_sync_process_schedule_time(
+        db,
+        &connector,
+        &payment_attempt.merchant_id,
+        pt.retry_count,
+    )
+    .await?;
+
+    match schedule_time {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payments_pending_authorization() {
+        assert!(is_pending_authorization(enums::IntentStatus::Processing));
+        assert!(is_pending_authorization(
+            enums::IntentStatus::RequiresCustomerAction
+        ));
+        assert!(!is_pending_authorization(
+            enums::IntentStatus::RequiresCapture
+        ));
+        assert!(!is_pending_authorization(enums::IntentStatus::Cancelled));
+        assert!(!is_pending_authorization(enums::IntentStatus::Succeeded));
+    }
+}
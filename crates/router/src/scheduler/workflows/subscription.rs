@@ -0,0 +1,150 @@
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, SubscriptionWorkflow};
+use crate::{
+    core::subscriptions,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for SubscriptionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: subscriptions::SubscriptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        match subscription.status {
+            enums::SubscriptionStatus::Active | enums::SubscriptionStatus::PastDue => {}
+            // Paused or cancelled subscriptions are not charged
+            status => {
+                return process
+                    .finish_with_status(db, format!("SUBSCRIPTION_{status}").to_uppercase())
+                    .await;
+            }
+        }
+
+        match subscriptions::charge_subscription(state, &merchant_account, &subscription).await? {
+            subscriptions::ChargeOutcome::Charged { payment_id } => {
+                let billing_cycle = subscription.billing_cycle + 1;
+                let next_charge_at = subscriptions::get_charge_time(
+                    subscription.billing_anchor,
+                    subscription.billing_interval,
+                    subscription.interval_count,
+                    billing_cycle,
+                )
+                .ok_or(errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "Subscription",
+                })?;
+                let subscription = db
+                    .update_subscription(
+                        subscription,
+                        storage::SubscriptionUpdate::ScheduleUpdate {
+                            status: enums::SubscriptionStatus::Active,
+                            billing_cycle,
+                            next_charge_at,
+                            last_payment_id: Some(payment_id),
+                        },
+                    )
+                    .await?;
+                subscriptions::reschedule_subscription_task(db, &subscription).await?;
+            }
+            subscriptions::ChargeOutcome::Failed { payment_id } => {
+                match subscriptions::get_dunning_retry_schedule_time(
+                    &state.conf.subscriptions,
+                    process.retry_count,
+                ) {
+                    Some(schedule_time) => {
+                        db.update_subscription(
+                            subscription,
+                            storage::SubscriptionUpdate::PaymentFailedUpdate {
+                                status: enums::SubscriptionStatus::PastDue,
+                                last_payment_id: payment_id,
+                            },
+                        )
+                        .await?;
+                        process.retry(db, schedule_time).await?;
+                    }
+                    None => {
+                        logger::info!(
+                            subscription_id = %subscription.subscription_id,
+                            "Retries for the charge of the subscription exceeded, cancelling the subscription"
+                        );
+                        db.update_subscription(
+                            subscription,
+                            storage::SubscriptionUpdate::PaymentFailedUpdate {
+                                status: enums::SubscriptionStatus::Cancelled,
+                                last_payment_id: payment_id,
+                            },
+                        )
+                        .await?;
+                        process
+                            .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                            .await?;
+                    }
+                }
+            }
+            // The payment is checked again without counting against the retries of the charge
+            subscriptions::ChargeOutcome::Pending { payment_id } => {
+                logger::info!(
+                    subscription_id = %subscription.subscription_id,
+                    %payment_id,
+                    "Charge of the subscription is still being processed"
+                );
+                let schedule_time =
+                    subscriptions::get_pending_charge_schedule_time(&state.conf.subscriptions);
+                db.update_process_tracker(
+                    process.clone(),
+                    storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                        status: enums::ProcessTrackerStatus::Pending,
+                        retry_count: process.retry_count,
+                        schedule_time,
+                    },
+                )
+                .await?;
+            }
+            subscriptions::ChargeOutcome::MandateInactive => {
+                db.update_subscription(
+                    subscription,
+                    storage::SubscriptionUpdate::StatusUpdate {
+                        status: enums::SubscriptionStatus::Cancelled,
+                    },
+                )
+                .await?;
+                process
+                    .finish_with_status(db, "MANDATE_INACTIVE".to_string())
+                    .await?;
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -1,6 +1,7 @@
 pub mod api;
 pub mod authentication;
 pub mod encryption;
+pub mod file_storage;
 pub mod logger;
 
 use std::sync::Arc;
@@ -15,16 +15,17 @@ use masking::ExposeOptionInterface;
 use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
-use self::request::{ContentType, HeaderExt, RequestBuilderExt};
-pub use self::request::{Method, Request, RequestBuilder};
+pub use self::request::{ContentType, Method, Request, RequestBuilder};
+use self::request::{HeaderExt, RequestBuilderExt};
 use crate::{
     configs::settings::Connectors,
     core::{
+        api_keys, connector_health,
         errors::{self, CustomResult, RouterResponse, RouterResult},
-        payments,
+        idempotency, payments,
     },
     db::StorageInterface,
-    logger,
+    headers, logger,
     routes::{app::AppStateInfo, AppState},
     services::authentication as auth,
     types::{
@@ -83,6 +84,13 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
         Ok(None)
     }
 
+    fn get_request_form_data(
+        &self,
+        _req: &types::RouterData<T, Req, Resp>,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     fn build_request(
         &self,
         _req: &types::RouterData<T, Req, Resp>,
@@ -163,7 +171,17 @@ where
         payments::CallConnectorAction::Trigger => {
             match connector_integration.build_request(req, &state.conf.connectors)? {
                 Some(request) => {
+                    let current_time = Instant::now();
                     let response = call_connector_api(state, request).await;
+                    // Server errors and timeouts count as failures of the connector, unlike the
+                    // client errors caused by the request
+                    connector_health::record_connector_call(
+                        state,
+                        &req.connector,
+                        response.is_ok(),
+                        current_time.elapsed(),
+                    )
+                    .await;
                     match response {
                         Ok(body) => {
                             let response = match body {
@@ -241,6 +259,9 @@ async fn send_request(
                     logger::debug!(?url_encoded_payload);
                     client.body(url_encoded_payload)
                 }
+                Some(ContentType::FormData) => {
+                    client.multipart(request.form_data.unwrap_or_default())
+                }
                 // If payload needs processing the body cannot have default
                 None => client.body(request.payload.expose_option().unwrap_or_default()),
             }
@@ -251,6 +272,9 @@ async fn send_request(
                 .put(url)
                 .body(request.payload.expose_option().unwrap_or_default()) // If payload needs processing the body cannot have default
         }
+        Method::Patch => client
+            .patch(url)
+            .body(request.payload.expose_option().unwrap_or_default()),
         Method::Delete => client.delete(url),
     }
     .add_headers(headers)
@@ -340,6 +364,7 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(RedirectForm),
+    FileData((Vec<u8>, mime::Mime)),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -398,8 +423,12 @@ where
     T: Debug,
     A: AppStateInfo,
 {
+    let verified_api_key = request
+        .extensions()
+        .get::<api_keys::VerifiedApiKey>()
+        .cloned();
     let auth_out = api_auth
-        .authenticate_and_fetch(request.headers(), state)
+        .authenticate_and_fetch_verified(request.headers(), verified_api_key, state)
         .await?;
     func(state, auth_out, payload).await
 }
@@ -421,6 +450,7 @@ where
     Q: Serialize + Debug + 'a,
     T: Debug,
     A: AppStateInfo,
+    U: auth::AuthInfo,
 {
     let request_method = request.method().as_str();
     let url_path = request.path();
@@ -430,7 +460,41 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest);
 
-    let res = match server_wrap_util(state, request, payload, func, api_auth).await {
+    let res = match idempotency::get_idempotency_key(request) {
+        Some(idempotency_key) => idempotent_server_wrap_util(
+            state,
+            request,
+            payload,
+            func,
+            api_auth,
+            idempotency_key,
+            build_http_response,
+        )
+        .await
+        .unwrap_or_else(log_and_return_error_response),
+        None => build_http_response(
+            request,
+            server_wrap_util(state, request, payload, func, api_auth).await,
+        ),
+    };
+
+    let response_code = res.status().as_u16();
+    let end_instant = Instant::now();
+    let request_duration = end_instant.saturating_duration_since(start_instant);
+    logger::info!(
+        tag = ?Tag::EndRequest,
+        status_code = response_code,
+        time_taken_ms = request_duration.as_millis(),
+    );
+
+    res
+}
+
+fn build_http_response<Q: Serialize>(
+    request: &HttpRequest,
+    result: RouterResult<ApplicationResponse<Q>>,
+) -> HttpResponse {
+    match result {
         Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
             Ok(res) => http_response_json(res),
             Err(_) => http_response_err(
@@ -458,20 +522,138 @@ where
         Ok(ApplicationResponse::Form(response)) => build_redirection_form(&response)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(ApplicationResponse::FileData((file_data, content_type))) => {
+            http_response_file_data(file_data, content_type)
+        }
 
         Err(error) => log_and_return_error_response(error),
+    }
+}
+
+/// Requests with an idempotency key are processed once per merchant, later requests with the same
+/// key being served the response of the first request. The response is built by
+/// `build_response`, so that the response is replayed in the format in which it was first served.
+#[instrument(skip(request, payload, state, func, api_auth, build_response))]
+pub(crate) async fn idempotent_server_wrap_util<'a, 'b, A, U, T, Q, F, Fut, R>(
+    state: &'b A,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
+    idempotency_key: &str,
+    build_response: R,
+) -> RouterResult<HttpResponse>
+where
+    F: Fn(&'b A, U, T) -> Fut,
+    Fut: Future<Output = RouterResult<ApplicationResponse<Q>>>,
+    Q: Serialize + Debug + 'a,
+    T: Debug,
+    A: AppStateInfo,
+    U: auth::AuthInfo,
+    R: Fn(&HttpRequest, RouterResult<ApplicationResponse<Q>>) -> HttpResponse,
+{
+    let verified_api_key = request
+        .extensions()
+        .get::<api_keys::VerifiedApiKey>()
+        .cloned();
+    let auth_out = api_auth
+        .authenticate_and_fetch_verified(request.headers(), verified_api_key, state)
+        .await?;
+    let merchant_id = match auth_out.get_merchant_id() {
+        Some(merchant_id) => merchant_id.to_string(),
+        // Idempotency keys are scoped to the merchant, requests not made by a merchant are
+        // processed as is
+        None => {
+            return Ok(build_response(
+                request,
+                func(state, auth_out, payload).await,
+            ))
+        }
     };
 
-    let response_code = res.status().as_u16();
-    let end_instant = Instant::now();
-    let request_duration = end_instant.saturating_duration_since(start_instant);
-    logger::info!(
-        tag = ?Tag::EndRequest,
-        status_code = response_code,
-        time_taken_ms = request_duration.as_millis(),
-    );
+    let db = state.store();
+    let conf = state.conf();
+    let settings = &conf.idempotency;
+    let request_fingerprint = request
+        .extensions()
+        .get::<idempotency::RequestFingerprint>()
+        .map(|fingerprint| fingerprint.0.clone())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Request with an idempotency key was not fingerprinted")?;
+    let claimed_key = match idempotency::claim_idempotency_key(
+        &*db,
+        &merchant_id,
+        idempotency_key,
+        request_fingerprint,
+        settings,
+    )
+    .await?
+    {
+        idempotency::IdempotentRequest::Process(claimed_key) => claimed_key,
+        idempotency::IdempotentRequest::Replay {
+            status_code,
+            response_body,
+        } => return Ok(http_response_idempotent_replay(status_code, response_body)),
+    };
 
-    res
+    let result = func(state, auth_out, payload).await;
+    let is_replayed = is_idempotent_response_replayed(&result);
+    let response = build_response(request, result);
+    if !is_replayed {
+        idempotency::release_idempotency_key(&*db, &claimed_key).await;
+        return Ok(response);
+    }
+
+    let (response, response_body) = take_response_body(response);
+    match response_body {
+        Some(response_body) => {
+            idempotency::save_idempotent_response(
+                &*db,
+                claimed_key,
+                response.status().as_u16(),
+                response_body,
+                settings,
+            )
+            .await
+        }
+        None => idempotency::release_idempotency_key(&*db, &claimed_key).await,
+    }
+    Ok(response)
+}
+
+/// Whether the response is replayed for later requests with the same idempotency key. Failed
+/// requests are replayed as well, as they may have made changes before failing, except for
+/// requests failing validation before they are processed.
+fn is_idempotent_response_replayed<Q>(result: &RouterResult<ApplicationResponse<Q>>) -> bool {
+    match result {
+        Ok(
+            ApplicationResponse::Json(_)
+            | ApplicationResponse::JsonForRedirection(_)
+            | ApplicationResponse::StatusOk,
+        ) => true,
+        // Pages and files are served again rather than replayed
+        Ok(
+            ApplicationResponse::TextPlain(_)
+            | ApplicationResponse::Form(_)
+            | ApplicationResponse::FileData(_),
+        ) => false,
+        Err(error) => !idempotency::is_request_validation_error(error.current_context()),
+    }
+}
+
+/// Body of the response as it is served, to be stored for the idempotency key of the request
+fn take_response_body(response: HttpResponse) -> (HttpResponse, Option<String>) {
+    use body::MessageBody;
+
+    let (response, response_body) = response.into_parts();
+    match response_body.try_into_bytes() {
+        Ok(bytes) => {
+            let response_body = str::from_utf8(&bytes).ok().map(ToOwned::to_owned);
+            (response.set_body(body::BoxBody::new(bytes)), response_body)
+        }
+        Err(response_body) => (response.set_body(response_body), None),
+    }
 }
 
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
@@ -500,6 +682,28 @@ pub fn http_response_json<T: body::MessageBody + 'static>(response: T) -> HttpRe
         .body(response)
 }
 
+pub fn http_response_idempotent_replay(status_code: u16, response: String) -> HttpResponse {
+    let status_code = actix_web::http::StatusCode::from_u16(status_code)
+        .unwrap_or(actix_web::http::StatusCode::OK);
+    let mut builder = HttpResponse::build(status_code);
+    builder
+        .content_type("application/json")
+        .append_header(("Via", "Juspay_router"))
+        .append_header((headers::IDEMPOTENT_REPLAYED, "true"));
+    // Redirections are replayed with the location they redirected to
+    if status_code == actix_web::http::StatusCode::FOUND {
+        if let Ok(redirection_response) =
+            serde_json::from_str::<api::RedirectionResponse>(&response)
+        {
+            builder.append_header((
+                "Location",
+                redirection_response.return_url_with_query_params,
+            ));
+        }
+    }
+    builder.body(response)
+}
+
 pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok()
         .content_type("text/plain")
@@ -507,6 +711,13 @@ pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpRe
         .body(res)
 }
 
+pub fn http_response_file_data<T: body::MessageBody + 'static>(
+    res: T,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    HttpResponse::Ok().content_type(content_type).body(res)
+}
+
 pub fn http_response_ok() -> HttpResponse {
     HttpResponse::Ok().finish()
 }
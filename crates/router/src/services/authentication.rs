@@ -1,11 +1,19 @@
 use actix_web::http::header::HeaderMap;
-use api_models::{payment_methods::ListPaymentMethodRequest, payments::PaymentsRequest};
+use api_models::{
+    payment_methods::ListPaymentMethodRequest,
+    payments::{PaymentsAuthenticateRequest, PaymentsPreAuthenticateRequest, PaymentsRequest},
+};
 use async_trait::async_trait;
+use common_utils::fp_utils;
 use error_stack::{report, IntoReport, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use router_env::{logger, Flow};
 
 use crate::{
-    core::errors::{self, RouterResult, StorageErrorExt},
+    core::{
+        api_keys,
+        errors::{self, RouterResult, StorageErrorExt},
+    },
     db::StorageInterface,
     routes::{app::AppStateInfo, AppState},
     services::api,
@@ -23,10 +31,39 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<T>;
+
+    /// Authenticate the request with the API key already verified for the request, if any
+    async fn authenticate_and_fetch_verified(
+        &self,
+        request_headers: &HeaderMap,
+        _verified_api_key: Option<api_keys::VerifiedApiKey>,
+        state: &A,
+    ) -> RouterResult<T> {
+        self.authenticate_and_fetch(request_headers, state).await
+    }
+}
+
+/// Details of the authenticated entity, used for request handling common to all endpoints
+pub trait AuthInfo {
+    fn get_merchant_id(&self) -> Option<&str>;
 }
 
+impl AuthInfo for () {
+    fn get_merchant_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl AuthInfo for storage::MerchantAccount {
+    fn get_merchant_id(&self) -> Option<&str> {
+        Some(&self.merchant_id)
+    }
+}
+
+/// Authenticates the merchant by a merchant API key, which must have the scopes required for the
+/// flow
 #[derive(Debug)]
-pub struct ApiKeyAuth;
+pub struct ApiKeyAuth(pub Flow);
 
 #[async_trait]
 impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for ApiKeyAuth {
@@ -34,12 +71,52 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for ApiKeyAuth {
         &self,
         request_headers: &HeaderMap,
         state: &AppState,
+    ) -> RouterResult<storage::MerchantAccount> {
+        self.authenticate_and_fetch_verified(request_headers, None, state)
+            .await
+    }
+
+    async fn authenticate_and_fetch_verified(
+        &self,
+        request_headers: &HeaderMap,
+        verified_api_key: Option<api_keys::VerifiedApiKey>,
+        state: &AppState,
     ) -> RouterResult<storage::MerchantAccount> {
         let api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+        let verified_api_key = match verified_api_key {
+            Some(verified_api_key) => verified_api_key,
+            None => api_keys::resolve_api_key(&*state.store, &state.conf.api_keys, api_key).await?,
+        };
+        let merchant_id = match verified_api_key {
+            api_keys::VerifiedApiKey::Hashed(api_key) => {
+                fp_utils::when(
+                    !api_keys::is_flow_permitted(api_key.scopes.as_deref(), &self.0),
+                    || {
+                        Err(report!(errors::ApiErrorResponse::AccessForbidden))
+                            .attach_printable_lazy(|| {
+                                format!("API key is not permitted to perform {}", self.0)
+                            })
+                    },
+                )?;
+                api_key.merchant_id
+            }
+            api_keys::VerifiedApiKey::Legacy(merchant_account) => {
+                if let Err(error) = api_keys::migrate_legacy_api_key(
+                    &*state.store,
+                    &merchant_account.merchant_id,
+                    api_key,
+                )
+                .await
+                {
+                    logger::error!(legacy_api_key_migration_error=?error);
+                }
+                return Ok(merchant_account);
+            }
+        };
         state
             .store
-            .find_merchant_account_by_api_key(api_key)
+            .find_merchant_account_by_merchant_id(&merchant_id)
             .await
             .change_context(errors::ApiErrorResponse::Unauthorized)
             .attach_printable("Merchant not authenticated")
@@ -166,6 +243,18 @@ impl ClientSecretFetch for ListPaymentMethodRequest {
     }
 }
 
+impl ClientSecretFetch for PaymentsPreAuthenticateRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+}
+
+impl ClientSecretFetch for PaymentsAuthenticateRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+}
+
 pub fn jwt_auth_or<'a, T, A: AppStateInfo>(
     default_auth: &'a dyn AuthenticateAndFetch<T, A>,
     headers: &HeaderMap,
@@ -181,6 +270,7 @@ where
 
 pub fn get_auth_type_and_flow(
     headers: &HeaderMap,
+    flow: Flow,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<storage::MerchantAccount, AppState>>,
     api::AuthFlow,
@@ -190,12 +280,13 @@ pub fn get_auth_type_and_flow(
     if api_key.starts_with("pk_") {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ApiKeyAuth(flow)), api::AuthFlow::Merchant))
 }
 
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
+    flow: Flow,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<storage::MerchantAccount, T>>,
     api::AuthFlow,
@@ -224,18 +315,19 @@ where
         .into());
     }
 
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ApiKeyAuth(flow)), api::AuthFlow::Merchant))
 }
 
 pub async fn is_ephemeral_auth(
     headers: &HeaderMap,
     db: &dyn StorageInterface,
     customer_id: &str,
+    flow: Flow,
 ) -> RouterResult<Box<dyn AuthenticateAndFetch<storage::MerchantAccount, AppState>>> {
     let api_key = get_api_key(headers)?;
 
     if !api_key.starts_with("epk") {
-        return Ok(Box::new(ApiKeyAuth));
+        return Ok(Box::new(ApiKeyAuth(flow)));
     }
 
     let ephemeral_key = db
@@ -266,7 +358,7 @@ where
         .change_context(errors::ApiErrorResponse::InvalidJwtToken)
 }
 
-fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
+pub(crate) fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     headers
         .get("api-key")
         .get_required_value("api-key")?
@@ -0,0 +1,125 @@
+use std::path::{Component, Path, PathBuf};
+
+use error_stack::{IntoReport, ResultExt};
+use tokio::io::AsyncWriteExt;
+
+use crate::{
+    configs::settings::FileStorageConfig,
+    core::errors::{self, CustomResult},
+};
+
+/// Blob storage in which the router persists the files uploaded by merchants
+#[async_trait::async_trait]
+pub trait FileStorageInterface: Send + Sync {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError>;
+
+    /// Append `chunk` to the end of the file, for large files to be written a part at a time
+    async fn append_file(
+        &self,
+        file_key: &str,
+        chunk: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError>;
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::FileStorageError>;
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError>;
+}
+
+pub fn get_file_storage_client(config: &FileStorageConfig) -> Box<dyn FileStorageInterface> {
+    match config {
+        FileStorageConfig::FileSystem { path } => Box::new(FileSystemStorage::new(path)),
+    }
+}
+
+/// File storage backed by a directory on the local filesystem
+#[derive(Debug, Clone)]
+pub struct FileSystemStorage {
+    base_path: PathBuf,
+}
+
+impl FileSystemStorage {
+    pub fn new(base_path: impl Into<PathBuf>) -> Self {
+        Self {
+            base_path: base_path.into(),
+        }
+    }
+
+    fn get_file_path(&self, file_key: &str) -> CustomResult<PathBuf, errors::FileStorageError> {
+        // File keys are generated by the router, but guard against keys escaping the base path
+        let is_valid_key = !file_key.is_empty()
+            && Path::new(file_key)
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_valid_key {
+            Err(errors::FileStorageError::InvalidFileKey).into_report()?
+        }
+        Ok(self.base_path.join(file_key))
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for FileSystemStorage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::UploadFailed)
+                .attach_printable("Failed to create directory in file storage")?;
+        }
+        tokio::fs::write(&file_path, file)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::UploadFailed)
+    }
+
+    async fn append_file(
+        &self,
+        file_key: &str,
+        chunk: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        let mut file = tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(&file_path)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::UploadFailed)
+            .attach_printable("Failed to open file in file storage")?;
+        file.write_all(&chunk)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::UploadFailed)
+    }
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        tokio::fs::read(&file_path)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::RetrieveFailed)
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key)?;
+        tokio::fs::remove_file(&file_path)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::DeleteFailed)
+    }
+}
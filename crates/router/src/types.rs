@@ -26,6 +26,15 @@ pub type PaymentsSyncRouterData = RouterData<api::PSync, PaymentsSyncData, Payme
 pub type PaymentsCaptureRouterData =
     RouterData<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
 pub type PaymentsCancelRouterData = RouterData<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
+pub type PaymentsPreAuthenticateRouterData =
+    RouterData<api::PreAuthenticate, PaymentsPreAuthenticateData, PaymentsResponseData>;
+pub type PaymentsAuthenticateRouterData =
+    RouterData<api::Authenticate, PaymentsAuthenticateData, PaymentsResponseData>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
@@ -45,6 +54,16 @@ pub type PaymentsSessionResponseRouterData<R> =
     ResponseRouterData<api::Session, R, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsCaptureResponseRouterData<R> =
     ResponseRouterData<api::Capture, R, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationResponseRouterData<R> = ResponseRouterData<
+    api::IncrementalAuthorization,
+    R,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
+pub type PaymentsPreAuthenticateResponseRouterData<R> =
+    ResponseRouterData<api::PreAuthenticate, R, PaymentsPreAuthenticateData, PaymentsResponseData>;
+pub type PaymentsAuthenticateResponseRouterData<R> =
+    ResponseRouterData<api::Authenticate, R, PaymentsAuthenticateData, PaymentsResponseData>;
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
@@ -59,6 +78,21 @@ pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationType = dyn services::ConnectorIntegration<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
+pub type PaymentsPreAuthenticateType = dyn services::ConnectorIntegration<
+    api::PreAuthenticate,
+    PaymentsPreAuthenticateData,
+    PaymentsResponseData,
+>;
+pub type PaymentsAuthenticateType = dyn services::ConnectorIntegration<
+    api::Authenticate,
+    PaymentsAuthenticateData,
+    PaymentsResponseData,
+>;
 
 pub type RefundExecuteType =
     dyn services::ConnectorIntegration<api::Execute, RefundsData, RefundsResponseData>;
@@ -68,6 +102,56 @@ pub type RefundSyncType =
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+
+pub type AcceptDisputeRouterData =
+    RouterData<api::Accept, AcceptDisputeRequestData, AcceptDisputeResponse>;
+pub type SubmitEvidenceRouterData =
+    RouterData<api::Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
+
+pub type UploadFileRouterData = RouterData<api::Upload, UploadFileRequestData, UploadFileResponse>;
+
+pub type MandateRevokeRouterData =
+    RouterData<api::MandateRevoke, MandateRevokeRequestData, MandateRevokeResponseData>;
+
+pub type ConnectorCustomerRouterData =
+    RouterData<api::CreateConnectorCustomer, ConnectorCustomerData, ConnectorCustomerResponseData>;
+
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+pub type PayoutReverseType =
+    dyn services::ConnectorIntegration<api::PoReverse, PayoutsData, PayoutsResponseData>;
+
+pub type AcceptDisputeType = dyn services::ConnectorIntegration<
+    api::Accept,
+    AcceptDisputeRequestData,
+    AcceptDisputeResponse,
+>;
+pub type SubmitEvidenceType = dyn services::ConnectorIntegration<
+    api::Evidence,
+    SubmitEvidenceRequestData,
+    SubmitEvidenceResponse,
+>;
+
+pub type UploadFileType =
+    dyn services::ConnectorIntegration<api::Upload, UploadFileRequestData, UploadFileResponse>;
+
+pub type MandateRevokeType = dyn services::ConnectorIntegration<
+    api::MandateRevoke,
+    MandateRevokeRequestData,
+    MandateRevokeResponseData,
+>;
+
+pub type ConnectorCustomerType = dyn services::ConnectorIntegration<
+    api::CreateConnectorCustomer,
+    ConnectorCustomerData,
+    ConnectorCustomerResponseData,
+>;
+
 pub type VerifyRouterData = RouterData<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
 #[derive(Debug, Clone)]
@@ -113,8 +197,14 @@ pub struct PaymentsAuthorizeData {
     pub mandate_id: Option<api_models::payments::MandateIds>,
     pub off_session: Option<bool>,
     pub setup_mandate_details: Option<payments::MandateData>,
+    pub customer_id: Option<String>,
+    /// Customer created at the connector to attach the payment method of a mandate to
+    pub connector_customer: Option<String>,
     pub browser_info: Option<BrowserInformation>,
     pub order_details: Option<api_models::payments::OrderDetails>,
+    /// Outcome of the 3DS authentication of the customer performed by the router, for connectors
+    /// to authorize the payment without authenticating the customer again
+    pub three_ds_authentication: Option<ThreeDsAuthentication>,
 }
 
 #[derive(Debug, Clone)]
@@ -123,6 +213,9 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    pub capture_id: String,
+    /// Whether the uncaptured amount is to be released after this capture
+    pub final_capture: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -139,6 +232,66 @@ pub struct PaymentsCancelData {
     pub cancellation_reason: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct PaymentsIncrementalAuthorizationData {
+    /// The new total amount authorized for the payment
+    pub total_amount: i64,
+    /// The amount by which the authorization is increased
+    pub additional_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector_transaction_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentsPreAuthenticateData {
+    pub payment_method_data: payments::PaymentMethod,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentsAuthenticateData {
+    pub payment_method_data: payments::PaymentMethod,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub browser_info: Option<BrowserInformation>,
+    pub three_ds_server_transaction_id: String,
+    pub message_version: String,
+    pub three_ds_method_completed: bool,
+    /// Challenge response from the access control server, to fetch the result of the challenge
+    pub challenge_response: Option<String>,
+}
+
+/// 3DS authentication of a payment performed through a 3DS server connector, independent of the
+/// connector authorizing the payment
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ThreeDsAuthentication {
+    /// The 3DS server connector authenticating the customer
+    pub connector: String,
+    pub three_ds_server_transaction_id: String,
+    pub message_version: String,
+    pub status: ThreeDsAuthenticationStatus,
+    pub three_ds_method_url: Option<String>,
+    pub three_ds_method_data: Option<String>,
+    pub acs_url: Option<String>,
+    pub challenge_request: Option<String>,
+    /// Cardholder authentication verification value issued by the access control server
+    pub authentication_value: Option<masking::Secret<String>>,
+    pub eci: Option<String>,
+    pub ds_transaction_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDsAuthenticationStatus {
+    /// The 3DS method is to be performed before authenticating the customer
+    Pending,
+    /// The customer is to answer the challenge of the access control server
+    ChallengeRequired,
+    Succeeded,
+    Failed,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsSessionData {
     pub amount: i64,
@@ -156,6 +309,10 @@ pub struct VerifyRequestData {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub setup_mandate_details: Option<payments::MandateData>,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    /// Customer created at the connector to attach the payment method of a mandate to
+    pub connector_customer: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -188,6 +345,9 @@ pub enum PaymentsResponseData {
     SessionResponse {
         session_token: api::SessionToken,
     },
+    ThreeDsAuthenticationResponse {
+        authentication: ThreeDsAuthentication,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -250,6 +410,105 @@ pub struct RefundsResponseData {
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    /// Identifier of the payout at the connector, available once the payout has been fulfilled
+    pub connector_payout_id: Option<String>,
+    pub customer_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub connector_payout_id: String,
+    pub status: storage_enums::PayoutStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct AcceptDisputeResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct SubmitEvidenceRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    pub cancellation_policy_provider_file_id: Option<String>,
+    pub customer_communication_provider_file_id: Option<String>,
+    pub customer_signature_provider_file_id: Option<String>,
+    pub receipt_provider_file_id: Option<String>,
+    pub refund_policy_provider_file_id: Option<String>,
+    pub service_documentation_provider_file_id: Option<String>,
+    pub shipping_documentation_provider_file_id: Option<String>,
+    pub uncategorized_file_provider_file_id: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct SubmitEvidenceResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct UploadFileRequestData {
+    pub file_key: String,
+    pub file: Vec<u8>,
+    pub file_type: mime::Mime,
+    pub file_size: i32,
+}
+
+// The file contents are deliberately left out to keep the router data logs readable
+impl std::fmt::Debug for UploadFileRequestData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadFileRequestData")
+            .field("file_key", &self.file_key)
+            .field("file_type", &self.file_type)
+            .field("file_size", &self.file_size)
+            .finish()
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct UploadFileResponse {
+    pub provider_file_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeRequestData {
+    pub mandate_id: String,
+    pub connector_mandate_id: String,
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MandateRevokeResponseData {
+    pub mandate_status: storage_enums::MandateStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectorCustomerData {
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectorCustomerResponseData {
+    pub connector_customer_id: String,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
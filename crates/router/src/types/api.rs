@@ -2,11 +2,16 @@ pub mod admin;
 pub mod api_keys;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod enums;
+pub mod exports;
+pub mod files;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
+pub mod subscriptions;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -14,7 +19,19 @@ use std::{fmt::Debug, str::FromStr};
 use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
+    admin::*,
+    api_keys::*,
+    configs::*,
+    customers::*,
+    disputes::*,
+    exports::*,
+    files::*,
+    mandates::{ConnectorMandateRevoke, MandateRevoke},
+    payment_methods::*,
+    payments::*,
+    payouts::*,
+    refunds::*,
+    subscriptions::*,
     webhooks::*,
 };
 use super::ErrorResponse;
@@ -89,7 +106,18 @@ pub trait ConnectorCommonExt<Flow, Req, Resp>:
 pub trait Router {}
 
 pub trait Connector:
-    Send + Refund + Payment + Debug + ConnectorRedirectResponse + IncomingWebhook + ConnectorAccessToken
+    Send
+    + Refund
+    + Payment
+    + Payouts
+    + Dispute
+    + FileUpload
+    + ConnectorMandateRevoke
+    + ConnectorCustomer
+    + Debug
+    + ConnectorRedirectResponse
+    + IncomingWebhook
+    + ConnectorAccessToken
 {
 }
 
@@ -100,6 +128,11 @@ pub struct Pe;
 impl<
         T: Refund
             + Payment
+            + Payouts
+            + Dispute
+            + FileUpload
+            + ConnectorMandateRevoke
+            + ConnectorCustomer
             + Debug
             + ConnectorRedirectResponse
             + Send
@@ -170,6 +203,7 @@ impl ConnectorData {
             "fiserv" => Ok(Box::new(&connector::Fiserv)),
             "globalpay" => Ok(Box::new(&connector::Globalpay)),
             "klarna" => Ok(Box::new(&connector::Klarna)),
+            "mock_three_ds" => Ok(Box::new(&connector::MockThreeDs)),
             "payu" => Ok(Box::new(&connector::Payu)),
             "rapyd" => Ok(Box::new(&connector::Rapyd)),
             "shift4" => Ok(Box::new(&connector::Shift4)),
@@ -1,7 +1,9 @@
 pub use api_models::admin::{
+    ConnectorHealthOverrideRequest, ConnectorHealthResponse, ConnectorVolumeSplit,
     CreateMerchantAccount, DeleteMcaResponse, DeleteMerchantAccountResponse,
     MerchantAccountResponse, MerchantConnectorId, MerchantDetails, MerchantId,
-    PaymentConnectorCreate, PaymentMethods, RoutingAlgorithm, ToggleKVRequest, ToggleKVResponse,
+    PaymentConnectorCreate, PaymentMethods, RoutingAlgorithm, RoutingCondition,
+    RoutingDryRunResponse, RoutingRule, RoutingRules, ToggleKVRequest, ToggleKVResponse,
     WebhookDetails,
 };
 
@@ -1,4 +1,4 @@
-#[derive(Clone, serde::Serialize, Debug)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Debug)]
 pub struct Config {
     pub key: String,
     pub value: String,
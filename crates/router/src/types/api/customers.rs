@@ -7,7 +7,8 @@ use crate::{
     core::errors::{self, RouterResult},
     newtype,
     pii::PeekInterface,
-    types::storage,
+    services,
+    types::{self, storage},
     utils::{self, ValidateCall},
 };
 
@@ -16,6 +17,18 @@ newtype!(
     derives = (Debug, Clone, Serialize)
 );
 
+#[derive(Debug, Clone)]
+pub struct CreateConnectorCustomer;
+
+pub trait ConnectorCustomer:
+    services::ConnectorIntegration<
+    CreateConnectorCustomer,
+    types::ConnectorCustomerData,
+    types::ConnectorCustomerResponseData,
+>
+{
+}
+
 pub(crate) trait CustomerRequestExt: Sized {
     fn validate(self) -> RouterResult<Self>;
 }
@@ -0,0 +1,70 @@
+pub use api_models::disputes::{DisputeListConstraints, DisputeResponse, SubmitEvidenceRequest};
+use time::PrimitiveDateTime;
+
+use super::ConnectorCommon;
+use crate::{
+    services::api,
+    types::{
+        self, storage,
+        storage::enums as storage_enums,
+        transformers::{Foreign, ForeignInto},
+    },
+};
+
+/// Dispute details extracted from the connector's incoming webhook
+#[derive(Default, Debug)]
+pub struct DisputePayload {
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+impl From<Foreign<storage::Dispute>> for Foreign<DisputeResponse> {
+    fn from(item: Foreign<storage::Dispute>) -> Self {
+        let dispute = item.0;
+        DisputeResponse {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_stage: dispute.dispute_stage.foreign_into(),
+            dispute_status: dispute.dispute_status.foreign_into(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            connector_created_at: dispute.dispute_created_at,
+            connector_updated_at: dispute.updated_at,
+            created_at: dispute.created_at,
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Accept;
+
+pub trait AcceptDispute:
+    api::ConnectorIntegration<Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct Evidence;
+
+pub trait SubmitEvidence:
+    api::ConnectorIntegration<Evidence, types::SubmitEvidenceRequestData, types::SubmitEvidenceResponse>
+{
+}
+
+pub trait Dispute: ConnectorCommon + AcceptDispute + SubmitEvidence {}
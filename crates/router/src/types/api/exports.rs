@@ -0,0 +1 @@
+pub use api_models::exports::{ExportRequest, ExportResponse};
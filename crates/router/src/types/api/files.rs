@@ -0,0 +1,38 @@
+pub use api_models::{enums::FilePurpose, files::CreateFileResponse};
+
+use super::ConnectorCommon;
+use crate::{
+    core::errors::{self, CustomResult},
+    services::api,
+    types,
+};
+
+#[derive(Debug, Clone)]
+pub struct Upload;
+
+pub trait UploadFile:
+    api::ConnectorIntegration<Upload, types::UploadFileRequestData, types::UploadFileResponse>
+{
+}
+
+pub trait FileUpload: ConnectorCommon + Sync + UploadFile {
+    /// Validate the file against the connector's constraints before it is uploaded to the connector
+    fn validate_file_upload(
+        &self,
+        _purpose: FilePurpose,
+        _file_size: i32,
+        _file_type: mime::Mime,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented("File Upload".to_owned()).into())
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateFileRequest {
+    pub file: Vec<u8>,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: mime::Mime,
+    pub purpose: FilePurpose,
+    pub dispute_id: Option<String>,
+}
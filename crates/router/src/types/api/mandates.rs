@@ -10,8 +10,9 @@ use crate::{
     },
     newtype,
     routes::AppState,
+    services,
     types::{
-        api,
+        self, api,
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
@@ -104,3 +105,15 @@ impl From<api::payment_methods::CardDetailFromLocker> for MandateCardDetails {
         .into()
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct MandateRevoke;
+
+pub trait ConnectorMandateRevoke:
+    services::ConnectorIntegration<
+    MandateRevoke,
+    types::MandateRevokeRequestData,
+    types::MandateRevokeResponseData,
+>
+{
+}
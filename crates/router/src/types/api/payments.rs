@@ -1,13 +1,15 @@
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListResponse, PaymentMethod, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
-    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
-    PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
-    PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
-    VerifyResponse, WalletData,
+    CustomerAcceptance, MandateAmountData, MandateData, MandateTxnType, MandateType,
+    MandateValidationFields, NextAction, NextActionType, OnlineMandate, PayLaterData,
+    PaymentAttemptResponse, PaymentIdType, PaymentListConstraints, PaymentListResponse,
+    PaymentMethod, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
+    PaymentsAuthenticateRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
+    PaymentsIncrementalAuthorizationRequest, PaymentsPreAuthenticateRequest,
+    PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse,
+    PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse,
+    PaymentsStartRequest, PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken,
+    ThreeDsData, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -72,6 +74,15 @@ pub struct PSync;
 #[derive(Debug, Clone)]
 pub struct Void;
 
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorization;
+
+#[derive(Debug, Clone)]
+pub struct PreAuthenticate;
+
+#[derive(Debug, Clone)]
+pub struct Authenticate;
+
 #[derive(Debug, Clone)]
 pub struct Session;
 
@@ -132,6 +143,22 @@ impl From<Foreign<storage::PaymentIntent>> for Foreign<PaymentsResponse> {
     }
 }
 
+impl From<Foreign<storage::PaymentAttempt>> for Foreign<PaymentAttemptResponse> {
+    fn from(item: Foreign<storage::PaymentAttempt>) -> Self {
+        let item = item.0;
+        PaymentAttemptResponse {
+            attempt_id: item.attempt_id,
+            status: item.status.foreign_into(),
+            amount: item.amount,
+            connector: item.connector,
+            error_code: item.error_code,
+            error_message: item.error_message,
+            created_at: item.created_at,
+        }
+        .into()
+    }
+}
+
 // Extract only the last 4 digits of card
 
 pub trait PaymentAuthorize:
@@ -154,6 +181,33 @@ pub trait PaymentCapture:
 {
 }
 
+pub trait PaymentIncrementalAuthorization:
+    api::ConnectorIntegration<
+    IncrementalAuthorization,
+    types::PaymentsIncrementalAuthorizationData,
+    types::PaymentsResponseData,
+>
+{
+}
+
+pub trait PaymentPreAuthenticate:
+    api::ConnectorIntegration<
+    PreAuthenticate,
+    types::PaymentsPreAuthenticateData,
+    types::PaymentsResponseData,
+>
+{
+}
+
+pub trait PaymentAuthenticate:
+    api::ConnectorIntegration<
+    Authenticate,
+    types::PaymentsAuthenticateData,
+    types::PaymentsResponseData,
+>
+{
+}
+
 pub trait PaymentSession:
     api::ConnectorIntegration<Session, types::PaymentsSessionData, types::PaymentsResponseData>
 {
@@ -170,6 +224,9 @@ pub trait Payment:
     + PaymentSync
     + PaymentCapture
     + PaymentVoid
+    + PaymentIncrementalAuthorization
+    + PaymentPreAuthenticate
+    + PaymentAuthenticate
     + PreVerify
     + PaymentSession
 {
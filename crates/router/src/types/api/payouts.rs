@@ -0,0 +1,62 @@
+pub use api_models::payouts::{
+    PayoutAccount, PayoutAccountsListRequest, PayoutAccountsListResponse, PayoutBank, PayoutCard,
+    PayoutCreateRequest, PayoutListRequest, PayoutListResponse, PayoutMethodData, PayoutResponse,
+    PayoutUpdateRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{
+    services::api,
+    types::{
+        self, storage,
+        transformers::{Foreign, ForeignInto},
+    },
+};
+
+impl From<Foreign<storage::Payouts>> for Foreign<PayoutResponse> {
+    fn from(item: Foreign<storage::Payouts>) -> Self {
+        let payout = item.0;
+        PayoutResponse {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            amount: payout.amount,
+            currency: payout.currency.foreign_into(),
+            connector: payout.connector,
+            connector_payout_id: payout.connector_payout_id,
+            payout_type: payout.payout_type.foreign_into(),
+            status: payout.status.foreign_into(),
+            description: payout.description,
+            metadata: payout.metadata,
+            error_message: payout.error_message,
+            error_code: payout.error_code,
+            payout_account: payout.payout_account,
+            created_at: Some(payout.created_at),
+        }
+        .into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+#[derive(Debug, Clone)]
+pub struct PoReverse;
+
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutCancel:
+    api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutReverse:
+    api::ConnectorIntegration<PoReverse, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait Payouts: ConnectorCommon + PayoutFulfill + PayoutCancel + PayoutReverse {}
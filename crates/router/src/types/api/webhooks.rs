@@ -1,8 +1,9 @@
 pub use api_models::webhooks::{
-    IncomingWebhookDetails, IncomingWebhookEvent, MerchantWebhookConfig, OutgoingWebhook,
-    OutgoingWebhookContent, WebhookFlow,
+    sign_outgoing_webhook, verify_outgoing_webhook_signature, IncomingWebhookDetails,
+    IncomingWebhookEvent, MerchantWebhookConfig, OutgoingWebhook, OutgoingWebhookContent,
+    WebhookFlow, OUTGOING_WEBHOOK_SIGNATURE_HEADER, OUTGOING_WEBHOOK_TIMESTAMP_HEADER,
 };
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 
 use super::ConnectorCommon;
 use crate::{
@@ -142,4 +143,14 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
     {
         Ok(services::api::ApplicationResponse::StatusOk)
     }
+
+    fn get_dispute_details(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<super::disputes::DisputePayload, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented(
+            "get_dispute_details method".to_string(),
+        ))
+        .into_report()
+    }
 }
@@ -0,0 +1,3 @@
+pub use storage_models::idempotency_key::{
+    IdempotencyKey, IdempotencyKeyNew, IdempotencyKeyUpdate, IdempotencyKeyUpdateInternal,
+};
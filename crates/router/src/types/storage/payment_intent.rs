@@ -1,7 +1,14 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{
+    associations::HasTable,
+    dsl::sql,
+    pg::Pg,
+    sql_types::{Bool, Text},
+    ExpressionMethods, QueryDsl,
+};
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
+use storage_models::schema::payment_attempt::dsl as attempt_dsl;
 pub use storage_models::{
     errors,
     payment_intent::{
@@ -10,11 +17,17 @@ pub use storage_models::{
     schema::payment_intent::dsl,
 };
 
-use crate::{connection::PgPooledConn, core::errors::CustomResult, types::api};
+use crate::{
+    connection::PgPooledConn,
+    core::errors::CustomResult,
+    types::{api, storage::enums as storage_enums, transformers::ForeignFrom},
+};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for PaymentIntent {}
 
+type BoxedPaymentIntentQuery = storage_models::schema::payment_intent::BoxedQuery<'static, Pg>;
+
 #[async_trait::async_trait]
 pub trait PaymentIntentDbExt: Sized {
     async fn filter_by_constraints(
@@ -22,6 +35,104 @@ pub trait PaymentIntentDbExt: Sized {
         merchant_id: &str,
         pc: &api::PaymentListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn count_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        pc: &api::PaymentListConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError>;
+}
+
+/// The payment intents of the merchant satisfying the filters of the constraints, irrespective
+/// of the pagination
+fn filter_payment_intents(
+    merchant_id: &str,
+    pc: &api::PaymentListConstraints,
+) -> BoxedPaymentIntentQuery {
+    //[#350]: Replace this with Boxable Expression and pass it into generic filter
+    // when https://github.com/rust-lang/rust/issues/52662 becomes stable
+    let mut filter = <PaymentIntent as HasTable>::table()
+        .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+        .into_boxed();
+
+    if let Some(customer_id) = &pc.customer_id {
+        filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+    }
+    if let Some(created) = pc.created {
+        filter = filter.filter(dsl::created_at.eq(created));
+    }
+    if let Some(created_lt) = pc.created_lt {
+        filter = filter.filter(dsl::created_at.lt(created_lt));
+    }
+    if let Some(created_gt) = pc.created_gt {
+        filter = filter.filter(dsl::created_at.gt(created_gt));
+    }
+    if let Some(created_lte) = pc.created_lte {
+        filter = filter.filter(dsl::created_at.le(created_lte));
+    }
+    if let Some(created_gte) = pc.created_gte {
+        filter = filter.filter(dsl::created_at.ge(created_gte));
+    }
+    if let Some(status) = pc.status {
+        filter = filter.filter(dsl::status.eq(storage_enums::IntentStatus::foreign_from(status)));
+    }
+    if let Some(currency) = pc.currency {
+        filter = filter.filter(dsl::currency.eq(storage_enums::Currency::foreign_from(currency)));
+    }
+    if let Some(amount_gte) = pc.amount_gte {
+        filter = filter.filter(dsl::amount.ge(amount_gte));
+    }
+    if let Some(amount_lte) = pc.amount_lte {
+        filter = filter.filter(dsl::amount.le(amount_lte));
+    }
+    if let Some(metadata_key) = &pc.metadata_key {
+        filter = filter.filter(sql::<Bool>("metadata ? ").bind::<Text, _>(metadata_key.to_owned()));
+    }
+    // The connector and the payment method are those of the attempts of the payment, so a
+    // payment retried on another connector matches either connector
+    if let Some(connector) = pc.connector {
+        filter = filter.filter(
+            dsl::payment_id.eq_any(
+                attempt_dsl::payment_attempt
+                    .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .filter(attempt_dsl::connector.eq(connector.to_string()))
+                    .select(attempt_dsl::payment_id),
+            ),
+        );
+    }
+    if let Some(payment_method) = pc.payment_method {
+        filter = filter.filter(
+            dsl::payment_id.eq_any(
+                attempt_dsl::payment_attempt
+                    .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .filter(attempt_dsl::payment_method.eq(
+                        storage_enums::PaymentMethodType::foreign_from(payment_method),
+                    ))
+                    .select(attempt_dsl::payment_id),
+            ),
+        );
+    }
+
+    filter
+}
+
+/// The page of the payment intents, listed from the newest to the oldest, the cursors paginating
+/// towards the older payments after `starting_after` or the newer payments before `ending_before`
+///
+/// The payments before `ending_before` are selected from the oldest, so that the page is the one
+/// adjacent to the cursor, and are to be reversed by the caller.
+fn paginate_payment_intents(
+    filter: BoxedPaymentIntentQuery,
+    starting_after: Option<i32>,
+    ending_before: Option<i32>,
+    limit: i64,
+) -> BoxedPaymentIntentQuery {
+    match (starting_after, ending_before) {
+        (Some(id), _) => filter.filter(dsl::id.lt(id)).order(dsl::id.desc()),
+        (None, Some(id)) => filter.filter(dsl::id.gt(id)).order(dsl::id.asc()),
+        (None, None) => filter.order(dsl::id.desc()),
+    }
+    .limit(limit)
 }
 
 #[async_trait::async_trait]
@@ -32,57 +143,132 @@ impl PaymentIntentDbExt for PaymentIntent {
         merchant_id: &str,
         pc: &api::PaymentListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
-        let customer_id = &pc.customer_id;
-        let starting_after = &pc.starting_after;
-        let ending_before = &pc.ending_before;
-
-        //[#350]: Replace this with Boxable Expression and pass it into generic filter
-        // when https://github.com/rust-lang/rust/issues/52662 becomes stable
-        let mut filter = <Self as HasTable>::table()
-            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
-            .into_boxed();
-
-        if let Some(customer_id) = customer_id {
-            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
-        }
-        if let Some(created) = pc.created {
-            filter = filter.filter(dsl::created_at.eq(created));
-        }
-        if let Some(created_lt) = pc.created_lt {
-            filter = filter.filter(dsl::created_at.lt(created_lt));
-        }
-        if let Some(created_gt) = pc.created_gt {
-            filter = filter.filter(dsl::created_at.gt(created_gt));
-        }
-        if let Some(created_lte) = pc.created_lte {
-            filter = filter.filter(dsl::created_at.le(created_lte));
-        }
-        if let Some(created_gte) = pc.created_gte {
-            filter = filter.filter(dsl::created_at.gt(created_gte));
-        }
-        if let Some(starting_after) = starting_after {
-            let id = Self::find_by_payment_id_merchant_id(conn, starting_after, merchant_id)
-                .await?
-                .id;
-            filter = filter.filter(dsl::id.gt(id));
-        }
-        if let Some(ending_before) = ending_before {
-            let id = Self::find_by_payment_id_merchant_id(conn, ending_before, merchant_id)
-                .await?
-                .id;
-            filter = filter.filter(dsl::id.lt(id.to_owned()));
-        }
+        let filter = filter_payment_intents(merchant_id, pc);
 
-        filter = filter.limit(pc.limit);
+        let starting_after = match &pc.starting_after {
+            Some(starting_after) => Some(
+                Self::find_by_payment_id_merchant_id(conn, starting_after, merchant_id)
+                    .await?
+                    .id,
+            ),
+            None => None,
+        };
+        let ending_before = match (&starting_after, &pc.ending_before) {
+            (None, Some(ending_before)) => Some(
+                Self::find_by_payment_id_merchant_id(conn, ending_before, merchant_id)
+                    .await?
+                    .id,
+            ),
+            _ => None,
+        };
+        let is_paginating_backwards = ending_before.is_some();
+        let filter = paginate_payment_intents(filter, starting_after, ending_before, pc.limit);
 
         crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
-        filter
+        let mut payment_intents: Vec<Self> = filter
             .get_results_async(conn)
             .await
             .into_report()
             .change_context(errors::DatabaseError::NotFound)
-            .attach_printable_lazy(|| "Error filtering records by predicate")
+            .attach_printable_lazy(|| "Error filtering records by predicate")?;
+        if is_paginating_backwards {
+            payment_intents.reverse();
+        }
+        Ok(payment_intents)
+    }
+
+    #[instrument(skip(conn))]
+    async fn count_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        pc: &api::PaymentListConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        filter_payment_intents(merchant_id, pc)
+            .count()
+            .get_result_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| "Error counting records by predicate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_constraints(constraints: serde_json::Value) -> api::PaymentListConstraints {
+        serde_json::from_value(constraints).unwrap()
+    }
+
+    fn get_query(query: &BoxedPaymentIntentQuery) -> String {
+        diesel::debug_query::<Pg, _>(query).to_string()
+    }
+
+    #[test]
+    fn test_filter_payment_intents() {
+        let constraints = get_constraints(serde_json::json!({
+            "status": "succeeded",
+            "currency": "USD",
+            "amount.gte": 100,
+            "amount.lte": 200,
+            "metadata_key": "order_id",
+        }));
+        let query = get_query(&filter_payment_intents("merchant", &constraints));
+
+        assert!(query.contains(r#""payment_intent"."merchant_id" = $1"#));
+        assert!(query.contains(r#""payment_intent"."status" = $2"#));
+        assert!(query.contains(r#""payment_intent"."currency" = $3"#));
+        assert!(query.contains(r#""payment_intent"."amount" >= $4"#));
+        assert!(query.contains(r#""payment_intent"."amount" <= $5"#));
+        assert!(query.contains("metadata ? $6"));
+        assert!(!query.contains(r#""payment_attempt""#));
+    }
+
+    #[test]
+    fn test_filter_payment_intents_by_attempts() {
+        let constraints = get_constraints(serde_json::json!({
+            "connector": "stripe",
+            "payment_method": "card",
+        }));
+        let query = get_query(&filter_payment_intents("merchant", &constraints));
+
+        assert!(query.contains(r#""payment_attempt"."connector" = $"#));
+        assert!(query.contains(r#""payment_attempt"."payment_method" = $"#));
+    }
+
+    #[test]
+    fn test_paginate_payment_intents() {
+        let constraints = get_constraints(serde_json::json!({}));
+
+        // The first page holds the newest payments
+        let query = get_query(&paginate_payment_intents(
+            filter_payment_intents("merchant", &constraints),
+            None,
+            None,
+            10,
+        ));
+        assert!(query.contains(r#"ORDER BY "payment_intent"."id" DESC"#));
+        assert!(!query.contains(r#""payment_intent"."id" <"#));
+
+        let query = get_query(&paginate_payment_intents(
+            filter_payment_intents("merchant", &constraints),
+            Some(20),
+            None,
+            10,
+        ));
+        assert!(query.contains(r#""payment_intent"."id" < $2"#));
+        assert!(query.contains(r#"ORDER BY "payment_intent"."id" DESC"#));
+
+        let query = get_query(&paginate_payment_intents(
+            filter_payment_intents("merchant", &constraints),
+            None,
+            Some(20),
+            10,
+        ));
+        assert!(query.contains(r#""payment_intent"."id" > $2"#));
+        assert!(query.contains(r#"ORDER BY "payment_intent"."id" ASC"#));
     }
 }
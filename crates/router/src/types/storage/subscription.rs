@@ -0,0 +1,3 @@
+pub use storage_models::subscription::{
+    Subscription, SubscriptionNew, SubscriptionUpdate, SubscriptionUpdateInternal,
+};
@@ -128,6 +128,18 @@ impl From<F<storage_enums::MandateStatus>> for F<api_enums::MandateStatus> {
     }
 }
 
+impl From<F<api_enums::MandateFrequencyPeriod>> for F<storage_enums::MandateFrequencyPeriod> {
+    fn from(period: F<api_enums::MandateFrequencyPeriod>) -> Self {
+        Self(frunk::labelled_convert_from(period.0))
+    }
+}
+
+impl From<F<storage_enums::MandateFrequencyPeriod>> for F<api_enums::MandateFrequencyPeriod> {
+    fn from(period: F<storage_enums::MandateFrequencyPeriod>) -> Self {
+        Self(frunk::labelled_convert_from(period.0))
+    }
+}
+
 impl From<F<api_enums::PaymentMethodType>> for F<storage_enums::PaymentMethodType> {
     fn from(pm_type: F<api_enums::PaymentMethodType>) -> Self {
         Self(frunk::labelled_convert_from(pm_type.0))
@@ -218,6 +230,9 @@ impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     fn try_from(value: F<api_enums::IntentStatus>) -> Result<Self, Self::Error> {
         match value.0 {
             api_enums::IntentStatus::Succeeded => Ok(storage_enums::EventType::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(storage_enums::EventType::PaymentFailed),
+            api_enums::IntentStatus::Processing => Ok(storage_enums::EventType::PaymentProcessing),
+            api_enums::IntentStatus::Cancelled => Ok(storage_enums::EventType::PaymentCancelled),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -226,6 +241,24 @@ impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     }
 }
 
+impl TryFrom<F<storage_enums::RefundStatus>> for F<storage_enums::EventType> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<storage_enums::RefundStatus>) -> Result<Self, Self::Error> {
+        match value.0 {
+            storage_enums::RefundStatus::Success => Ok(storage_enums::EventType::RefundSucceeded),
+            storage_enums::RefundStatus::Failure
+            | storage_enums::RefundStatus::TransactionFailure => {
+                Ok(storage_enums::EventType::RefundFailed)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "refund_status",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
 impl From<F<storage_enums::EventType>> for F<api_enums::EventType> {
     fn from(event_type: F<storage_enums::EventType>) -> Self {
         Self(frunk::labelled_convert_from(event_type.0))
@@ -238,6 +271,12 @@ impl From<F<api_enums::FutureUsage>> for F<storage_enums::FutureUsage> {
     }
 }
 
+impl From<F<storage_enums::AttemptStatus>> for F<api_enums::AttemptStatus> {
+    fn from(status: F<storage_enums::AttemptStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
 impl From<F<storage_enums::FutureUsage>> for F<api_enums::FutureUsage> {
     fn from(future_usage: F<storage_enums::FutureUsage>) -> Self {
         Self(frunk::labelled_convert_from(future_usage.0))
@@ -285,6 +324,200 @@ impl From<F<storage_enums::Currency>> for F<api_enums::Currency> {
     }
 }
 
+impl From<F<api_enums::PayoutType>> for F<storage_enums::PayoutType> {
+    fn from(payout_type: F<api_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::PayoutType>> for F<api_enums::PayoutType> {
+    fn from(payout_type: F<storage_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::PayoutStatus>> for F<api_enums::PayoutStatus> {
+    fn from(status: F<storage_enums::PayoutStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::SubscriptionStatus>> for F<api_enums::SubscriptionStatus> {
+    fn from(status: F<storage_enums::SubscriptionStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::SubscriptionInterval>> for F<storage_enums::SubscriptionInterval> {
+    fn from(interval: F<api_enums::SubscriptionInterval>) -> Self {
+        Self(frunk::labelled_convert_from(interval.0))
+    }
+}
+
+impl From<F<storage_enums::SubscriptionInterval>> for F<api_enums::SubscriptionInterval> {
+    fn from(interval: F<storage_enums::SubscriptionInterval>) -> Self {
+        Self(frunk::labelled_convert_from(interval.0))
+    }
+}
+
+impl From<F<api_enums::DisputeStage>> for F<storage_enums::DisputeStage> {
+    fn from(dispute_stage: F<api_enums::DisputeStage>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_stage.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStage>> for F<api_enums::DisputeStage> {
+    fn from(dispute_stage: F<storage_enums::DisputeStage>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_stage.0))
+    }
+}
+
+impl From<F<api_enums::DisputeStatus>> for F<storage_enums::DisputeStatus> {
+    fn from(dispute_status: F<api_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_status.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<api_enums::DisputeStatus> {
+    fn from(dispute_status: F<storage_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_status.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<storage_enums::EventType> {
+    fn from(value: F<storage_enums::DisputeStatus>) -> Self {
+        match value.0 {
+            storage_enums::DisputeStatus::DisputeOpened => storage_enums::EventType::DisputeOpened,
+            storage_enums::DisputeStatus::DisputeExpired => {
+                storage_enums::EventType::DisputeExpired
+            }
+            storage_enums::DisputeStatus::DisputeAccepted => {
+                storage_enums::EventType::DisputeAccepted
+            }
+            storage_enums::DisputeStatus::DisputeCancelled => {
+                storage_enums::EventType::DisputeCancelled
+            }
+            storage_enums::DisputeStatus::DisputeChallenged => {
+                storage_enums::EventType::DisputeChallenged
+            }
+            storage_enums::DisputeStatus::DisputeWon => storage_enums::EventType::DisputeWon,
+            storage_enums::DisputeStatus::DisputeLost => storage_enums::EventType::DisputeLost,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage_enums::EventType>> for F<api_models::webhooks::IncomingWebhookEvent> {
+    fn from(value: F<storage_enums::EventType>) -> Self {
+        use api_models::webhooks::IncomingWebhookEvent;
+
+        match value.0 {
+            storage_enums::EventType::PaymentSucceeded => {
+                IncomingWebhookEvent::PaymentIntentSuccess
+            }
+            storage_enums::EventType::PaymentFailed => IncomingWebhookEvent::PaymentIntentFailure,
+            storage_enums::EventType::PaymentProcessing => {
+                IncomingWebhookEvent::PaymentIntentProcessing
+            }
+            storage_enums::EventType::PaymentCancelled => {
+                IncomingWebhookEvent::PaymentIntentCancelled
+            }
+            storage_enums::EventType::PaymentCaptured => {
+                IncomingWebhookEvent::PaymentIntentCaptured
+            }
+            storage_enums::EventType::RefundSucceeded => IncomingWebhookEvent::RefundSuccess,
+            storage_enums::EventType::RefundFailed => IncomingWebhookEvent::RefundFailure,
+            storage_enums::EventType::DisputeOpened => IncomingWebhookEvent::DisputeOpened,
+            storage_enums::EventType::DisputeExpired => IncomingWebhookEvent::DisputeExpired,
+            storage_enums::EventType::DisputeAccepted => IncomingWebhookEvent::DisputeAccepted,
+            storage_enums::EventType::DisputeCancelled => IncomingWebhookEvent::DisputeCancelled,
+            storage_enums::EventType::DisputeChallenged => IncomingWebhookEvent::DisputeChallenged,
+            storage_enums::EventType::DisputeWon => IncomingWebhookEvent::DisputeWon,
+            storage_enums::EventType::DisputeLost => IncomingWebhookEvent::DisputeLost,
+            storage_enums::EventType::MandateRevoked => IncomingWebhookEvent::MandateRevoked,
+        }
+        .into()
+    }
+}
+
+impl TryFrom<F<api_models::webhooks::IncomingWebhookEvent>> for F<storage_enums::DisputeStatus> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<api_models::webhooks::IncomingWebhookEvent>) -> Result<Self, Self::Error> {
+        match value.0 {
+            api_models::webhooks::IncomingWebhookEvent::DisputeOpened => {
+                Ok(storage_enums::DisputeStatus::DisputeOpened)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeExpired => {
+                Ok(storage_enums::DisputeStatus::DisputeExpired)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeAccepted => {
+                Ok(storage_enums::DisputeStatus::DisputeAccepted)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeCancelled => {
+                Ok(storage_enums::DisputeStatus::DisputeCancelled)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeChallenged => {
+                Ok(storage_enums::DisputeStatus::DisputeChallenged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeWon => {
+                Ok(storage_enums::DisputeStatus::DisputeWon)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeLost => {
+                Ok(storage_enums::DisputeStatus::DisputeLost)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
+impl TryFrom<F<api_models::webhooks::IncomingWebhookEvent>> for F<storage_enums::RefundStatus> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<api_models::webhooks::IncomingWebhookEvent>) -> Result<Self, Self::Error> {
+        match value.0 {
+            api_models::webhooks::IncomingWebhookEvent::RefundSuccess => {
+                Ok(storage_enums::RefundStatus::Success)
+            }
+            api_models::webhooks::IncomingWebhookEvent::RefundFailure => {
+                Ok(storage_enums::RefundStatus::Failure)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
+impl TryFrom<F<api_models::webhooks::IncomingWebhookEvent>> for F<storage_enums::PayoutStatus> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<api_models::webhooks::IncomingWebhookEvent>) -> Result<Self, Self::Error> {
+        match value.0 {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => {
+                Ok(storage_enums::PayoutStatus::Success)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => {
+                Ok(storage_enums::PayoutStatus::Failed)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => {
+                Ok(storage_enums::PayoutStatus::Cancelled)
+            }
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => {
+                Ok(storage_enums::PayoutStatus::Reversed)
+            }
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
 impl<'a> From<F<&'a api_types::Address>> for F<storage::AddressUpdate> {
     fn from(address: F<&api_types::Address>) -> Self {
         let address = address.0;
@@ -316,6 +549,17 @@ impl From<F<storage::Config>> for F<api_types::Config> {
     }
 }
 
+impl From<F<api_types::Config>> for F<storage::ConfigNew> {
+    fn from(config: F<api_types::Config>) -> Self {
+        let config = config.0;
+        storage::ConfigNew {
+            key: config.key,
+            config: config.value,
+        }
+        .into()
+    }
+}
+
 impl<'a> From<F<&'a api_types::ConfigUpdate>> for F<storage::ConfigUpdate> {
     fn from(config: F<&api_types::ConfigUpdate>) -> Self {
         let config_update = config.0;
@@ -428,6 +672,7 @@ impl
             )),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes.map(crate::core::api_keys::parse_scopes),
         }
         .into()
     }
@@ -444,6 +689,7 @@ impl From<F<storage_models::api_keys::ApiKey>> for F<api_models::api_keys::Retri
             prefix: format!("{}-{}", api_key.key_id, api_key.prefix).into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key.scopes.map(crate::core::api_keys::parse_scopes),
         }
         .into()
     }
@@ -459,6 +705,10 @@ impl From<F<api_models::api_keys::UpdateApiKeyRequest>>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            // An empty list of scopes resets the API key to access all resources
+            scopes: api_key.scopes.map(|scopes| {
+                (!scopes.is_empty()).then(|| scopes.iter().map(ToString::to_string).collect())
+            }),
         }
         .into()
     }
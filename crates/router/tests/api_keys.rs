@@ -0,0 +1,130 @@
+#![allow(clippy::expect_used, clippy::panic, clippy::unwrap_used)]
+
+use actix_web::http::header::{HeaderMap, HeaderValue};
+use masking::PeekInterface;
+use router::{
+    configs::settings::Settings,
+    core::{admin, api_keys, errors},
+    db::StorageImpl,
+    routes::AppState,
+    services::{
+        authentication::{ApiKeyAuth, AuthenticateAndFetch},
+        ApplicationResponse,
+    },
+    types::api,
+};
+use router_env::Flow;
+
+async fn authenticate(state: &AppState, api_key: &str) -> Result<String, errors::ApiErrorResponse> {
+    let mut headers = HeaderMap::new();
+    headers.insert("api-key", HeaderValue::from_str(api_key).unwrap());
+    ApiKeyAuth(Flow::PaymentsRetrieve)
+        .authenticate_and_fetch(&headers, state)
+        .await
+        .map(|merchant_account| merchant_account.merchant_id)
+        .map_err(|error| error.current_context().clone())
+}
+
+#[actix_rt::test]
+async fn test_api_key_scopes_are_reset_by_empty_scopes() {
+    let conf = Settings::new().expect("invalid settings");
+    let state = AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+
+    let merchant_id = format!("merchant_{}", uuid::Uuid::new_v4().simple());
+    let request: api::CreateMerchantAccount =
+        serde_json::from_value(serde_json::json!({ "merchant_id": merchant_id })).unwrap();
+    admin::create_merchant_account(&*state.store, request)
+        .await
+        .unwrap();
+
+    let request: api::CreateApiKeyRequest = serde_json::from_value(serde_json::json!({
+        "name": "Refunds key",
+        "expiration": "never",
+        "scopes": ["refunds:read"]
+    }))
+    .unwrap();
+    let api_key = match api_keys::create_api_key(&*state.store, request, merchant_id.clone())
+        .await
+        .unwrap()
+    {
+        ApplicationResponse::Json(api_key) => api_key,
+        _ => panic!("Unexpected response"),
+    };
+    let plaintext_api_key = api_key.api_key.peek().to_owned();
+    assert!(matches!(
+        authenticate(&state, &plaintext_api_key).await,
+        Err(errors::ApiErrorResponse::AccessForbidden)
+    ));
+
+    // An empty list of scopes resets the API key to access all resources
+    let request: api::UpdateApiKeyRequest =
+        serde_json::from_value(serde_json::json!({ "scopes": [] })).unwrap();
+    let updated_api_key = match api_keys::update_api_key(&*state.store, &api_key.key_id, request)
+        .await
+        .unwrap()
+    {
+        ApplicationResponse::Json(api_key) => api_key,
+        _ => panic!("Unexpected response"),
+    };
+    assert!(updated_api_key.scopes.is_none());
+    assert_eq!(
+        authenticate(&state, &plaintext_api_key).await.unwrap(),
+        merchant_id
+    );
+}
+
+#[actix_rt::test]
+async fn test_revoked_legacy_api_key_is_not_migrated_again() {
+    let conf = Settings::new().expect("invalid settings");
+    let state = AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+
+    let merchant_id = format!("merchant_{}", uuid::Uuid::new_v4().simple());
+    let request: api::CreateMerchantAccount =
+        serde_json::from_value(serde_json::json!({ "merchant_id": merchant_id })).unwrap();
+    let merchant_account = match admin::create_merchant_account(&*state.store, request)
+        .await
+        .unwrap()
+    {
+        ApplicationResponse::Json(merchant_account) => merchant_account,
+        _ => panic!("Unexpected response"),
+    };
+    let legacy_api_key = merchant_account.api_key.unwrap().peek().to_owned();
+
+    // The legacy API key is migrated to a hashed API key on its first use
+    assert_eq!(
+        authenticate(&state, &legacy_api_key).await.unwrap(),
+        merchant_id
+    );
+    let migrated_api_keys =
+        match api_keys::list_api_keys(&*state.store, merchant_id.clone(), None, None)
+            .await
+            .unwrap()
+        {
+            ApplicationResponse::Json(api_keys) => api_keys,
+            _ => panic!("Unexpected response"),
+        };
+    assert_eq!(migrated_api_keys.len(), 1);
+    assert_eq!(
+        authenticate(&state, &legacy_api_key).await.unwrap(),
+        merchant_id
+    );
+
+    api_keys::revoke_api_key(&*state.store, &migrated_api_keys[0].key_id)
+        .await
+        .unwrap();
+
+    // The legacy API key is cleared once migrated, so the revoked API key cannot be
+    // authenticated as a legacy API key and migrated again
+    assert!(matches!(
+        authenticate(&state, &legacy_api_key).await,
+        Err(errors::ApiErrorResponse::Unauthorized)
+    ));
+    let api_keys = match api_keys::list_api_keys(&*state.store, merchant_id, None, None)
+        .await
+        .unwrap()
+    {
+        ApplicationResponse::Json(api_keys) => api_keys,
+        _ => panic!("Unexpected response"),
+    };
+    assert!(api_keys.is_empty());
+}
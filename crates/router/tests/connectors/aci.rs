@@ -46,10 +46,13 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             mandate_id: None,
             off_session: None,
             setup_mandate_details: None,
+            customer_id: None,
+            connector_customer: None,
             capture_method: None,
             browser_info: None,
             order_details: None,
             email: None,
+            three_ds_authentication: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -383,7 +383,8 @@ pub trait ConnectorActions: Connector {
             Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
                 resource_id.get_connector_transaction_id().ok()
             }
-            Ok(types::PaymentsResponseData::SessionResponse { .. }) => None,
+            Ok(types::PaymentsResponseData::SessionResponse { .. })
+            | Ok(types::PaymentsResponseData::ThreeDsAuthenticationResponse { .. }) => None,
             Err(_) => None,
         }
     }
@@ -466,9 +467,12 @@ impl Default for PaymentAuthorizeType {
             mandate_id: None,
             off_session: None,
             setup_mandate_details: None,
+            customer_id: None,
+            connector_customer: None,
             browser_info: Some(BrowserInfoType::default().0),
             order_details: None,
             email: None,
+            three_ds_authentication: None,
         };
         Self(data)
     }
@@ -481,6 +485,8 @@ impl Default for PaymentCaptureType {
             currency: enums::Currency::USD,
             connector_transaction_id: "".to_string(),
             amount: 100,
+            capture_id: "test_capture".to_string(),
+            final_capture: true,
         })
     }
 }
@@ -548,7 +554,8 @@ pub fn get_connector_transaction_id(
         Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
             resource_id.get_connector_transaction_id().ok()
         }
-        Ok(types::PaymentsResponseData::SessionResponse { .. }) => None,
+        Ok(types::PaymentsResponseData::SessionResponse { .. })
+        | Ok(types::PaymentsResponseData::ThreeDsAuthenticationResponse { .. }) => None,
         Err(_) => None,
     }
 }
@@ -2,15 +2,51 @@
 
 mod utils;
 
+fn mk_payout_method_data() -> serde_json::Value {
+    serde_json::json!({
+        "card": {
+            "card_number": "4111111111111111",
+            "card_exp_month": "03",
+            "card_exp_year": "2030",
+            "card_holder_name": "John Doe"
+        }
+    })
+}
+
+fn mk_payout(payout_id: &str, confirm: bool) -> serde_json::Value {
+    serde_json::json!({
+        "payout_id": payout_id,
+        "amount": 1000,
+        "currency": "EUR",
+        "connector": "adyen",
+        "payout_type": "card",
+        "payout_method_data": mk_payout_method_data(),
+        "confirm": confirm,
+        "description": "Its my first payout request"
+    })
+}
+
+fn mk_payout_fulfill() -> serde_json::Value {
+    serde_json::json!({
+        "confirm": true,
+        "payout_method_data": mk_payout_method_data(),
+    })
+}
+
 #[actix_web::test]
-async fn payouts_todo() {
+async fn payouts_require_api_key() {
     utils::setup().await;
 
     let client = awc::Client::default();
     let mut response;
     let mut response_body;
-    let get_endpoints = vec!["retrieve", "accounts"];
-    let post_endpoints = vec!["create", "update", "reverse", "cancel"];
+    let get_endpoints = vec!["payout_123", "list", "accounts?customer_id=customer_123"];
+    let post_endpoints = vec![
+        "create",
+        "payout_123",
+        "payout_123/cancel",
+        "payout_123/reverse",
+    ];
 
     for endpoint in get_endpoints {
         response = client
@@ -20,7 +56,7 @@ async fn payouts_todo() {
             .unwrap();
         response_body = response.body().await;
         println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
     }
 
     for endpoint in post_endpoints {
@@ -31,6 +67,206 @@ async fn payouts_todo() {
             .unwrap();
         response_body = response.body().await;
         println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
     }
 }
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id has adyen configured for payouts
+async fn payouts_create_and_fulfill() {
+    utils::setup().await;
+
+    let payout_id = format!("payout_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body: serde_json::Value;
+
+    // create payout
+    response = client
+        .post("http://127.0.0.1:8080/payouts/create")
+        .insert_header(api_key)
+        .send_json(&mk_payout(&payout_id, false))
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-create: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["payout_id"], payout_id.as_str());
+    assert_eq!(response_body["status"], "requires_fulfillment");
+
+    // fulfill payout
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}"))
+        .insert_header(api_key)
+        .send_json(&mk_payout_fulfill())
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-fulfill: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_ne!(response_body["status"], "requires_fulfillment");
+    assert!(!response_body["connector_payout_id"].is_null());
+
+    // retrieve payout
+    response = client
+        .get(format!("http://127.0.0.1:8080/payouts/{payout_id}"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-retrieve: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["payout_id"], payout_id.as_str());
+
+    // a fulfilled payout cannot be cancelled
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}/cancel"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    println!("payout-cancel: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id has adyen configured for payouts
+async fn payouts_fulfill_and_reverse() {
+    utils::setup().await;
+
+    let payout_id = format!("payout_{}", uuid::Uuid::new_v4().simple());
+    let customer_id = format!("customer_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+    let mut payout = mk_payout(&payout_id, true);
+    payout["customer_id"] = serde_json::json!(customer_id);
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body: serde_json::Value;
+
+    // create and fulfill payout
+    response = client
+        .post("http://127.0.0.1:8080/payouts/create")
+        .insert_header(api_key)
+        .send_json(&payout)
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-create: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "success");
+    assert_eq!(response_body["payout_account"], "************1111");
+
+    // the card paid out to is listed as an account of the customer
+    response = client
+        .get(format!(
+            "http://127.0.0.1:8080/payouts/accounts?customer_id={customer_id}"
+        ))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-accounts: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["size"], 1);
+    assert_eq!(
+        response_body["data"][0]["payout_account"],
+        "************1111"
+    );
+    assert_eq!(
+        response_body["data"][0]["last_payout_id"],
+        payout_id.as_str()
+    );
+
+    // reverse payout
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}/reverse"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-reverse: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "reversed");
+
+    // a reversed payout cannot be reversed again
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}/reverse"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    println!("payout-reverse: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id has adyen configured for payouts
+async fn payouts_create_and_cancel() {
+    utils::setup().await;
+
+    let payout_id = format!("payout_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body: serde_json::Value;
+
+    // create payout
+    response = client
+        .post("http://127.0.0.1:8080/payouts/create")
+        .insert_header(api_key)
+        .send_json(&mk_payout(&payout_id, false))
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-create: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "requires_fulfillment");
+
+    // cancel payout
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}/cancel"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-cancel: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "cancelled");
+
+    // a cancelled payout cannot be fulfilled
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}"))
+        .insert_header(api_key)
+        .send_json(&mk_payout_fulfill())
+        .await
+        .unwrap();
+    println!("payout-fulfill: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+
+    // the cancelled payout is listed
+    response = client
+        .get("http://127.0.0.1:8080/payouts/list")
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-list: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert!(response_body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|payout| payout["payout_id"] == payout_id.as_str()));
+}
@@ -21,6 +21,12 @@ enum Derives {
     Verify,
     Session,
     SessionData,
+    IncrementalAuthorization,
+    IncrementalAuthorizationData,
+    PreAuthenticate,
+    PreAuthenticateData,
+    Authenticate,
+    AuthenticateData,
 }
 
 impl From<String> for Derives {
@@ -39,6 +45,12 @@ impl From<String> for Derives {
             "verifydata" => Self::VerifyData,
             "session" => Self::Session,
             "sessiondata" => Self::SessionData,
+            "incremental_authorization" => Self::IncrementalAuthorization,
+            "incrementalauthorizationdata" => Self::IncrementalAuthorizationData,
+            "pre_authenticate" => Self::PreAuthenticate,
+            "preauthenticatedata" => Self::PreAuthenticateData,
+            "authenticate" => Self::Authenticate,
+            "authenticatedata" => Self::AuthenticateData,
             _ => Self::Authorize,
         }
     }
@@ -115,6 +127,24 @@ impl Conversion {
             Derives::VerifyData => syn::Ident::new("VerifyRequestData", Span::call_site()),
             Derives::Session => syn::Ident::new("PaymentsSessionRequest", Span::call_site()),
             Derives::SessionData => syn::Ident::new("PaymentsSessionData", Span::call_site()),
+            Derives::IncrementalAuthorization => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationRequest", Span::call_site())
+            }
+            Derives::IncrementalAuthorizationData => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationData", Span::call_site())
+            }
+            Derives::PreAuthenticate => {
+                syn::Ident::new("PaymentsPreAuthenticateRequest", Span::call_site())
+            }
+            Derives::PreAuthenticateData => {
+                syn::Ident::new("PaymentsPreAuthenticateData", Span::call_site())
+            }
+            Derives::Authenticate => {
+                syn::Ident::new("PaymentsAuthenticateRequest", Span::call_site())
+            }
+            Derives::AuthenticateData => {
+                syn::Ident::new("PaymentsAuthenticateData", Span::call_site())
+            }
         }
     }
 
@@ -328,6 +358,9 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                     PaymentsCancelData,
                     PaymentsAuthorizeData,
                     PaymentsSessionData,
+                    PaymentsIncrementalAuthorizationData,
+                    PaymentsPreAuthenticateData,
+                    PaymentsAuthenticateData,
 
                     api::{
                         PaymentsCaptureRequest,
@@ -336,6 +369,9 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                         PaymentsRequest,
                         PaymentsStartRequest,
                         PaymentsSessionRequest,
+                        PaymentsIncrementalAuthorizationRequest,
+                        PaymentsPreAuthenticateRequest,
+                        PaymentsAuthenticateRequest,
                         VerifyRequest
                     }
                 };
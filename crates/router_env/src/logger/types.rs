@@ -120,6 +120,12 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments incremental authorization flow.
+    PaymentsIncrementalAuthorization,
+    /// Payments 3DS pre authentication flow.
+    PaymentsPreAuthenticate,
+    /// Payments 3DS authentication flow.
+    PaymentsAuthenticate,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
@@ -132,12 +138,24 @@ pub enum Flow {
     PayoutsRetrieve,
     /// Payouts update flow.
     PayoutsUpdate,
-    /// Payouts reverse flow.
-    PayoutsReverse,
     /// Payouts cancel flow.
     PayoutsCancel,
+    /// Payouts reverse flow.
+    PayoutsReverse,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payouts list flow.
+    PayoutsList,
+    /// Subscriptions create flow.
+    SubscriptionsCreate,
+    /// Subscriptions retrieve flow.
+    SubscriptionsRetrieve,
+    /// Subscriptions pause flow.
+    SubscriptionsPause,
+    /// Subscriptions resume flow.
+    SubscriptionsResume,
+    /// Subscriptions cancel flow.
+    SubscriptionsCancel,
     /// Refunds create flow.
     RefundsCreate,
     /// Refunds retrieve flow.
@@ -160,6 +178,32 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// Dispute Retrieve flow
+    DisputesRetrieve,
+    /// Dispute List flow
+    DisputesList,
+    /// Dispute Accept flow
+    DisputesAccept,
+    /// Dispute Evidence submission flow
+    DisputesEvidenceSubmit,
+    /// Routing Dry Run flow
+    RoutingDryRun,
+    /// Connector Health Retrieve flow
+    ConnectorHealthRetrieve,
+    /// Connector Health Override flow
+    ConnectorHealthOverride,
+    /// Connector Health Override Delete flow
+    ConnectorHealthOverrideDelete,
+    /// Export Create flow
+    ExportCreate,
+    /// Export Retrieve flow
+    ExportRetrieve,
+    /// Create File flow
+    CreateFile,
+    /// Retrieve File flow
+    RetrieveFile,
+    /// Delete File flow
+    DeleteFile,
 }
 
 /// Category of log event.
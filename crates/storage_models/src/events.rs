@@ -1,5 +1,5 @@
 use common_utils::custom_serde;
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -32,4 +32,53 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub delivery_attempts: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+pub enum EventUpdate {
+    DeliveryAttemptUpdate {
+        is_webhook_notified: bool,
+        delivery_attempts: serde_json::Value,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = events)]
+pub struct EventUpdateInternal {
+    is_webhook_notified: Option<bool>,
+    delivery_attempts: Option<serde_json::Value>,
+}
+
+impl From<EventUpdate> for EventUpdateInternal {
+    fn from(event_update: EventUpdate) -> Self {
+        match event_update {
+            EventUpdate::DeliveryAttemptUpdate {
+                is_webhook_notified,
+                delivery_attempts,
+            } => Self {
+                is_webhook_notified: Some(is_webhook_notified),
+                delivery_attempts: Some(delivery_attempts),
+            },
+        }
+    }
+}
+
+/// Outcome of a single attempt at delivering the webhook of an event to the merchant
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookDeliveryAttempt {
+    pub status_code: Option<u16>,
+    pub latency_ms: u128,
+    pub response_body: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub attempted_at: PrimitiveDateTime,
+}
+
+/// Tracking data of the process tracker task retrying the delivery of an outgoing webhook
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    pub webhook: serde_json::Value,
 }
@@ -0,0 +1,84 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::file_metadata;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = file_metadata)]
+#[serde(deny_unknown_fields)]
+pub struct FileMetadataNew {
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub file_key: String,
+    pub file_upload_provider: Option<String>,
+    pub provider_file_id: Option<String>,
+    pub available: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadata {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub file_key: String,
+    pub file_upload_provider: Option<String>,
+    pub provider_file_id: Option<String>,
+    pub available: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum FileMetadataUpdate {
+    /// The file has been persisted in the router's file storage
+    StorageUpdate { available: bool },
+    /// The exported records have been written to the file in the router's file storage
+    ExportUpdate { file_size: i32 },
+    /// The file has been uploaded to a connector
+    ProviderUpdate {
+        file_upload_provider: String,
+        provider_file_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataUpdateInternal {
+    file_size: Option<i32>,
+    available: Option<bool>,
+    file_upload_provider: Option<String>,
+    provider_file_id: Option<String>,
+}
+
+impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
+    fn from(file_metadata_update: FileMetadataUpdate) -> Self {
+        match file_metadata_update {
+            FileMetadataUpdate::StorageUpdate { available } => Self {
+                available: Some(available),
+                ..Default::default()
+            },
+            FileMetadataUpdate::ExportUpdate { file_size } => Self {
+                file_size: Some(file_size),
+                available: Some(true),
+                ..Default::default()
+            },
+            FileMetadataUpdate::ProviderUpdate {
+                file_upload_provider,
+                provider_file_id,
+            } => Self {
+                file_upload_provider: Some(file_upload_provider),
+                provider_file_id: Some(provider_file_id),
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::idempotency_keys;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = idempotency_keys)]
+pub struct IdempotencyKeyNew {
+    pub merchant_id: String,
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = idempotency_keys)]
+pub struct IdempotencyKey {
+    pub id: i32,
+    pub merchant_id: String,
+    pub idempotency_key: String,
+    /// Digest of the request which first used the idempotency key
+    pub request_fingerprint: String,
+    /// Status code of the response, absent while the request is still being processed
+    pub response_status_code: Option<i16>,
+    pub response_body: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum IdempotencyKeyUpdate {
+    ResponseUpdate {
+        response_status_code: i16,
+        response_body: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = idempotency_keys)]
+pub struct IdempotencyKeyUpdateInternal {
+    response_status_code: Option<i16>,
+    response_body: Option<String>,
+}
+
+impl From<IdempotencyKeyUpdate> for IdempotencyKeyUpdateInternal {
+    fn from(idempotency_key_update: IdempotencyKeyUpdate) -> Self {
+        match idempotency_key_update {
+            IdempotencyKeyUpdate::ResponseUpdate {
+                response_status_code,
+                response_body,
+            } => Self {
+                response_status_code: Some(response_status_code),
+                response_body: Some(response_body),
+            },
+        }
+    }
+}
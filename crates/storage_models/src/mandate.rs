@@ -26,6 +26,13 @@ pub struct Mandate {
     pub amount_captured: Option<i64>,
     pub connector: String,
     pub connector_mandate_id: Option<String>,
+    pub start_date: Option<PrimitiveDateTime>,
+    pub end_date: Option<PrimitiveDateTime>,
+    pub frequency_max_charges: Option<i32>,
+    pub frequency_period: Option<storage_enums::MandateFrequencyPeriod>,
+    /// The number of times the mandate has been charged in the period starting at `period_start`
+    pub period_charge_count: i32,
+    pub period_start: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -50,6 +57,10 @@ pub struct MandateNew {
     pub amount_captured: Option<i64>,
     pub connector: String,
     pub connector_mandate_id: Option<String>,
+    pub start_date: Option<PrimitiveDateTime>,
+    pub end_date: Option<PrimitiveDateTime>,
+    pub frequency_max_charges: Option<i32>,
+    pub frequency_period: Option<storage_enums::MandateFrequencyPeriod>,
 }
 
 #[derive(Debug)]
@@ -63,6 +74,11 @@ pub enum MandateUpdate {
     ConnectorReferenceUpdate {
         connector_mandate_id: Option<String>,
     },
+    UsageUpdate {
+        amount_captured: Option<i64>,
+        period_charge_count: i32,
+        period_start: Option<PrimitiveDateTime>,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -77,6 +93,8 @@ pub struct MandateUpdateInternal {
     mandate_status: Option<storage_enums::MandateStatus>,
     amount_captured: Option<i64>,
     connector_mandate_id: Option<String>,
+    period_charge_count: Option<i32>,
+    period_start: Option<PrimitiveDateTime>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -84,13 +102,11 @@ impl From<MandateUpdate> for MandateUpdateInternal {
         match mandate_update {
             MandateUpdate::StatusUpdate { mandate_status } => Self {
                 mandate_status: Some(mandate_status),
-                connector_mandate_id: None,
-                amount_captured: None,
+                ..Default::default()
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
-                mandate_status: None,
                 amount_captured,
-                connector_mandate_id: None,
+                ..Default::default()
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_id,
@@ -98,6 +114,16 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id,
                 ..Default::default()
             },
+            MandateUpdate::UsageUpdate {
+                amount_captured,
+                period_charge_count,
+                period_start,
+            } => Self {
+                amount_captured,
+                period_charge_count: Some(period_charge_count),
+                period_start,
+                ..Default::default()
+            },
         }
     }
 }
@@ -76,13 +76,15 @@ pub enum MerchantAccountUpdate {
     StorageSchemeUpdate {
         storage_scheme: storage_enums::MerchantStorageScheme,
     },
+    /// Clear the legacy API key of the merchant, once migrated to a hashed API key
+    LegacyApiKeyClear,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = merchant_account)]
 pub struct MerchantAccountUpdateInternal {
     merchant_name: Option<String>,
-    api_key: Option<StrongSecret<String>>,
+    api_key: Option<Option<StrongSecret<String>>>,
     merchant_details: Option<serde_json::Value>,
     return_url: Option<String>,
     webhook_details: Option<serde_json::Value>,
@@ -118,7 +120,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 metadata,
             } => Self {
                 merchant_name,
-                api_key,
+                api_key: api_key.map(Some),
                 merchant_details,
                 return_url,
                 webhook_details,
@@ -137,6 +139,10 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 storage_scheme: Some(storage_scheme),
                 ..Default::default()
             },
+            MerchantAccountUpdate::LegacyApiKeyClear => Self {
+                api_key: Some(None),
+                ..Default::default()
+            },
         }
     }
 }
@@ -39,6 +39,7 @@ pub struct PaymentAttempt {
     pub error_code: Option<String>,
     pub payment_token: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub three_ds_authentication: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -78,6 +79,7 @@ pub struct PaymentAttemptNew {
     pub payment_token: Option<String>,
     pub error_code: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub three_ds_authentication: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,6 +126,16 @@ pub enum PaymentAttemptUpdate {
     StatusUpdate {
         status: storage_enums::AttemptStatus,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+        amount_to_capture: Option<i64>,
+    },
+    ThreeDsAuthenticationUpdate {
+        status: storage_enums::AttemptStatus,
+        payment_token: Option<String>,
+        three_ds_authentication: serde_json::Value,
+        error_message: Option<String>,
+    },
     ErrorUpdate {
         connector: Option<String>,
         status: storage_enums::AttemptStatus,
@@ -152,6 +164,8 @@ pub struct PaymentAttemptUpdateInternal {
     payment_token: Option<String>,
     error_code: Option<String>,
     connector_metadata: Option<serde_json::Value>,
+    amount_to_capture: Option<i64>,
+    three_ds_authentication: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptUpdate {
@@ -174,6 +188,10 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            amount_to_capture: pa_update.amount_to_capture.or(source.amount_to_capture),
+            three_ds_authentication: pa_update
+                .three_ds_authentication
+                .or(source.three_ds_authentication),
             ..source
         }
     }
@@ -275,6 +293,28 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+                amount,
+                amount_to_capture,
+            } => Self {
+                amount: Some(amount),
+                amount_to_capture,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::ThreeDsAuthenticationUpdate {
+                status,
+                payment_token,
+                three_ds_authentication,
+                error_message,
+            } => Self {
+                status: Some(status),
+                payment_token,
+                three_ds_authentication: Some(three_ds_authentication),
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
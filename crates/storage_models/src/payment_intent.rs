@@ -92,6 +92,9 @@ pub enum PaymentIntentUpdate {
     PGStatusUpdate {
         status: storage_enums::IntentStatus,
     },
+    IncrementalAuthorizationAmountUpdate {
+        amount: i64,
+    },
     Update {
         amount: i64,
         currency: storage_enums::Currency,
@@ -201,6 +204,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
                 shipping_address_id,
@@ -0,0 +1,163 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, Serialize, Deserialize)]
+#[diesel(table_name = payouts)]
+pub struct Payouts {
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: Option<String>,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// Masked details of the card or bank account the payout was fulfilled to
+    pub payout_account: Option<String>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
+    router_derive::Setter,
+)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: Option<String>,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PayoutsUpdate {
+    Update {
+        amount: Option<i64>,
+        currency: Option<storage_enums::Currency>,
+        payout_type: Option<storage_enums::PayoutType>,
+        customer_id: Option<String>,
+        description: Option<String>,
+        metadata: Option<serde_json::Value>,
+    },
+    StatusUpdate {
+        connector: Option<String>,
+        connector_payout_id: Option<String>,
+        status: storage_enums::PayoutStatus,
+    },
+    FulfillUpdate {
+        connector: Option<String>,
+        connector_payout_id: Option<String>,
+        status: storage_enums::PayoutStatus,
+        payout_account: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsUpdateInternal {
+    amount: Option<i64>,
+    currency: Option<storage_enums::Currency>,
+    payout_type: Option<storage_enums::PayoutType>,
+    customer_id: Option<String>,
+    description: Option<String>,
+    metadata: Option<serde_json::Value>,
+    connector: Option<String>,
+    connector_payout_id: Option<String>,
+    status: Option<storage_enums::PayoutStatus>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+    payout_account: Option<String>,
+}
+
+impl From<PayoutsUpdate> for PayoutsUpdateInternal {
+    fn from(payout_update: PayoutsUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match payout_update {
+            PayoutsUpdate::Update {
+                amount,
+                currency,
+                payout_type,
+                customer_id,
+                description,
+                metadata,
+            } => Self {
+                amount,
+                currency,
+                payout_type,
+                customer_id,
+                description,
+                metadata,
+                modified_at,
+                ..Default::default()
+            },
+            PayoutsUpdate::StatusUpdate {
+                connector,
+                connector_payout_id,
+                status,
+            } => Self {
+                connector,
+                connector_payout_id,
+                status: Some(status),
+                modified_at,
+                ..Default::default()
+            },
+            PayoutsUpdate::FulfillUpdate {
+                connector,
+                connector_payout_id,
+                status,
+                payout_account,
+            } => Self {
+                connector,
+                connector_payout_id,
+                status: Some(status),
+                payout_account,
+                modified_at,
+                ..Default::default()
+            },
+            PayoutsUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
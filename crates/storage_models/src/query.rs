@@ -1,10 +1,14 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
 pub mod customers;
+pub mod dispute;
 pub mod events;
+pub mod file;
 pub mod generics;
+pub mod idempotency_key;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -12,6 +16,8 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod subscription;
@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -81,4 +81,15 @@ impl ApiKey {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_prefix(conn: &PgPooledConn, prefix: &str) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::prefix.eq(prefix.to_owned()), None, None, None)
+        .await
+    }
 }
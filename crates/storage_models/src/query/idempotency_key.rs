@@ -0,0 +1,111 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    errors,
+    idempotency_key::{
+        IdempotencyKey, IdempotencyKeyNew, IdempotencyKeyUpdate, IdempotencyKeyUpdateInternal,
+    },
+    schema::idempotency_keys::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IdempotencyKeyNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IdempotencyKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IdempotencyKey {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_idempotency_key(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::idempotency_key.eq(idempotency_key.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_idempotency_key(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        idempotency_key: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::idempotency_key.eq(idempotency_key.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        created_before: PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.lt(created_before)),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_oldest_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(1),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+        .map(|idempotency_keys| idempotency_keys.into_iter().next())
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        idempotency_key_update: IdempotencyKeyUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::idempotency_key.eq(self.idempotency_key.to_owned())),
+            IdempotencyKeyUpdateInternal::from(idempotency_key_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable, pg::PgExpressionMethods, BoolExpressionMethods, ExpressionMethods,
+    Table,
+};
 use error_stack::report;
 use router_env::{instrument, tracing};
 
@@ -70,4 +73,28 @@ impl Mandate {
                 .attach_printable("Error while updating mandate")
         })
     }
+
+    /// Update the usage of the mandate, provided that its status and usage are still the same as
+    /// in `mandate`, so that concurrent charges of the mandate cannot exceed its limits. Returns
+    /// `None` if the mandate was updated in the meantime.
+    #[instrument(skip(conn))]
+    pub async fn update_usage_if_unchanged(
+        conn: &PgPooledConn,
+        mandate: &Self,
+        mandate_update: MandateUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(mandate.merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate.mandate_id.to_owned()))
+                .and(dsl::mandate_status.eq(mandate.mandate_status))
+                .and(dsl::amount_captured.is_not_distinct_from(mandate.amount_captured))
+                .and(dsl::period_charge_count.eq(mandate.period_charge_count))
+                .and(dsl::period_start.is_not_distinct_from(mandate.period_start)),
+            MandateUpdateInternal::from(mandate_update),
+        )
+        .await
+        .map(|mut mandates| mandates.pop())
+    }
 }
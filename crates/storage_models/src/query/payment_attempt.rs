@@ -1,6 +1,7 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use error_stack::IntoReport;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -30,7 +31,8 @@ impl PaymentAttempt {
             conn,
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
-                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::attempt_id.eq(self.attempt_id.to_owned())),
             PaymentAttemptUpdateInternal::from(payment_attempt),
         )
         .await
@@ -45,32 +47,49 @@ impl PaymentAttempt {
         }
     }
 
+    /// Find the latest attempt made for the payment
     #[instrument(skip(conn))]
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::payment_id.eq(payment_id.to_owned())),
-        )
-        .await
+        Self::find_optional_by_payment_id_merchant_id(conn, payment_id, merchant_id)
+            .await?
+            .ok_or(errors::DatabaseError::NotFound)
+            .into_report()
     }
 
+    /// Find the latest attempt made for the payment, if any
     #[instrument(skip(conn))]
     pub async fn find_optional_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Option<Self>> {
-        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+        Ok(
+            Self::filter_by_payment_id_merchant_id(conn, payment_id, merchant_id, Some(1))
+                .await?
+                .pop(),
+        )
+    }
+
+    /// Find the attempts made for the payment, latest first
+    #[instrument(skip(conn))]
+    pub async fn filter_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
                 .and(dsl::payment_id.eq(payment_id.to_owned())),
+            limit,
+            None,
+            Some(dsl::created_at.desc()),
         )
         .await
     }
@@ -153,4 +172,27 @@ impl PaymentAttempt {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn filter_by_merchant_id_created_at_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.ge(start_time))
+                .and(dsl::created_at.lt(end_time))
+                .and(dsl::id.gt(after_id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
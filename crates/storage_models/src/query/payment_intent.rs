@@ -1,5 +1,6 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -73,4 +74,27 @@ impl PaymentIntent {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn filter_by_merchant_id_created_at_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.ge(start_time))
+                .and(dsl::created_at.lt(end_time))
+                .and(dsl::id.gt(after_id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
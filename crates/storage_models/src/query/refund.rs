@@ -1,5 +1,6 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -57,6 +58,23 @@ impl Refund {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_refund_id_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_refund_id: &str,
+        connector: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_refund_id.eq(connector_refund_id.to_owned()))
+                .and(dsl::connector.eq(connector.to_owned())),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_internal_reference_id_merchant_id(
         conn: &PgPooledConn,
@@ -117,4 +135,27 @@ impl Refund {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn filter_by_merchant_id_created_at_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.ge(start_time))
+                .and(dsl::created_at.lt(end_time))
+                .and(dsl::id.gt(after_id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
 }
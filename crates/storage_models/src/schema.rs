@@ -40,6 +40,29 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Currency,
+        connector -> Varchar,
+        final_capture -> Bool,
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -90,6 +113,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute (id) {
+        id -> Int4,
+        dispute_id -> Varchar,
+        amount -> Varchar,
+        currency -> Varchar,
+        dispute_stage -> DisputeStage,
+        dispute_status -> DisputeStatus,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        merchant_id -> Varchar,
+        connector_status -> Varchar,
+        connector_dispute_id -> Varchar,
+        connector_reason -> Nullable<Varchar>,
+        connector_reason_code -> Nullable<Varchar>,
+        challenge_required_by -> Nullable<Timestamp>,
+        dispute_created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        connector -> Varchar,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -104,6 +154,41 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        delivery_attempts -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    file_metadata (id) {
+        id -> Int4,
+        file_id -> Varchar,
+        merchant_id -> Varchar,
+        file_name -> Nullable<Varchar>,
+        file_size -> Int4,
+        file_type -> Varchar,
+        file_key -> Varchar,
+        file_upload_provider -> Nullable<Varchar>,
+        provider_file_id -> Nullable<Varchar>,
+        available -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    idempotency_keys (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        idempotency_key -> Varchar,
+        request_fingerprint -> Varchar,
+        response_status_code -> Nullable<Int2>,
+        response_body -> Nullable<Text>,
+        created_at -> Timestamp,
     }
 }
 
@@ -153,6 +238,12 @@ diesel::table! {
         amount_captured -> Nullable<Int8>,
         connector -> Varchar,
         connector_mandate_id -> Nullable<Varchar>,
+        start_date -> Nullable<Timestamp>,
+        end_date -> Nullable<Timestamp>,
+        frequency_max_charges -> Nullable<Int4>,
+        frequency_period -> Nullable<MandateFrequencyPeriod>,
+        period_charge_count -> Int4,
+        period_start -> Nullable<Timestamp>,
     }
 }
 
@@ -236,6 +327,7 @@ diesel::table! {
         error_code -> Nullable<Varchar>,
         payment_token -> Nullable<Varchar>,
         connector_metadata -> Nullable<Jsonb>,
+        three_ds_authentication -> Nullable<Jsonb>,
     }
 }
 
@@ -298,6 +390,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Nullable<Varchar>,
+        connector_payout_id -> Nullable<Varchar>,
+        payout_type -> PayoutType,
+        amount -> Int8,
+        currency -> Currency,
+        status -> PayoutStatus,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Json>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        payout_account -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -363,13 +480,44 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscriptions (id) {
+        id -> Int4,
+        subscription_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Varchar,
+        mandate_id -> Varchar,
+        status -> SubscriptionStatus,
+        amount -> Int8,
+        currency -> Currency,
+        billing_interval -> SubscriptionInterval,
+        interval_count -> Int4,
+        billing_anchor -> Timestamp,
+        billing_cycle -> Int4,
+        next_charge_at -> Timestamp,
+        last_payment_id -> Nullable<Varchar>,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Json>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        pending_payment_id -> Nullable<Varchar>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    captures,
     configs,
     connector_response,
     customers,
+    dispute,
     events,
+    file_metadata,
+    idempotency_keys,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -377,7 +525,9 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_methods,
+    payouts,
     process_tracker,
     refund,
     reverse_lookup,
+    subscriptions,
 );
@@ -0,0 +1,128 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::subscriptions};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, Serialize, Deserialize)]
+#[diesel(table_name = subscriptions)]
+pub struct Subscription {
+    pub id: i32,
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    /// The time from which the charges of the subscription are scheduled
+    pub billing_anchor: PrimitiveDateTime,
+    /// The number of the billing cycle to be charged next, starting from zero
+    pub billing_cycle: i32,
+    pub next_charge_at: PrimitiveDateTime,
+    pub last_payment_id: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// The payment made for the billing cycle to be charged next, which is checked before the
+    /// billing cycle is charged again
+    pub pending_payment_id: Option<String>,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = subscriptions)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub billing_anchor: PrimitiveDateTime,
+    pub billing_cycle: i32,
+    pub next_charge_at: PrimitiveDateTime,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SubscriptionUpdate {
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+    },
+    ScheduleUpdate {
+        status: storage_enums::SubscriptionStatus,
+        billing_cycle: i32,
+        next_charge_at: PrimitiveDateTime,
+        last_payment_id: Option<String>,
+    },
+    PaymentFailedUpdate {
+        status: storage_enums::SubscriptionStatus,
+        last_payment_id: Option<String>,
+    },
+    ChargeUpdate {
+        pending_payment_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscriptions)]
+pub struct SubscriptionUpdateInternal {
+    status: Option<storage_enums::SubscriptionStatus>,
+    billing_cycle: Option<i32>,
+    next_charge_at: Option<PrimitiveDateTime>,
+    last_payment_id: Option<String>,
+    pending_payment_id: Option<Option<String>>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match subscription_update {
+            SubscriptionUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::ScheduleUpdate {
+                status,
+                billing_cycle,
+                next_charge_at,
+                last_payment_id,
+            } => Self {
+                status: Some(status),
+                billing_cycle: Some(billing_cycle),
+                next_charge_at: Some(next_charge_at),
+                last_payment_id,
+                // The billing cycle is charged, the next one is charged with a new payment
+                pending_payment_id: Some(None),
+                modified_at,
+            },
+            SubscriptionUpdate::PaymentFailedUpdate {
+                status,
+                last_payment_id,
+            } => Self {
+                status: Some(status),
+                last_payment_id,
+                modified_at,
+                ..Default::default()
+            },
+            SubscriptionUpdate::ChargeUpdate { pending_payment_id } => Self {
+                pending_payment_id: Some(Some(pending_payment_id)),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
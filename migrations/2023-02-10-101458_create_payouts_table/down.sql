@@ -0,0 +1,5 @@
+DROP TABLE payouts;
+
+DROP TYPE "PayoutType";
+
+DROP TYPE "PayoutStatus";
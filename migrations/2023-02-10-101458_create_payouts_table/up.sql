@@ -0,0 +1,32 @@
+CREATE TYPE "PayoutStatus" AS ENUM (
+    'success',
+    'failed',
+    'cancelled',
+    'pending',
+    'ineligible',
+    'requires_fulfillment',
+    'reversed'
+);
+
+CREATE TYPE "PayoutType" AS ENUM ('card', 'bank');
+
+CREATE TABLE payouts (
+    id SERIAL PRIMARY KEY,
+    payout_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    connector VARCHAR(64),
+    connector_payout_id VARCHAR(128),
+    payout_type "PayoutType" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status "PayoutStatus" NOT NULL,
+    description VARCHAR(255),
+    metadata JSON,
+    error_message TEXT,
+    error_code VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payouts_merchant_id_payout_id_index ON payouts (merchant_id, payout_id);
@@ -0,0 +1,31 @@
+DROP TABLE dispute;
+
+DROP TYPE "DisputeStage";
+
+DROP TYPE "DisputeStatus";
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'disputes'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'dispute_details'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN (
+  'dispute_opened',
+  'dispute_expired',
+  'dispute_accepted',
+  'dispute_cancelled',
+  'dispute_challenged',
+  'dispute_won',
+  'dispute_lost'
+)
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
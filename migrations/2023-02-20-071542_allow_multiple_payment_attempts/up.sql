@@ -0,0 +1,4 @@
+-- A payment can now have multiple attempts, when it is retried on a different connector
+DROP INDEX payment_attempt_payment_id_merchant_id_index;
+CREATE INDEX payment_attempt_payment_id_merchant_id_index ON payment_attempt (payment_id, merchant_id);
+CREATE UNIQUE INDEX payment_attempt_payment_id_merchant_id_attempt_id_index ON payment_attempt (payment_id, merchant_id, attempt_id);
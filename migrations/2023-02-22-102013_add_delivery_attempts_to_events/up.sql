@@ -0,0 +1 @@
+ALTER TABLE events ADD COLUMN delivery_attempts JSONB;
@@ -0,0 +1,25 @@
+DELETE FROM pg_enum
+WHERE enumlabel IN ('refunds', 'mandates')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('refund_details', 'mandate_details')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN (
+  'payment_failed',
+  'payment_processing',
+  'payment_cancelled',
+  'payment_captured',
+  'refund_succeeded',
+  'refund_failed',
+  'mandate_revoked'
+)
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
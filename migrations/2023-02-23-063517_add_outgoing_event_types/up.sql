@@ -0,0 +1,13 @@
+ALTER TYPE "EventClass" ADD VALUE 'refunds';
+ALTER TYPE "EventClass" ADD VALUE 'mandates';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'refund_details';
+ALTER TYPE "EventObjectType" ADD VALUE 'mandate_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_failed';
+ALTER TYPE "EventType" ADD VALUE 'payment_processing';
+ALTER TYPE "EventType" ADD VALUE 'payment_cancelled';
+ALTER TYPE "EventType" ADD VALUE 'payment_captured';
+ALTER TYPE "EventType" ADD VALUE 'refund_succeeded';
+ALTER TYPE "EventType" ADD VALUE 'refund_failed';
+ALTER TYPE "EventType" ADD VALUE 'mandate_revoked';
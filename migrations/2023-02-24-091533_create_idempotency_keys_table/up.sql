@@ -0,0 +1,11 @@
+CREATE TABLE idempotency_keys (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    idempotency_key VARCHAR(255) NOT NULL,
+    request_fingerprint VARCHAR(128) NOT NULL,
+    response_status_code SMALLINT,
+    response_body TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idempotency_keys_merchant_id_idempotency_key_index ON idempotency_keys (merchant_id, idempotency_key);
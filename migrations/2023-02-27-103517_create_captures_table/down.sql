@@ -0,0 +1,3 @@
+DROP TABLE captures;
+
+DROP TYPE "CaptureStatus";
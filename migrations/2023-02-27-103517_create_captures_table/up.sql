@@ -0,0 +1,22 @@
+CREATE TYPE "CaptureStatus" AS ENUM ('started', 'charged', 'pending', 'failed');
+
+CREATE TABLE captures (
+    id SERIAL PRIMARY KEY,
+    capture_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    status "CaptureStatus" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    final_capture BOOLEAN NOT NULL DEFAULT FALSE,
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX captures_capture_id_index ON captures (capture_id);
+
+CREATE INDEX captures_merchant_id_payment_id_index ON captures (merchant_id, payment_id);
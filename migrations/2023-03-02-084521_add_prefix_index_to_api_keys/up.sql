@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX api_keys_prefix_index ON api_keys (prefix);
@@ -0,0 +1,5 @@
+DROP TABLE subscriptions;
+
+DROP TYPE "SubscriptionInterval";
+
+DROP TYPE "SubscriptionStatus";
@@ -0,0 +1,26 @@
+CREATE TYPE "SubscriptionStatus" AS ENUM ('active', 'past_due', 'paused', 'cancelled');
+
+CREATE TYPE "SubscriptionInterval" AS ENUM ('day', 'week', 'month', 'year');
+
+CREATE TABLE subscriptions (
+    id SERIAL PRIMARY KEY,
+    subscription_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    status "SubscriptionStatus" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval "SubscriptionInterval" NOT NULL,
+    interval_count INTEGER NOT NULL,
+    billing_anchor TIMESTAMP NOT NULL,
+    billing_cycle INTEGER NOT NULL DEFAULT 0,
+    next_charge_at TIMESTAMP NOT NULL,
+    last_payment_id VARCHAR(64),
+    description VARCHAR(255),
+    metadata JSON,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX subscriptions_merchant_id_subscription_id_index ON subscriptions (merchant_id, subscription_id);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE mandate
+DROP COLUMN start_date,
+DROP COLUMN end_date,
+DROP COLUMN frequency_max_charges,
+DROP COLUMN frequency_period,
+DROP COLUMN period_charge_count,
+DROP COLUMN period_start;
+
+DROP TYPE "MandateFrequencyPeriod";
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TYPE "MandateFrequencyPeriod" AS ENUM ('day', 'week', 'month', 'year');
+
+ALTER TABLE mandate
+ADD COLUMN start_date TIMESTAMP,
+ADD COLUMN end_date TIMESTAMP,
+ADD COLUMN frequency_max_charges INTEGER,
+ADD COLUMN frequency_period "MandateFrequencyPeriod",
+ADD COLUMN period_charge_count INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN period_start TIMESTAMP;
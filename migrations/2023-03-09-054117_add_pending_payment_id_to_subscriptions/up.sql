@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE subscriptions ADD COLUMN pending_payment_id VARCHAR(64);
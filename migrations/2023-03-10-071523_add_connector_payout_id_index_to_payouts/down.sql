@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payouts_merchant_id_connector_payout_id_index;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX payouts_merchant_id_connector_payout_id_index ON payouts (merchant_id, connector_payout_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payouts_merchant_id_customer_id_index;
+
+ALTER TABLE payouts DROP COLUMN payout_account;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payouts ADD COLUMN payout_account VARCHAR(64);
+
+CREATE INDEX payouts_merchant_id_customer_id_index ON payouts (merchant_id, customer_id);